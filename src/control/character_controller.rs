@@ -0,0 +1,702 @@
+use crate::data::ComponentSet;
+use crate::geometry::{
+    ColliderFlags, ColliderHandle, ColliderPosition, ColliderShape, InteractionGroups,
+};
+use crate::math::{Isometry, Real, Translation, UnitVector, Vector};
+use crate::pipeline::QueryPipeline;
+use na::RealField;
+use parry::query::{TOIStatus, TOI};
+use parry::shape::Shape;
+
+#[cfg(feature = "default-sets")]
+use crate::{
+    dynamics::{RigidBodySet, RigidBodyType},
+    geometry::ColliderSet,
+};
+
+/// A length measure used for various options of a character controller.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+pub enum CharacterLength {
+    /// The length is specified relative to some of the character shape’s size.
+    ///
+    /// For example setting `CharacterAutostep::max_height` to `CharacterLength::Relative(0.1)`
+    /// for a shape with an height equal to 20.0 will result in a maximum step height
+    /// of `0.1 * 20.0 = 2.0`.
+    Relative(Real),
+    /// The length is specified as an absolute value, independent from the character shape’s size.
+    Absolute(Real),
+}
+
+impl CharacterLength {
+    /// Returns `self` with its value changed by the closure `f` if `self` is the `Self::Absolute`
+    /// variant.
+    pub fn map_absolute(self, f: impl FnOnce(Real) -> Real) -> Self {
+        if let Self::Absolute(value) = self {
+            Self::Absolute(f(value))
+        } else {
+            self
+        }
+    }
+
+    /// Returns `self` with its value changed by the closure `f` if `self` is the `Self::Relative`
+    /// variant.
+    pub fn map_relative(self, f: impl FnOnce(Real) -> Real) -> Self {
+        if let Self::Relative(value) = self {
+            Self::Relative(f(value))
+        } else {
+            self
+        }
+    }
+
+    fn eval(self, value: Real) -> Real {
+        match self {
+            Self::Relative(x) => value * x,
+            Self::Absolute(x) => x,
+        }
+    }
+}
+
+/// Configuration for the auto-stepping character controller feature.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+pub struct CharacterAutostep {
+    /// The maximum step height a character can automatically step over.
+    pub max_height: CharacterLength,
+    /// The minimum width of free space that must be available after stepping on a stair.
+    pub min_width: CharacterLength,
+}
+
+impl Default for CharacterAutostep {
+    fn default() -> Self {
+        Self {
+            max_height: CharacterLength::Relative(0.25),
+            min_width: CharacterLength::Relative(0.5),
+        }
+    }
+}
+
+/// A collision between the character and its environment during its movement.
+#[derive(Copy, Clone, Debug)]
+pub struct CharacterCollision {
+    /// The collider hit by the character.
+    pub handle: ColliderHandle,
+    /// The position of the character when the collider was hit.
+    pub character_pos: Isometry<Real>,
+    /// The translation that was already applied to the character when the hit happens.
+    pub translation_applied: Vector<Real>,
+    /// The translations that was still waiting to be applied to the character when the hit happens.
+    pub translation_remaining: Vector<Real>,
+    /// Geometric information about the hit.
+    pub toi: TOI,
+}
+
+/// The effective movement computed by the character controller.
+#[derive(Clone, Debug)]
+pub struct EffectiveCharacterMovement {
+    /// The movement to apply.
+    pub translation: Vector<Real>,
+    /// Is the character touching the ground after applying `EffectiveCharacterMovement::translation`?
+    pub grounded: bool,
+    /// All the collisions that happened while computing the movement, in the order they happened.
+    pub collisions: Vec<CharacterCollision>,
+}
+
+/// A character controller for kinematic bodies.
+///
+/// The controller doesn’t move anything by itself: it computes the movement a shape can
+/// actually perform along a desired translation, taking obstacles, slopes and stairs into
+/// account. The result is typically applied to a `RigidBodyType::KinematicPositionBased`
+/// rigid-body with `RigidBody::set_next_kinematic_translation`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+pub struct KinematicCharacterController {
+    /// The direction that goes "up". Used to determine where the floor is, and the floor’s angle.
+    pub up: UnitVector<Real>,
+    /// A small gap to preserve between the character and its surroundings.
+    ///
+    /// This value should not be too large to avoid visual artifacts, but shouldn’t be too small
+    /// (must not be zero) to improve numerical stability of the character controller.
+    pub offset: CharacterLength,
+    /// Should the character try to slide against the floor if it hits it?
+    pub slide: bool,
+    /// Should the character automatically step over small obstacles?
+    pub autostep: Option<CharacterAutostep>,
+    /// The maximum angle (radians) between the floor’s normal and the `up` vector that the
+    /// character is able to climb.
+    pub max_slope_climb_angle: Real,
+    /// The minimum angle (radians) between the floor’s normal and the `up` vector before the
+    /// character starts to slide down automatically.
+    pub min_slope_slide_angle: Real,
+    /// Should the character be automatically snapped to the ground if the distance between
+    /// the ground and its feed are smaller than the specified threshold?
+    pub snap_to_ground: Option<CharacterLength>,
+}
+
+impl Default for KinematicCharacterController {
+    fn default() -> Self {
+        Self {
+            up: Vector::y_axis(),
+            offset: CharacterLength::Relative(0.01),
+            slide: true,
+            autostep: None,
+            max_slope_climb_angle: Real::frac_pi_4(),
+            min_slope_slide_angle: Real::frac_pi_4(),
+            snap_to_ground: Some(CharacterLength::Relative(0.2)),
+        }
+    }
+}
+
+impl KinematicCharacterController {
+    /// Computes the possible movement for a shape.
+    ///
+    /// # Parameters
+    /// * `colliders` - The set of colliders taking part in the query pipeline.
+    /// * `queries` - The query pipeline used to detect the obstacles.
+    /// * `character_shape` - The shape of the character.
+    /// * `character_pos` - The current position of the character.
+    /// * `desired_translation` - The translation the character would like to perform.
+    /// * `query_groups` - the interaction groups which will be tested against the collider's `contact_group`
+    ///                   to determine if it should be taken into account as an obstacle.
+    /// * `filter` - a more fine-grained filter. A collider is taken into account as an obstacle if
+    ///             its `contact_group` is compatible with the `query_groups`, and if this `filter`
+    ///             is either `None` or returns `true`. This should exclude the character’s own colliders.
+    pub fn move_shape<Colliders>(
+        &self,
+        colliders: &Colliders,
+        queries: &QueryPipeline,
+        character_shape: &dyn Shape,
+        character_pos: &Isometry<Real>,
+        desired_translation: Vector<Real>,
+        query_groups: InteractionGroups,
+        filter: Option<&dyn Fn(ColliderHandle) -> bool>,
+    ) -> EffectiveCharacterMovement
+    where
+        Colliders: ComponentSet<ColliderFlags>
+            + ComponentSet<ColliderPosition>
+            + ComponentSet<ColliderShape>,
+    {
+        let mut result = EffectiveCharacterMovement {
+            translation: Vector::zeros(),
+            grounded: false,
+            collisions: vec![],
+        };
+
+        let extents = character_shape.compute_aabb(character_pos).extents();
+        let up_extent = extents.dot(&self.up).abs();
+        let side_extent = (extents - *self.up * up_extent).norm();
+        let offset = self.offset.eval(up_extent);
+
+        let grounded_at_starting_pos = self.detect_grounded_status(
+            colliders,
+            queries,
+            character_shape,
+            character_pos,
+            up_extent,
+            query_groups,
+            filter,
+        );
+
+        // Colliders the character started penetrating and is moving away from. They
+        // are ignored by the subsequent casts so they don’t block the movement.
+        let mut ignored: Vec<ColliderHandle> = vec![];
+        let mut translation_remaining = desired_translation;
+        let mut max_iters = 20;
+
+        while let Some((translation_dir, translation_dist)) =
+            UnitVector::try_new_and_get(translation_remaining, 1.0e-5)
+        {
+            if max_iters == 0 {
+                break;
+            } else {
+                max_iters -= 1;
+            }
+
+            let current_pos = Translation::from(result.translation) * character_pos;
+            let ignored_ref = &ignored;
+            let cast_filter = |handle: ColliderHandle| {
+                !ignored_ref.contains(&handle) && filter.map(|f| f(handle)).unwrap_or(true)
+            };
+
+            if let Some((handle, toi)) = queries.cast_shape(
+                colliders,
+                &current_pos,
+                &translation_dir,
+                character_shape,
+                translation_dist + offset,
+                query_groups,
+                Some(&cast_filter),
+            ) {
+                if toi.status == TOIStatus::Penetrating && toi.normal1.dot(&translation_dir) >= 0.0
+                {
+                    // We are already moving out of this collider.
+                    ignored.push(handle);
+                    continue;
+                }
+
+                // We hit something, compute the allowed movement.
+                let allowed_dist = (toi.toi - (-toi.normal1.dot(&translation_dir)) * offset)
+                    .max(0.0)
+                    .min(translation_dist);
+                let allowed_translation = *translation_dir * allowed_dist;
+                result.translation += allowed_translation;
+                translation_remaining -= allowed_translation;
+
+                result.collisions.push(CharacterCollision {
+                    handle,
+                    character_pos: Translation::from(result.translation) * character_pos,
+                    translation_applied: result.translation,
+                    translation_remaining,
+                    toi,
+                });
+
+                // Try to go up stairs first, and slide along the obstacle otherwise.
+                let can_step = (grounded_at_starting_pos || result.grounded)
+                    && self.up.angle(&*toi.normal1) > self.max_slope_climb_angle;
+
+                if !can_step
+                    || !self.handle_stairs(
+                        colliders,
+                        queries,
+                        character_shape,
+                        &(Translation::from(result.translation) * character_pos),
+                        up_extent,
+                        side_extent,
+                        query_groups,
+                        filter,
+                        &mut translation_remaining,
+                        &mut result,
+                    )
+                {
+                    translation_remaining = self.handle_slopes(&toi, &translation_remaining);
+                }
+            } else {
+                // No interference along the path.
+                result.translation += translation_remaining;
+                translation_remaining.fill(0.0);
+            }
+
+            result.grounded = self.detect_grounded_status(
+                colliders,
+                queries,
+                character_shape,
+                &(Translation::from(result.translation) * character_pos),
+                up_extent,
+                query_groups,
+                filter,
+            );
+        }
+
+        // If needed, and if we are not already grounded, snap to the ground.
+        if grounded_at_starting_pos && !result.grounded {
+            self.snap_to_ground(
+                colliders,
+                queries,
+                character_shape,
+                character_pos,
+                up_extent,
+                query_groups,
+                filter,
+                &mut result,
+            );
+        }
+
+        result
+    }
+
+    /// Moves the rigid-body the given collider is attached to.
+    ///
+    /// The movement is computed with [`Self::move_shape`] and applied by setting the next
+    /// kinematic translation of the collider’s parent. Colliders attached to the same
+    /// rigid-body are automatically excluded from the obstacles.
+    ///
+    /// Returns `None` if the collider doesn’t exist, or isn’t attached to a
+    /// `RigidBodyType::KinematicPositionBased` rigid-body.
+    #[cfg(feature = "default-sets")]
+    pub fn move_collider(
+        &self,
+        bodies: &mut RigidBodySet,
+        colliders: &ColliderSet,
+        queries: &QueryPipeline,
+        collider: ColliderHandle,
+        desired_translation: Vector<Real>,
+        query_groups: InteractionGroups,
+        filter: Option<&dyn Fn(ColliderHandle) -> bool>,
+    ) -> Option<EffectiveCharacterMovement> {
+        let co = colliders.get(collider)?;
+        let parent = co.parent()?;
+        let rb = bodies.get_mut(parent)?;
+
+        if rb.body_type() != RigidBodyType::KinematicPositionBased {
+            return None;
+        }
+
+        let movement_filter = |handle: ColliderHandle| {
+            colliders.get(handle).and_then(|co| co.parent()) != Some(parent)
+                && filter.map(|f| f(handle)).unwrap_or(true)
+        };
+
+        let movement = self.move_shape(
+            colliders,
+            queries,
+            co.shape(),
+            co.position(),
+            desired_translation,
+            query_groups,
+            Some(&movement_filter),
+        );
+
+        rb.set_next_kinematic_translation(rb.translation() + movement.translation);
+        Some(movement)
+    }
+
+    fn ground_prediction(&self, up_extent: Real) -> Real {
+        self.offset.eval(up_extent) + 0.05 * up_extent
+    }
+
+    fn detect_grounded_status<Colliders>(
+        &self,
+        colliders: &Colliders,
+        queries: &QueryPipeline,
+        character_shape: &dyn Shape,
+        character_pos: &Isometry<Real>,
+        up_extent: Real,
+        query_groups: InteractionGroups,
+        filter: Option<&dyn Fn(ColliderHandle) -> bool>,
+    ) -> bool
+    where
+        Colliders: ComponentSet<ColliderFlags>
+            + ComponentSet<ColliderPosition>
+            + ComponentSet<ColliderShape>,
+    {
+        queries
+            .cast_shape(
+                colliders,
+                character_pos,
+                &-*self.up,
+                character_shape,
+                self.ground_prediction(up_extent),
+                query_groups,
+                filter,
+            )
+            .map(|(_, hit)| self.up.angle(&*hit.normal1) <= self.max_slope_climb_angle)
+            .unwrap_or(false)
+    }
+
+    fn snap_to_ground<Colliders>(
+        &self,
+        colliders: &Colliders,
+        queries: &QueryPipeline,
+        character_shape: &dyn Shape,
+        character_pos: &Isometry<Real>,
+        up_extent: Real,
+        query_groups: InteractionGroups,
+        filter: Option<&dyn Fn(ColliderHandle) -> bool>,
+        result: &mut EffectiveCharacterMovement,
+    ) where
+        Colliders: ComponentSet<ColliderFlags>
+            + ComponentSet<ColliderPosition>
+            + ComponentSet<ColliderShape>,
+    {
+        let snap_distance = try_ret!(self.snap_to_ground).eval(up_extent);
+
+        // Don’t snap if the character is explicitly moving upward (jumping, stepping).
+        if result.translation.dot(&self.up) >= 1.0e-5 {
+            return;
+        }
+
+        let offset = self.offset.eval(up_extent);
+        let current_pos = Translation::from(result.translation) * character_pos;
+
+        if let Some((_, hit)) = queries.cast_shape(
+            colliders,
+            &current_pos,
+            &-*self.up,
+            character_shape,
+            snap_distance + offset,
+            query_groups,
+            filter,
+        ) {
+            if self.up.angle(&*hit.normal1) <= self.max_slope_climb_angle {
+                result.translation -= *self.up * (hit.toi - offset).max(0.0);
+                result.grounded = true;
+            }
+        }
+    }
+
+    fn split_into_components(&self, translation: &Vector<Real>) -> [Vector<Real>; 2] {
+        let vertical_translation = *self.up * (self.up.dot(translation));
+        let horizontal_translation = *translation - vertical_translation;
+        [vertical_translation, horizontal_translation]
+    }
+
+    fn handle_slopes(&self, hit: &TOI, translation_remaining: &Vector<Real>) -> Vector<Real> {
+        if !self.slide {
+            return Vector::zeros();
+        }
+
+        let [vertical_translation, horizontal_translation] =
+            self.split_into_components(translation_remaining);
+        let slope_translation = subtract_hit(*translation_remaining, hit);
+
+        // Check if there is a slope to climb.
+        let angle_with_floor = self.up.angle(&*hit.normal1);
+
+        // We are climbing if the movement along the slope goes upward, and the angle with
+        // the floor is smaller than pi/2.
+        let climbing = self.up.dot(&slope_translation) >= 0.0 && self.up.dot(&hit.normal1) > 0.0;
+
+        if climbing && angle_with_floor >= self.max_slope_climb_angle {
+            // Prevent horizontal movement from pushing through the slope.
+            subtract_hit(vertical_translation, hit)
+        } else if !climbing && angle_with_floor <= self.min_slope_slide_angle {
+            // Prevent the vertical movement from sliding down.
+            subtract_hit(horizontal_translation, hit)
+        } else {
+            // Let it slide (including climbing the slope).
+            slope_translation
+        }
+    }
+
+    fn handle_stairs<Colliders>(
+        &self,
+        colliders: &Colliders,
+        queries: &QueryPipeline,
+        character_shape: &dyn Shape,
+        character_pos: &Isometry<Real>,
+        up_extent: Real,
+        side_extent: Real,
+        query_groups: InteractionGroups,
+        filter: Option<&dyn Fn(ColliderHandle) -> bool>,
+        translation_remaining: &mut Vector<Real>,
+        result: &mut EffectiveCharacterMovement,
+    ) -> bool
+    where
+        Colliders: ComponentSet<ColliderFlags>
+            + ComponentSet<ColliderPosition>
+            + ComponentSet<ColliderShape>,
+    {
+        let autostep = try_ret!(self.autostep, false);
+        let offset = self.offset.eval(up_extent);
+        let min_width = autostep.min_width.eval(side_extent) + offset;
+        let max_height = autostep.max_height.eval(up_extent) + offset;
+
+        let [_, horizontal_translation] = self.split_into_components(translation_remaining);
+        let horizontal_dir = try_ret!(horizontal_translation.try_normalize(1.0e-5), false);
+
+        if queries
+            .cast_shape(
+                colliders,
+                character_pos,
+                &self.up,
+                character_shape,
+                max_height,
+                query_groups,
+                filter,
+            )
+            .is_some()
+        {
+            // We can’t go up.
+            return false;
+        }
+
+        let shifted_character_pos = Translation::from(*self.up * max_height) * character_pos;
+
+        if queries
+            .cast_shape(
+                colliders,
+                &shifted_character_pos,
+                &horizontal_dir,
+                character_shape,
+                min_width,
+                query_groups,
+                filter,
+            )
+            .is_some()
+        {
+            // We don’t have enough room on the stair to stay on it.
+            return false;
+        }
+
+        let stair_pos = Translation::from(horizontal_dir * min_width) * shifted_character_pos;
+        let stair_hit = match queries.cast_shape(
+            colliders,
+            &stair_pos,
+            &-*self.up,
+            character_shape,
+            max_height,
+            query_groups,
+            filter,
+        ) {
+            Some((_, hit)) => hit,
+            // There is no stair to step on.
+            None => return false,
+        };
+
+        // Check that we are not getting onto a ramp that is too steep after stepping.
+        if self.up.angle(&*stair_hit.normal1) > self.max_slope_climb_angle {
+            return false;
+        }
+
+        // We can step, we need to find the actual step height. The character is kept
+        // `offset` above the stair so that it doesn’t end up touching it.
+        let step_height = max_height - stair_hit.toi + offset;
+        let stepped_pos = Translation::from(*self.up * step_height) * character_pos;
+
+        // Make sure the character doesn’t end up penetrating anything after the step.
+        if queries
+            .intersection_with_shape(
+                colliders,
+                &stepped_pos,
+                character_shape,
+                query_groups,
+                filter,
+            )
+            .is_some()
+        {
+            return false;
+        }
+
+        // Remove the step height from the upward part of the remaining movement.
+        let step = *self.up * step_height;
+        *translation_remaining -= *self.up
+            * translation_remaining
+                .dot(&self.up)
+                .max(0.0)
+                .min(step_height);
+
+        // Advance the character on the step horizontally, to make sure further
+        // movement won’t just get stuck on its edge.
+        let horizontal_nudge =
+            horizontal_dir * horizontal_dir.dot(&*translation_remaining).min(min_width);
+        *translation_remaining -= horizontal_nudge;
+
+        result.translation += step + horizontal_nudge;
+        true
+    }
+}
+
+fn subtract_hit(translation: Vector<Real>, hit: &TOI) -> Vector<Real> {
+    let surface_correction = (-translation).dot(&hit.normal1).max(0.0);
+    // This fixes some instances of moving through walls.
+    let surface_correction = surface_correction * (1.0 + 1.0e-5);
+    translation + *hit.normal1 * surface_correction
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CharacterAutostep, CharacterLength, KinematicCharacterController};
+    use crate::geometry::InteractionGroups;
+    use crate::math::{Isometry, Vector};
+    use crate::test_utils::{cuboid, TestWorld};
+
+    #[cfg(feature = "dim2")]
+    fn tangent() -> Vector<crate::math::Real> {
+        Vector::y()
+    }
+    #[cfg(feature = "dim3")]
+    fn tangent() -> Vector<crate::math::Real> {
+        Vector::z()
+    }
+
+    fn world_with_step(step_height: crate::math::Real) -> TestWorld {
+        let mut world = TestWorld::new();
+        world.colliders.insert(
+            cuboid(10.0, 0.1, 10.0)
+                .translation(Vector::y() * -0.1)
+                .build(),
+        );
+        world.colliders.insert(
+            cuboid(1.0, step_height / 2.0, 5.0)
+                .translation(Vector::x() * 2.0 + Vector::y() * step_height / 2.0)
+                .build(),
+        );
+        world.update_query_pipeline();
+        world
+    }
+
+    fn stepping_controller() -> KinematicCharacterController {
+        KinematicCharacterController {
+            autostep: Some(CharacterAutostep {
+                max_height: CharacterLength::Absolute(0.3),
+                min_width: CharacterLength::Absolute(0.1),
+            }),
+            ..KinematicCharacterController::default()
+        }
+    }
+
+    #[test]
+    fn slides_along_wall() {
+        let mut world = TestWorld::new();
+        world
+            .colliders
+            .insert(cuboid(0.1, 5.0, 5.0).translation(Vector::x() * 1.0).build());
+        world.update_query_pipeline();
+
+        let character = cuboid(0.25, 0.5, 0.25).build();
+        let movement = KinematicCharacterController::default().move_shape(
+            &world.colliders,
+            &world.query_pipeline,
+            character.shape(),
+            &Isometry::identity(),
+            Vector::x() * 2.0 + tangent() * 2.0,
+            InteractionGroups::all(),
+            None,
+        );
+
+        // Stopped by the wall, but still moving along it.
+        assert!(movement.translation.x <= 0.65, "{}", movement.translation);
+        assert!(movement.translation.x > 0.6, "{}", movement.translation);
+        assert!(
+            (movement.translation.dot(&tangent()) - 2.0).abs() < 1.0e-3,
+            "{}",
+            movement.translation
+        );
+        assert_eq!(movement.collisions.len(), 1);
+    }
+
+    #[test]
+    fn climbs_step_below_max_height() {
+        let world = world_with_step(0.2);
+        let character = cuboid(0.25, 0.5, 0.25).build();
+        let movement = stepping_controller().move_shape(
+            &world.colliders,
+            &world.query_pipeline,
+            character.shape(),
+            &Isometry::new(Vector::y() * 0.51, na::zero()),
+            Vector::x() * 1.5,
+            InteractionGroups::all(),
+            None,
+        );
+
+        assert!(movement.translation.x > 1.4, "{}", movement.translation);
+        assert!(
+            (movement.translation.y - 0.2).abs() < 0.02,
+            "{}",
+            movement.translation
+        );
+        assert!(movement.grounded);
+    }
+
+    #[test]
+    fn blocked_by_step_above_max_height() {
+        let world = world_with_step(0.5);
+        let character = cuboid(0.25, 0.5, 0.25).build();
+        let movement = stepping_controller().move_shape(
+            &world.colliders,
+            &world.query_pipeline,
+            character.shape(),
+            &Isometry::new(Vector::y() * 0.51, na::zero()),
+            Vector::x() * 1.5,
+            InteractionGroups::all(),
+            None,
+        );
+
+        assert!(movement.translation.x <= 0.75, "{}", movement.translation);
+        assert!(
+            movement.translation.y.abs() < 1.0e-3,
+            "{}",
+            movement.translation
+        );
+    }
+}
@@ -0,0 +1,15 @@
+//! Utilities for controlling the trajectories of objects in a non-physical way.
+
+pub use self::character_controller::{
+    CharacterAutostep, CharacterCollision, CharacterLength, EffectiveCharacterMovement,
+    KinematicCharacterController,
+};
+
+#[cfg(all(feature = "dim3", feature = "default-sets"))]
+pub use self::ray_cast_vehicle_controller::{
+    DynamicRayCastVehicleController, RayCastInfo, Wheel, WheelTuning,
+};
+
+mod character_controller;
+#[cfg(all(feature = "dim3", feature = "default-sets"))]
+mod ray_cast_vehicle_controller;
@@ -0,0 +1,854 @@
+//! A vehicle controller based on ray-casting, ported and modified from Bullet’s `btRaycastVehicle`.
+
+use crate::dynamics::{RigidBody, RigidBodyHandle, RigidBodySet};
+use crate::geometry::{ColliderHandle, ColliderSet, InteractionGroups, Ray};
+use crate::math::{Point, Real, Rotation, Vector};
+use crate::pipeline::QueryPipeline;
+use crate::utils::{self, WCross};
+
+/// A controller to simulate vehicles using ray-casting for the wheels.
+pub struct DynamicRayCastVehicleController {
+    wheels: Vec<Wheel>,
+    forward_ws: Vec<Vector<Real>>,
+    axle: Vec<Vector<Real>>,
+    /// The current forward speed of the vehicle.
+    pub current_vehicle_speed: Real,
+
+    /// Handle of the vehicle’s chassis.
+    pub chassis: RigidBodyHandle,
+    /// The chassis’ local _up_ direction (`0 = x, 1 = y, 2 = z`)
+    pub index_up_axis: usize,
+    /// The chassis’ local _forward_ direction (`0 = x, 1 = y, 2 = z`)
+    pub index_forward_axis: usize,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+/// Parameters affecting the physical behavior of a wheel.
+pub struct WheelTuning {
+    /// The suspension stiffness.
+    ///
+    /// Increase this value if the suspension appears to not push the vehicle strong enough.
+    pub suspension_stiffness: Real,
+    /// The suspension’s damping when it is being compressed.
+    pub suspension_compression: Real,
+    /// The suspension’s damping when it is being released.
+    ///
+    /// Increase this value if the suspension appears to overshoot.
+    pub suspension_damping: Real,
+    /// The maximum distance the suspension can travel before and after its resting length.
+    pub max_suspension_travel: Real,
+    /// Parameter controlling how much traction the tire his.
+    ///
+    /// The larger the value, the more instantaneous braking will happen (with the risk of
+    /// causing the vehicle to flip if it’s too strong).
+    pub friction_slip: Real,
+    /// The maximum force applied by the suspension.
+    pub max_suspension_force: Real,
+}
+
+impl Default for WheelTuning {
+    fn default() -> Self {
+        Self {
+            suspension_stiffness: 5.88,
+            suspension_compression: 0.83,
+            suspension_damping: 0.88,
+            max_suspension_travel: 5.0,
+            friction_slip: 10.5,
+            max_suspension_force: 6000.0,
+        }
+    }
+}
+
+/// Objects used to initialize a wheel.
+struct WheelDesc {
+    /// The position of the wheel, relative to the chassis.
+    pub chassis_connection_cs: Point<Real>,
+    /// The direction of the wheel’s suspension, relative to the chassis.
+    ///
+    /// The ray-casting will happen following this direction to detect the ground.
+    pub direction_cs: Vector<Real>,
+    /// The wheel’s axle axis, relative to the chassis.
+    pub axle_cs: Vector<Real>,
+    /// The rest length of the wheel’s suspension spring.
+    pub suspension_rest_length: Real,
+    /// The maximum distance the suspension can travel before and after its resting length.
+    pub max_suspension_travel: Real,
+    /// The wheel’s radius.
+    pub radius: Real,
+
+    /// The suspension stiffness.
+    ///
+    /// Increase this value if the suspension appears to not push the vehicle strong enough.
+    pub suspension_stiffness: Real,
+    /// The suspension’s damping when it is being compressed.
+    pub damping_compression: Real,
+    /// The suspension’s damping when it is being released.
+    ///
+    /// Increase this value if the suspension appears to overshoot.
+    pub damping_relaxation: Real,
+    /// Parameter controlling how much traction the tire his.
+    ///
+    /// The larger the value, the more instantaneous braking will happen (with the risk of
+    /// causing the vehicle to flip if it’s too strong).
+    pub friction_slip: Real,
+    /// The maximum force applied by the suspension.
+    pub max_suspension_force: Real,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+/// A wheel attached to a vehicle.
+pub struct Wheel {
+    raycast_info: RayCastInfo,
+
+    center: Point<Real>,
+    wheel_direction_ws: Vector<Real>,
+    wheel_axle_ws: Vector<Real>,
+
+    /// The position of the wheel, relative to the chassis.
+    pub chassis_connection_point_cs: Point<Real>,
+    /// The direction of the wheel’s suspension, relative to the chassis.
+    ///
+    /// The ray-casting will happen following this direction to detect the ground.
+    pub direction_cs: Vector<Real>,
+    /// The wheel’s axle axis, relative to the chassis.
+    pub axle_cs: Vector<Real>,
+    /// The rest length of the wheel’s suspension spring.
+    pub suspension_rest_length: Real,
+    /// The maximum distance the suspension can travel before and after its resting length.
+    pub max_suspension_travel: Real,
+    /// The wheel’s radius.
+    pub radius: Real,
+    /// The suspension stiffness.
+    ///
+    /// Increase this value if the suspension appears to not push the vehicle strong enough.
+    pub suspension_stiffness: Real,
+    /// The suspension’s damping when it is being compressed.
+    pub damping_compression: Real,
+    /// The suspension’s damping when it is being released.
+    ///
+    /// Increase this value if the suspension appears to overshoot.
+    pub damping_relaxation: Real,
+    /// Parameter controlling how much traction the tire his.
+    ///
+    /// The larger the value, the more instantaneous braking will happen (with the risk of
+    /// causing the vehicle to flip if it’s too strong).
+    pub friction_slip: Real,
+    /// The wheel’s current rotation on its axle.
+    pub rotation: Real,
+    delta_rotation: Real,
+    roll_influence: Real,
+    /// The maximum force applied by the suspension.
+    pub max_suspension_force: Real,
+
+    /// The forward impulses applied by the wheel on the chassis.
+    pub forward_impulse: Real,
+    /// The side impulses applied by the wheel on the chassis.
+    pub side_impulse: Real,
+
+    /// The steering angle for this wheel.
+    pub steering: Real,
+    /// The forward force applied by this wheel on the chassis.
+    pub engine_force: Real,
+    /// The maximum amount of braking impulse applied to slow down the vehicle.
+    pub brake: Real,
+    // The steering and brake of the last update, used to wake up the chassis when they change.
+    last_steering: Real,
+    last_brake: Real,
+
+    clipped_inv_contact_dot_suspension: Real,
+    suspension_relative_velocity: Real,
+    /// The force applied by the suspension.
+    pub wheel_suspension_force: Real,
+    skid_info: Real,
+}
+
+impl Wheel {
+    fn new(info: WheelDesc) -> Self {
+        Self {
+            raycast_info: RayCastInfo::default(),
+            suspension_rest_length: info.suspension_rest_length,
+            max_suspension_travel: info.max_suspension_travel,
+            radius: info.radius,
+            suspension_stiffness: info.suspension_stiffness,
+            damping_compression: info.damping_compression,
+            damping_relaxation: info.damping_relaxation,
+            chassis_connection_point_cs: info.chassis_connection_cs,
+            direction_cs: info.direction_cs,
+            axle_cs: info.axle_cs,
+            wheel_direction_ws: info.direction_cs,
+            wheel_axle_ws: info.axle_cs,
+            center: Point::origin(),
+            friction_slip: info.friction_slip,
+            steering: 0.0,
+            engine_force: 0.0,
+            rotation: 0.0,
+            delta_rotation: 0.0,
+            brake: 0.0,
+            last_steering: 0.0,
+            last_brake: 0.0,
+            roll_influence: 0.1,
+            clipped_inv_contact_dot_suspension: 0.0,
+            suspension_relative_velocity: 0.0,
+            wheel_suspension_force: 0.0,
+            max_suspension_force: info.max_suspension_force,
+            skid_info: 0.0,
+            side_impulse: 0.0,
+            forward_impulse: 0.0,
+        }
+    }
+
+    /// Information about suspension and the ground obtained from the ray-casting
+    /// for this wheel.
+    pub fn raycast_info(&self) -> &RayCastInfo {
+        &self.raycast_info
+    }
+
+    /// The world-space center of the wheel.
+    pub fn center(&self) -> Point<Real> {
+        self.center
+    }
+
+    /// The world-space direction of the wheel’s suspension.
+    pub fn suspension(&self) -> Vector<Real> {
+        self.wheel_direction_ws
+    }
+
+    /// The world-space direction of the wheel’s axle.
+    pub fn axle(&self) -> Vector<Real> {
+        self.wheel_axle_ws
+    }
+
+    /// Is this wheel sliding on the ground because the friction impulses it could apply
+    /// were not strong enough?
+    pub fn is_sliding(&self) -> bool {
+        self.raycast_info.is_in_contact && self.skid_info < 1.0
+    }
+}
+
+/// Information about suspension and the ground obtained from the ray-casting
+/// to simulate a wheel’s suspension.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RayCastInfo {
+    /// The (world-space) contact normal between the wheel and the floor.
+    pub contact_normal_ws: Vector<Real>,
+    /// The (world-space) point hit by the wheel’s ray-cast.
+    pub contact_point_ws: Point<Real>,
+    /// The suspension length for the wheel.
+    pub suspension_length: Real,
+    /// The (world-space) starting point of the ray-cast.
+    pub hard_point_ws: Point<Real>,
+    /// Is the wheel in contact with the ground?
+    pub is_in_contact: bool,
+    /// The collider hit by the ray-cast.
+    pub ground_object: Option<ColliderHandle>,
+}
+
+impl Default for RayCastInfo {
+    fn default() -> Self {
+        Self {
+            contact_normal_ws: Vector::zeros(),
+            contact_point_ws: Point::origin(),
+            suspension_length: 0.0,
+            hard_point_ws: Point::origin(),
+            is_in_contact: false,
+            ground_object: None,
+        }
+    }
+}
+
+impl DynamicRayCastVehicleController {
+    /// Creates a new vehicle represented by the given rigid-body.
+    ///
+    /// Wheels have to be attached afterwards calling [`Self::add_wheel`].
+    pub fn new(chassis: RigidBodyHandle) -> Self {
+        Self {
+            wheels: vec![],
+            forward_ws: vec![],
+            axle: vec![],
+            current_vehicle_speed: 0.0,
+            chassis,
+            index_up_axis: 1,
+            index_forward_axis: 0,
+        }
+    }
+
+    /// Adds a wheel to this vehicle.
+    pub fn add_wheel(
+        &mut self,
+        chassis_connection_cs: Point<Real>,
+        direction_cs: Vector<Real>,
+        axle_cs: Vector<Real>,
+        suspension_rest_length: Real,
+        radius: Real,
+        tuning: &WheelTuning,
+    ) -> &mut Wheel {
+        let ci = WheelDesc {
+            chassis_connection_cs,
+            direction_cs,
+            axle_cs,
+            suspension_rest_length,
+            radius,
+            suspension_stiffness: tuning.suspension_stiffness,
+            damping_compression: tuning.suspension_compression,
+            damping_relaxation: tuning.suspension_damping,
+            friction_slip: tuning.friction_slip,
+            max_suspension_travel: tuning.max_suspension_travel,
+            max_suspension_force: tuning.max_suspension_force,
+        };
+
+        let wheel_id = self.wheels.len();
+        self.wheels.push(Wheel::new(ci));
+
+        &mut self.wheels[wheel_id]
+    }
+
+    /// The vehicle’s wheels.
+    pub fn wheels(&self) -> &[Wheel] {
+        &self.wheels
+    }
+
+    /// The mutable reference to the vehicle’s wheels.
+    pub fn wheels_mut(&mut self) -> &mut [Wheel] {
+        &mut self.wheels
+    }
+
+    /// Updates the vehicle’s velocity based on its suspension, engine force, and brake.
+    ///
+    /// This must be called once per timestep, before `PhysicsPipeline::step`.
+    ///
+    /// # Parameters
+    /// * `dt` - The timestep length, generally `IntegrationParameters::dt`.
+    /// * `query_groups` - the interaction groups which will be tested against the collider's `contact_group`
+    ///                   to determine if it should be taken into account by the wheels’ ray-casts.
+    /// * `filter` - a more fine-grained filter. This should exclude the chassis’ own colliders.
+    pub fn update_vehicle(
+        &mut self,
+        dt: Real,
+        bodies: &mut RigidBodySet,
+        colliders: &ColliderSet,
+        queries: &QueryPipeline,
+        query_groups: InteractionGroups,
+        filter: Option<&dyn Fn(ColliderHandle) -> bool>,
+    ) {
+        let chassis = try_ret!(bodies.get(self.chassis));
+
+        for wheel in &mut self.wheels {
+            wheel.update_transform(chassis);
+        }
+
+        self.current_vehicle_speed = chassis.linvel().norm();
+
+        let forward_w = chassis.position() * Vector::ith(self.index_forward_axis, 1.0);
+
+        if forward_w.dot(chassis.linvel()) < 0.0 {
+            self.current_vehicle_speed *= -1.0;
+        }
+
+        // Simulate suspension.
+        for wheel in &mut self.wheels {
+            wheel.ray_cast(colliders, queries, query_groups, filter, chassis);
+        }
+
+        let chassis_mass = chassis.mass();
+        self.update_suspension(chassis_mass);
+
+        let chassis = &mut bodies[self.chassis];
+
+        for wheel in &mut self.wheels {
+            if wheel.engine_force != 0.0
+                || wheel.steering != wheel.last_steering
+                || wheel.brake != wheel.last_brake
+            {
+                chassis.wake_up(true);
+            }
+
+            wheel.last_steering = wheel.steering;
+            wheel.last_brake = wheel.brake;
+        }
+
+        if chassis.is_sleeping() {
+            // The chassis is resting on its suspension. Don’t apply any impulse: they would
+            // accumulate into the velocity of the sleeping chassis without moving it.
+            return;
+        }
+
+        for wheel in &mut self.wheels {
+            // Apply the suspension force.
+            let suspension_force = wheel.wheel_suspension_force.min(wheel.max_suspension_force);
+            let impulse = wheel.raycast_info.contact_normal_ws * suspension_force * dt;
+            chassis.apply_impulse_at_point(impulse, wheel.raycast_info.contact_point_ws, false);
+        }
+
+        self.update_friction(bodies, colliders, dt);
+
+        let chassis = &bodies[self.chassis];
+
+        for wheel in &mut self.wheels {
+            // Only refresh the wheel’s world-space frame: its contact state is still the one
+            // computed by the ray-cast above.
+            wheel.update_world_transform(chassis);
+            let vel = chassis.velocity_at_point(&wheel.raycast_info.hard_point_ws);
+
+            if wheel.raycast_info.is_in_contact {
+                let mut fwd = chassis.position() * Vector::ith(self.index_forward_axis, 1.0);
+                let proj = fwd.dot(&wheel.raycast_info.contact_normal_ws);
+                fwd -= wheel.raycast_info.contact_normal_ws * proj;
+
+                let proj2 = fwd.dot(&vel);
+
+                wheel.delta_rotation = (proj2 * dt) / wheel.radius;
+            }
+
+            wheel.rotation += wheel.delta_rotation;
+            wheel.delta_rotation *= 0.99; // Damping of rotation when not in contact.
+        }
+    }
+
+    fn update_suspension(&mut self, chassis_mass: Real) {
+        for wheel in &mut self.wheels {
+            if wheel.raycast_info.is_in_contact {
+                // Spring.
+                let length_diff =
+                    wheel.suspension_rest_length - wheel.raycast_info.suspension_length;
+                let mut force = wheel.suspension_stiffness
+                    * length_diff
+                    * wheel.clipped_inv_contact_dot_suspension;
+
+                // Damper.
+                let projected_rel_vel = wheel.suspension_relative_velocity;
+                let susp_damping = if projected_rel_vel < 0.0 {
+                    wheel.damping_compression
+                } else {
+                    wheel.damping_relaxation
+                };
+                force -= susp_damping * projected_rel_vel;
+
+                wheel.wheel_suspension_force = (force * chassis_mass).max(0.0);
+            } else {
+                wheel.wheel_suspension_force = 0.0;
+            }
+        }
+    }
+
+    fn update_friction(&mut self, bodies: &mut RigidBodySet, colliders: &ColliderSet, dt: Real) {
+        let num_wheels = self.wheels.len();
+
+        if num_wheels == 0 {
+            return;
+        }
+
+        self.forward_ws.resize(num_wheels, Vector::zeros());
+        self.axle.resize(num_wheels, Vector::zeros());
+
+        let mut num_wheels_on_ground = 0;
+
+        for wheel in &mut self.wheels {
+            if wheel.raycast_info.ground_object.is_some() {
+                num_wheels_on_ground += 1;
+            }
+
+            wheel.side_impulse = 0.0;
+            wheel.forward_impulse = 0.0;
+        }
+
+        let chassis = &bodies[self.chassis];
+        let ground_body = |wheel: &Wheel| {
+            wheel
+                .raycast_info
+                .ground_object
+                .and_then(|h| colliders.get(h))
+                .and_then(|co| co.parent())
+                .and_then(|h| bodies.get(h))
+                .filter(|rb| rb.is_dynamic())
+        };
+
+        // Lateral friction.
+        for (i, wheel) in self.wheels.iter_mut().enumerate() {
+            if wheel.raycast_info.ground_object.is_none() {
+                continue;
+            }
+
+            let surf_normal_ws = wheel.raycast_info.contact_normal_ws;
+            let axle =
+                wheel.wheel_axle_ws - surf_normal_ws * wheel.wheel_axle_ws.dot(&surf_normal_ws);
+            self.axle[i] = axle.try_normalize(1.0e-5).unwrap_or_else(Vector::zeros);
+            self.forward_ws[i] = surf_normal_ws
+                .cross(&self.axle[i])
+                .try_normalize(1.0e-5)
+                .unwrap_or_else(Vector::zeros);
+
+            let contact_pt = &wheel.raycast_info.contact_point_ws;
+            wheel.side_impulse = resolve_single_bilateral(
+                chassis,
+                contact_pt,
+                ground_body(wheel),
+                contact_pt,
+                &self.axle[i],
+            );
+        }
+
+        let side_factor = 1.0;
+        let fwd_factor = 0.5;
+        let mut sliding = false;
+
+        // Longitudinal friction and engine.
+        for (i, wheel) in self.wheels.iter_mut().enumerate() {
+            wheel.forward_impulse = 0.0;
+            wheel.skid_info = 1.0;
+
+            if wheel.raycast_info.ground_object.is_none() {
+                continue;
+            }
+
+            let rolling_friction = if wheel.engine_force != 0.0 {
+                wheel.engine_force * dt
+            } else {
+                let contact_pt = WheelContactPoint::new(
+                    chassis,
+                    ground_body(wheel),
+                    wheel.raycast_info.contact_point_ws,
+                    self.forward_ws[i],
+                    wheel.brake,
+                );
+                contact_pt.calc_rolling_friction(num_wheels_on_ground)
+            };
+
+            let max_imp = wheel.wheel_suspension_force * dt * wheel.friction_slip;
+            let max_imp_squared = max_imp * max_imp;
+
+            wheel.forward_impulse = rolling_friction;
+
+            let x = wheel.forward_impulse * fwd_factor;
+            let y = wheel.side_impulse * side_factor;
+            let impulse_squared = x * x + y * y;
+
+            if impulse_squared > max_imp_squared {
+                sliding = true;
+                wheel.skid_info *= max_imp * utils::inv(impulse_squared.sqrt());
+            }
+        }
+
+        if sliding {
+            for wheel in &mut self.wheels {
+                if wheel.side_impulse != 0.0 && wheel.skid_info < 1.0 {
+                    wheel.forward_impulse *= wheel.skid_info;
+                    wheel.side_impulse *= wheel.skid_info;
+                }
+            }
+        }
+
+        // Apply the impulses.
+        let chassis = &mut bodies[self.chassis];
+
+        for (i, wheel) in self.wheels.iter().enumerate() {
+            let mut impulse_point = wheel.raycast_info.contact_point_ws;
+
+            if wheel.forward_impulse != 0.0 {
+                chassis.apply_impulse_at_point(
+                    self.forward_ws[i] * wheel.forward_impulse,
+                    impulse_point,
+                    false,
+                );
+            }
+
+            if wheel.side_impulse != 0.0 {
+                let side_impulse = self.axle[i] * wheel.side_impulse;
+                let chassis_world_up =
+                    chassis.position().rotation * Vector::ith(self.index_up_axis, 1.0);
+
+                // Move the impulse point toward the center of mass to reduce the rolling torque.
+                impulse_point -= chassis_world_up
+                    * (chassis_world_up.dot(&(impulse_point - chassis.rb_mprops.world_com))
+                        * (1.0 - wheel.roll_influence));
+
+                chassis.apply_impulse_at_point(side_impulse, impulse_point, false);
+            }
+        }
+    }
+}
+
+impl Wheel {
+    fn update_transform(&mut self, chassis: &RigidBody) {
+        self.raycast_info.is_in_contact = false;
+        self.update_world_transform(chassis);
+    }
+
+    fn update_world_transform(&mut self, chassis: &RigidBody) {
+        let chassis_pos = chassis.position();
+
+        self.raycast_info.hard_point_ws = chassis_pos * self.chassis_connection_point_cs;
+        self.wheel_direction_ws = chassis_pos * self.direction_cs;
+
+        let steering_orn = Rotation::new(-self.wheel_direction_ws * self.steering);
+        self.wheel_axle_ws = steering_orn * (chassis_pos * self.axle_cs);
+        self.center = self.raycast_info.hard_point_ws
+            + self.wheel_direction_ws * self.raycast_info.suspension_length;
+    }
+
+    fn ray_cast(
+        &mut self,
+        colliders: &ColliderSet,
+        queries: &QueryPipeline,
+        query_groups: InteractionGroups,
+        filter: Option<&dyn Fn(ColliderHandle) -> bool>,
+        chassis: &RigidBody,
+    ) {
+        let raylen = self.suspension_rest_length + self.radius;
+        let rayvector = self.wheel_direction_ws * raylen;
+        let source = self.raycast_info.hard_point_ws;
+        self.raycast_info.contact_point_ws = source + rayvector;
+        self.raycast_info.ground_object = None;
+
+        let ray = Ray::new(source, rayvector);
+        let hit = queries.cast_ray_and_get_normal(colliders, &ray, 1.0, true, query_groups, filter);
+
+        if let Some((collider_hit, mut hit)) = hit {
+            if hit.toi == 0.0 {
+                // The ray started inside of the ground: cast it upward to find the surface normal.
+                let collider = &colliders[collider_hit];
+                let up_ray = Ray::new(source + rayvector, -rayvector);
+                if let Some(hit2) = collider.shape().cast_ray_and_get_normal(
+                    collider.position(),
+                    &up_ray,
+                    1.0,
+                    false,
+                ) {
+                    hit.normal = -hit2.normal;
+                }
+
+                if hit.normal == Vector::zeros() {
+                    // If the normal is still not defined, use the opposite of the ray direction.
+                    hit.normal = -self.wheel_direction_ws;
+                }
+            }
+
+            self.raycast_info.contact_normal_ws = hit.normal;
+            self.raycast_info.is_in_contact = true;
+            self.raycast_info.ground_object = Some(collider_hit);
+
+            // Clamp on max suspension travel.
+            let hit_distance = hit.toi * raylen;
+            let min_suspension_length = self.suspension_rest_length - self.max_suspension_travel;
+            let max_suspension_length = self.suspension_rest_length + self.max_suspension_travel;
+            self.raycast_info.suspension_length = (hit_distance - self.radius)
+                .max(min_suspension_length)
+                .min(max_suspension_length);
+            self.raycast_info.contact_point_ws = ray.point_at(hit.toi);
+
+            let denominator = self
+                .raycast_info
+                .contact_normal_ws
+                .dot(&self.wheel_direction_ws);
+            let chassis_velocity_at_contact_point =
+                chassis.velocity_at_point(&self.raycast_info.contact_point_ws);
+            let proj_vel = self
+                .raycast_info
+                .contact_normal_ws
+                .dot(&chassis_velocity_at_contact_point);
+
+            if denominator >= -0.1 {
+                self.suspension_relative_velocity = 0.0;
+                self.clipped_inv_contact_dot_suspension = 1.0 / 0.1;
+            } else {
+                let inv = -1.0 / denominator;
+                self.suspension_relative_velocity = proj_vel * inv;
+                self.clipped_inv_contact_dot_suspension = inv;
+            }
+        } else {
+            // No contact, put the wheel info in its rest position.
+            self.raycast_info.suspension_length = self.suspension_rest_length;
+            self.suspension_relative_velocity = 0.0;
+            self.raycast_info.contact_normal_ws = -self.wheel_direction_ws;
+            self.clipped_inv_contact_dot_suspension = 1.0;
+        }
+    }
+}
+
+fn impulse_denominator(body: &RigidBody, pos: &Point<Real>, n: &Vector<Real>) -> Real {
+    let dpt = pos - body.rb_mprops.world_com;
+    let ang = body.rb_mprops.effective_world_inv_inertia_sqrt * dpt.gcross(*n);
+    body.rb_mprops.local_mprops.inv_mass + ang.norm_squared()
+}
+
+struct WheelContactPoint<'a> {
+    body0: &'a RigidBody,
+    body1: Option<&'a RigidBody>,
+    friction_position_world: Point<Real>,
+    friction_direction_world: Vector<Real>,
+    jac_diag_ab_inv: Real,
+    max_impulse: Real,
+}
+
+impl<'a> WheelContactPoint<'a> {
+    fn new(
+        body0: &'a RigidBody,
+        body1: Option<&'a RigidBody>,
+        friction_position_world: Point<Real>,
+        friction_direction_world: Vector<Real>,
+        max_impulse: Real,
+    ) -> Self {
+        let denom0 =
+            impulse_denominator(body0, &friction_position_world, &friction_direction_world);
+        let denom1 = body1
+            .map(|body1| {
+                impulse_denominator(body1, &friction_position_world, &friction_direction_world)
+            })
+            .unwrap_or(0.0);
+
+        Self {
+            body0,
+            body1,
+            friction_position_world,
+            friction_direction_world,
+            jac_diag_ab_inv: utils::inv(denom0 + denom1),
+            max_impulse,
+        }
+    }
+
+    fn calc_rolling_friction(&self, num_wheels_on_ground: usize) -> Real {
+        let contact_pos_world = self.friction_position_world;
+        let max_impulse = self.max_impulse;
+
+        let vel1 = self.body0.velocity_at_point(&contact_pos_world);
+        let vel2 = self
+            .body1
+            .map(|b| b.velocity_at_point(&contact_pos_world))
+            .unwrap_or_else(Vector::zeros);
+        let vrel = self.friction_direction_world.dot(&(vel1 - vel2));
+
+        // Calculate the friction that moves us to zero relative velocity.
+        (-vrel * self.jac_diag_ab_inv / (num_wheels_on_ground as Real))
+            .max(-max_impulse)
+            .min(max_impulse)
+    }
+}
+
+fn resolve_single_bilateral(
+    body1: &RigidBody,
+    pt1: &Point<Real>,
+    body2: Option<&RigidBody>,
+    pt2: &Point<Real>,
+    normal: &Vector<Real>,
+) -> Real {
+    let vel1 = body1.velocity_at_point(pt1);
+    let vel2 = body2
+        .map(|b| b.velocity_at_point(pt2))
+        .unwrap_or_else(Vector::zeros);
+    let rel_vel = normal.dot(&(vel1 - vel2));
+
+    let jac_diag_ab = impulse_denominator(body1, pt1, normal)
+        + body2
+            .map(|b| impulse_denominator(b, pt2, normal))
+            .unwrap_or(0.0);
+
+    // TODO: move this into a proper structure.
+    let contact_damping = 0.2;
+    -contact_damping * rel_vel * utils::inv(jac_diag_ab)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{DynamicRayCastVehicleController, WheelTuning};
+    use crate::dynamics::RigidBodyBuilder;
+    use crate::geometry::{ColliderHandle, InteractionGroups};
+    use crate::math::{Point, Vector};
+    use crate::test_utils::{cuboid, TestWorld};
+
+    fn vehicle_world() -> (TestWorld, DynamicRayCastVehicleController, ColliderHandle) {
+        let mut world = TestWorld::new();
+        world.add_ground();
+
+        let chassis = world.bodies.insert(
+            RigidBodyBuilder::dynamic()
+                .translation(Vector::y() * 1.0)
+                .build(),
+        );
+        let chassis_collider = world.colliders.insert_with_parent(
+            cuboid(1.0, 0.25, 0.5).build(),
+            chassis,
+            &mut world.bodies,
+        );
+
+        let tuning = WheelTuning {
+            suspension_stiffness: 30.0,
+            ..WheelTuning::default()
+        };
+        let mut vehicle = DynamicRayCastVehicleController::new(chassis);
+        for x in [-0.8, 0.8] {
+            for z in [-0.5, 0.5] {
+                vehicle.add_wheel(
+                    Point::new(x, 0.0, z),
+                    -Vector::y(),
+                    Vector::z(),
+                    0.3,
+                    0.3,
+                    &tuning,
+                );
+            }
+        }
+
+        (world, vehicle, chassis_collider)
+    }
+
+    fn step(
+        world: &mut TestWorld,
+        vehicle: &mut DynamicRayCastVehicleController,
+        chassis_collider: ColliderHandle,
+    ) {
+        world.update_query_pipeline();
+        vehicle.update_vehicle(
+            world.integration_parameters.dt,
+            &mut world.bodies,
+            &world.colliders,
+            &world.query_pipeline,
+            InteractionGroups::all(),
+            Some(&|handle| handle != chassis_collider),
+        );
+        world.step();
+    }
+
+    #[test]
+    fn vehicle_settles_on_ground() {
+        let (mut world, mut vehicle, chassis_collider) = vehicle_world();
+
+        for _ in 0..300 {
+            step(&mut world, &mut vehicle, chassis_collider);
+        }
+
+        assert!(vehicle
+            .wheels()
+            .iter()
+            .all(|wheel| wheel.raycast_info().is_in_contact));
+        // The chassis rests on its suspension, without touching the ground.
+        let height = world.bodies[vehicle.chassis].translation().y;
+        assert!(height > 0.4 && height < 0.7, "{}", height);
+        let linvel = world.bodies[vehicle.chassis].linvel();
+        assert!(linvel.norm() < 1.0e-2, "{}", linvel);
+    }
+
+    #[test]
+    fn vehicle_accelerates_under_engine_force() {
+        let (mut world, mut vehicle, chassis_collider) = vehicle_world();
+
+        for _ in 0..300 {
+            step(&mut world, &mut vehicle, chassis_collider);
+        }
+
+        // The throttle must wake up the resting vehicle.
+        assert!(world.bodies[vehicle.chassis].is_sleeping());
+
+        for wheel in vehicle.wheels_mut() {
+            wheel.engine_force = -20.0;
+        }
+
+        for _ in 0..60 {
+            step(&mut world, &mut vehicle, chassis_collider);
+        }
+
+        // Reverse throttle moves the vehicle backward, and the wheels roll with it.
+        assert!(world.bodies[vehicle.chassis].linvel().x < -0.5);
+        assert!(vehicle.current_vehicle_speed < -0.5);
+        assert!(vehicle.wheels().iter().all(|wheel| wheel.rotation < 0.0));
+    }
+}
@@ -0,0 +1,58 @@
+use std::fmt::{Display, Formatter, Result};
+
+/// The number of buckets of the island size histogram of the [`IslandCounters`].
+pub const ISLAND_SIZE_HISTOGRAM_LEN: usize = 12;
+
+/// Performance counters related to the islands of rigid-bodies solved during one timestep.
+#[derive(Default, Clone, Copy)]
+pub struct IslandCounters {
+    /// The number of islands solved, summed over all the CCD substeps.
+    pub num_islands: usize,
+    /// The total number of rigid-bodies part of the solved islands.
+    pub num_island_bodies: usize,
+    /// The number of rigid-bodies of the largest island solved.
+    pub max_island_size: usize,
+    /// The histogram of the sizes of the solved islands.
+    ///
+    /// The bucket `i` counts the islands with a number of rigid-bodies in `[2^i, 2^(i + 1))`.
+    /// The last bucket also counts all the larger islands.
+    pub island_size_histogram: [usize; ISLAND_SIZE_HISTOGRAM_LEN],
+}
+
+impl IslandCounters {
+    /// Creates a new counter initialized to zero.
+    pub fn new() -> Self {
+        IslandCounters {
+            num_islands: 0,
+            num_island_bodies: 0,
+            max_island_size: 0,
+            island_size_histogram: [0; ISLAND_SIZE_HISTOGRAM_LEN],
+        }
+    }
+
+    /// Resets this counter to 0.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Accounts for a solved island containing `size` rigid-bodies.
+    pub fn add_island(&mut self, size: usize) {
+        self.num_islands += 1;
+        self.num_island_bodies += size;
+        self.max_island_size = self.max_island_size.max(size);
+
+        if size > 0 {
+            let bucket = (usize::BITS - 1 - size.leading_zeros()) as usize;
+            self.island_size_histogram[bucket.min(ISLAND_SIZE_HISTOGRAM_LEN - 1)] += 1;
+        }
+    }
+}
+
+impl Display for IslandCounters {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        writeln!(f, "Number of islands: {}", self.num_islands)?;
+        writeln!(f, "Number of bodies in islands: {}", self.num_island_bodies)?;
+        writeln!(f, "Largest island size: {}", self.max_island_size)?;
+        writeln!(f, "Island size histogram: {:?}", self.island_size_histogram)
+    }
+}
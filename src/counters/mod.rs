@@ -4,12 +4,14 @@ use std::fmt::{Display, Formatter, Result};
 
 pub use self::ccd_counters::CCDCounters;
 pub use self::collision_detection_counters::CollisionDetectionCounters;
+pub use self::island_counters::{IslandCounters, ISLAND_SIZE_HISTOGRAM_LEN};
 pub use self::solver_counters::SolverCounters;
 pub use self::stages_counters::StagesCounters;
 pub use self::timer::Timer;
 
 mod ccd_counters;
 mod collision_detection_counters;
+mod island_counters;
 mod solver_counters;
 mod stages_counters;
 mod timer;
@@ -31,6 +33,8 @@ pub struct Counters {
     pub solver: SolverCounters,
     /// Counters for the CCD resolution stage.
     pub ccd: CCDCounters,
+    /// Counters of the islands solved during the timestep.
+    pub islands: IslandCounters,
 }
 
 impl Counters {
@@ -44,6 +48,7 @@ impl Counters {
             cd: CollisionDetectionCounters::new(),
             solver: SolverCounters::new(),
             ccd: CCDCounters::new(),
+            islands: IslandCounters::new(),
         }
     }
 
@@ -124,6 +129,7 @@ impl Counters {
             self.cd.reset();
             self.solver.reset();
             self.ccd.reset();
+            self.islands.reset();
         }
     }
 }
@@ -226,6 +232,7 @@ impl Display for Counters {
         self.stages.fmt(f)?;
         self.cd.fmt(f)?;
         self.solver.fmt(f)?;
+        self.islands.fmt(f)?;
         writeln!(f, "Custom timer: {}", self.custom)
     }
 }
@@ -31,10 +31,14 @@ pub struct IntegrationParameters {
     /// 0-1: multiplier for how much of the joint violation
     /// will be compensated for during the velocity solve.
     /// (default `1.0`).
+    ///
+    /// Can be overridden for each joint with `GenericJoint::softness`.
     pub joint_erp: Real,
 
     /// The fraction of critical damping applied to the joint for constraints regularization.
     /// (default `0.25`).
+    ///
+    /// Can be overridden for each joint with `GenericJoint::softness`.
     pub joint_damping_ratio: Real,
 
     /// Amount of penetration the engine wont attempt to correct (default: `0.001m`).
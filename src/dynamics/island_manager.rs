@@ -5,7 +5,9 @@ use crate::dynamics::{
 };
 use crate::geometry::{ColliderParent, NarrowPhase};
 use crate::math::Real;
+use crate::pipeline::{BodyActivationEvent, EventHandler};
 use crate::utils::WDot;
+use std::collections::HashSet;
 
 /// Structure responsible for maintaining the set of active rigid-bodies, and
 /// putting non-moving rigid-bodies to sleep to save computation times.
@@ -20,6 +22,10 @@ pub struct IslandManager {
     can_sleep: Vec<RigidBodyHandle>, // Workspace.
     #[cfg_attr(feature = "serde-serialize", serde(skip))]
     stack: Vec<RigidBodyHandle>, // Workspace.
+    #[cfg_attr(feature = "serde-serialize", serde(skip))]
+    woken_up: Vec<RigidBodyHandle>, // Workspace.
+    #[cfg_attr(feature = "serde-serialize", serde(skip))]
+    fallen_asleep: Vec<RigidBodyHandle>, // Workspace.
 }
 
 impl IslandManager {
@@ -32,11 +38,14 @@ impl IslandManager {
             active_set_timestamp: 0,
             can_sleep: vec![],
             stack: vec![],
+            woken_up: vec![],
+            fallen_asleep: vec![],
         }
     }
 
-    pub(crate) fn num_islands(&self) -> usize {
-        self.active_islands.len() - 1
+    /// The number of islands of active dynamic rigid-bodies computed during the last timestep.
+    pub fn num_islands(&self) -> usize {
+        self.active_islands.len().saturating_sub(1)
     }
 
     /// Update this data-structure after one or multiple rigid-bodies have been removed for `bodies`.
@@ -113,6 +122,70 @@ impl IslandManager {
         }
     }
 
+    /// Puts the specified rigid-body to sleep if it is dynamic, and removes it from the active set.
+    ///
+    /// The islands computed during the last timestep are kept, minus this rigid-body.
+    pub(crate) fn put_to_sleep<Bodies>(&mut self, bodies: &mut Bodies, handle: RigidBodyHandle)
+    where
+        Bodies: ComponentSetMut<RigidBodyActivation>
+            + ComponentSetMut<RigidBodyVelocity>
+            + ComponentSetOption<RigidBodyType>
+            + ComponentSetMut<RigidBodyIds>,
+    {
+        let rb_type: Option<RigidBodyType> = bodies.get(handle.0).copied();
+        if rb_type != Some(RigidBodyType::Dynamic) {
+            return;
+        }
+
+        bodies.set_internal(handle.0, RigidBodyVelocity::zero());
+        bodies.map_mut_internal(handle.0, |activation: &mut RigidBodyActivation| {
+            activation.sleep()
+        });
+
+        // Reset the ids so the body is detected as waking up the next time it is simulated.
+        let ids: RigidBodyIds = *bodies.index(handle.0);
+        bodies.set_internal(handle.0, RigidBodyIds::default());
+
+        let removed_id = ids.active_set_id;
+        if self.active_dynamic_set.get(removed_id) != Some(&handle) {
+            return;
+        }
+
+        // NOTE: the active set is shifted instead of swap-removed so that the islands
+        //       remain contiguous ranges of the active set.
+        let num_island_bodies = self.active_islands.last().copied().unwrap_or(0);
+        let mut removed_island = None;
+        self.active_dynamic_set.remove(removed_id);
+
+        if removed_id < num_island_bodies {
+            let island_id = ids.active_island_id;
+            for boundary in &mut self.active_islands[island_id + 1..] {
+                *boundary -= 1;
+            }
+
+            if self.active_islands[island_id] == self.active_islands[island_id + 1] {
+                // The island is now empty.
+                self.active_islands.remove(island_id + 1);
+                removed_island = Some(island_id);
+            }
+        }
+
+        let num_island_bodies = self.active_islands.last().copied().unwrap_or(0);
+        for (i, moved) in self.active_dynamic_set.iter().enumerate().skip(removed_id) {
+            let islands = &self.active_islands;
+            bodies.map_mut_internal(moved.0, |ids: &mut RigidBodyIds| {
+                ids.active_set_id = i;
+
+                if i < num_island_bodies {
+                    if matches!(removed_island, Some(removed) if ids.active_island_id > removed) {
+                        ids.active_island_id -= 1;
+                    }
+                    ids.active_set_offset = i - islands[ids.active_island_id];
+                }
+            });
+        }
+    }
+
     /// Iter through all the active kinematic rigid-bodies on this set.
     pub fn active_kinematic_bodies(&self) -> &[RigidBodyHandle] {
         &self.active_kinematic_set[..]
@@ -123,11 +196,68 @@ impl IslandManager {
         &self.active_dynamic_set[..]
     }
 
-    pub(crate) fn active_island(&self, island_id: usize) -> &[RigidBodyHandle] {
+    /// Iter through the islands of active dynamic rigid-bodies computed during the last timestep.
+    ///
+    /// Each island is a group of rigid-bodies solved together because they are touching or
+    /// jointed, directly or indirectly.
+    pub fn active_islands(&self) -> impl Iterator<Item = &[RigidBodyHandle]> {
+        (0..self.num_islands()).map(move |i| self.active_island(i))
+    }
+
+    /// The handles of the rigid-bodies of the active island with the given id.
+    ///
+    /// Panics if `island_id` isn’t smaller than `self.num_islands()`.
+    pub fn active_island(&self, island_id: usize) -> &[RigidBodyHandle] {
         let island_range = self.active_islands[island_id]..self.active_islands[island_id + 1];
         &self.active_dynamic_set[island_range]
     }
 
+    /// The id of the active island containing the given rigid-body.
+    ///
+    /// Returns `None` if the rigid-body wasn’t part of any active island during the last
+    /// timestep (for example because it is sleeping, or isn’t dynamic).
+    pub fn active_island_id<Bodies>(
+        &self,
+        bodies: &Bodies,
+        handle: RigidBodyHandle,
+    ) -> Option<usize>
+    where
+        Bodies: ComponentSetOption<RigidBodyIds>,
+    {
+        let ids = bodies.get(handle.0)?;
+        let num_island_bodies = self.active_islands.last().copied().unwrap_or(0);
+
+        // NOTE: bodies woken up since the last timestep are in the active set, but not
+        //       part of any island yet.
+        if ids.active_set_id < num_island_bodies
+            && self.active_dynamic_set.get(ids.active_set_id) == Some(&handle)
+        {
+            Some(ids.active_island_id)
+        } else {
+            None
+        }
+    }
+
+    /// The smallest amount of time the rigid-bodies of the given active island have been
+    /// allowed to sleep for.
+    ///
+    /// The island falls asleep once this reaches `RigidBodyActivation::default_time_until_sleep()`.
+    ///
+    /// Panics if `island_id` isn’t smaller than `self.num_islands()`.
+    pub fn active_island_time_since_can_sleep<Bodies>(
+        &self,
+        bodies: &Bodies,
+        island_id: usize,
+    ) -> Real
+    where
+        Bodies: ComponentSet<RigidBodyActivation>,
+    {
+        self.active_island(island_id)
+            .iter()
+            .map(|handle| bodies.index(handle.0).time_since_can_sleep)
+            .fold(Real::MAX, Real::min)
+    }
+
     #[inline(always)]
     pub(crate) fn iter_active_bodies<'a>(&'a self) -> impl Iterator<Item = RigidBodyHandle> + 'a {
         self.active_dynamic_set
@@ -150,6 +280,7 @@ impl IslandManager {
         impulse_joints: &ImpulseJointSet,
         multibody_joints: &MultibodyJointSet,
         min_island_size: usize,
+        events: &dyn EventHandler,
     ) where
         Bodies: ComponentSetMut<RigidBodyIds>
             + ComponentSetMut<RigidBodyActivation>
@@ -166,9 +297,12 @@ impl IslandManager {
         // Update the energy of every rigid body and
         // keep only those that may not sleep.
         //        let t = instant::now();
+        let prev_active_set_timestamp = self.active_set_timestamp;
         self.active_set_timestamp += 1;
         self.stack.clear();
         self.can_sleep.clear();
+        self.woken_up.clear();
+        self.fallen_asleep.clear();
 
         // NOTE: the `.rev()` is here so that two successive timesteps preserve
         // the order of the bodies in the `active_dynamic_set` vec. This reversal
@@ -279,6 +413,10 @@ impl IslandManager {
             push_contacting_bodies(rb_colliders, colliders, narrow_phase, &mut self.stack);
 
             for inter in impulse_joints.joints_with(handle) {
+                if !inter.2.data.is_enabled() {
+                    continue;
+                }
+
                 let other = crate::utils::select_other((inter.0, inter.1), handle);
                 self.stack.push(other);
             }
@@ -287,6 +425,14 @@ impl IslandManager {
                 self.stack.push(other);
             }
 
+            // A body that wasn’t part of the previous active set is waking up.
+            if rb_ids.active_set_timestamp != prev_active_set_timestamp {
+                let activation: &RigidBodyActivation = bodies.index(handle.0);
+                if activation.events_enabled {
+                    self.woken_up.push(handle);
+                }
+            }
+
             bodies.map_mut_internal(handle.0, |activation: &mut RigidBodyActivation| {
                 activation.wake_up(false);
             });
@@ -308,16 +454,90 @@ impl IslandManager {
         //            self.active_islands.len() - 1
         //        );
 
+        for handle in &self.woken_up {
+            let rb_ids: &RigidBodyIds = bodies.index(handle.0);
+            let event = BodyActivationEvent {
+                body: *handle,
+                sleeping: false,
+            };
+            events.handle_body_activation_event(event, self.active_island(rb_ids.active_island_id));
+        }
+
         // Actually put to sleep bodies which have not been detected as awake.
         for h in &self.can_sleep {
             let activation: &RigidBodyActivation = bodies.index(h.0);
             if activation.sleeping {
+                if activation.events_enabled {
+                    self.fallen_asleep.push(*h);
+                }
+
                 bodies.set_internal(h.0, RigidBodyVelocity::zero());
                 bodies.map_mut_internal(h.0, |activation: &mut RigidBodyActivation| {
                     activation.sleep()
                 });
             }
         }
+
+        if self.fallen_asleep.is_empty() {
+            return;
+        }
+
+        // Gather the sleeping islands of the bodies that just fell asleep. Because sleeping
+        // bodies aren’t traversed above, these are the groups of sleeping bodies touching or
+        // jointed to each other.
+        let fallen_asleep: HashSet<_> = self.fallen_asleep.iter().copied().collect();
+        let mut visited = HashSet::new();
+        let mut island = vec![];
+
+        for h in &self.fallen_asleep {
+            if visited.contains(h) {
+                // The event was already emitted with the island of another body.
+                continue;
+            }
+
+            island.clear();
+            self.stack.clear();
+            self.stack.push(*h);
+
+            while let Some(handle) = self.stack.pop() {
+                let (rb_status, activation, rb_colliders): (
+                    &RigidBodyType,
+                    &RigidBodyActivation,
+                    &RigidBodyColliders,
+                ) = bodies.index_bundle(handle.0);
+
+                if !rb_status.is_dynamic() || !activation.sleeping || !visited.insert(handle) {
+                    continue;
+                }
+
+                push_contacting_bodies(rb_colliders, colliders, narrow_phase, &mut self.stack);
+
+                for inter in impulse_joints.joints_with(handle) {
+                    if inter.2.data.is_enabled() {
+                        let other = crate::utils::select_other((inter.0, inter.1), handle);
+                        self.stack.push(other);
+                    }
+                }
+
+                for other in multibody_joints.attached_bodies(handle) {
+                    self.stack.push(other);
+                }
+
+                island.push(handle);
+            }
+
+            for handle in &island {
+                // NOTE: only emit events for the bodies that just fell asleep, not for the
+                //       ones that were already sleeping.
+                if fallen_asleep.contains(handle) {
+                    let event = BodyActivationEvent {
+                        body: *handle,
+                        sleeping: true,
+                    };
+                    events.handle_body_activation_event(event, &island);
+                }
+            }
+        }
     }
 }
 
@@ -330,3 +550,90 @@ fn update_energy(activation: &mut RigidBodyActivation, sq_linvel: Real, sq_angve
         activation.time_since_can_sleep = 0.0;
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::dynamics::RigidBodyActivation;
+    use crate::math::Vector;
+    use crate::test_utils::TestWorld;
+
+    #[test]
+    fn island_inspection_and_counters() {
+        let mut world = TestWorld::new();
+        world.pipeline.counters.enable();
+        world.integration_parameters.min_island_size = 1;
+
+        // Two stacks of two balls each, far away from each other.
+        let handles: Vec<_> = [-10.0, 10.0]
+            .into_iter()
+            .flat_map(|x| [0.0, 1.0].map(|y| (x, y)))
+            .map(|(x, y)| world.add_ball(Vector::x() * x + Vector::y() * y, 0.5))
+            .collect();
+
+        world.step();
+
+        let islands = &world.islands;
+        let bodies = &world.bodies;
+        assert_eq!(islands.num_islands(), 2);
+        assert!(islands.active_islands().all(|island| island.len() == 2));
+
+        let island_ids: Vec<_> = handles
+            .iter()
+            .map(|h| islands.active_island_id(bodies, *h).unwrap())
+            .collect();
+        assert_eq!(island_ids[0], island_ids[1]);
+        assert_eq!(island_ids[2], island_ids[3]);
+        assert_ne!(island_ids[0], island_ids[2]);
+        assert!(islands.active_island(island_ids[0]).contains(&handles[0]));
+        assert!(
+            islands.active_island_time_since_can_sleep(bodies, island_ids[0])
+                < RigidBodyActivation::default_time_until_sleep()
+        );
+
+        let counters = &world.pipeline.counters.islands;
+        assert_eq!(counters.num_islands, 2);
+        assert_eq!(counters.max_island_size, 2);
+        assert_eq!(counters.island_size_histogram[1], 2);
+    }
+
+    #[test]
+    fn put_to_sleep_keeps_islands_consistent() {
+        let mut world = TestWorld::without_gravity();
+        world.integration_parameters.min_island_size = 1;
+
+        // Three pairs of touching balls, far away from each other.
+        let handles: Vec<_> = [-10.0, 0.0, 10.0]
+            .into_iter()
+            .flat_map(|x| [0.0, 0.9].map(|y| (x, y)))
+            .map(|(x, y)| world.add_ball(Vector::x() * x + Vector::y() * y, 0.5))
+            .collect();
+
+        world.step();
+        assert_eq!(world.islands.num_islands(), 3);
+
+        // Empty the island of the first pair, and shrink the one of the second pair.
+        for handle in &handles[..3] {
+            world.islands.put_to_sleep(&mut world.bodies, *handle);
+            assert!(world.bodies[*handle].is_sleeping());
+        }
+
+        let islands = &world.islands;
+        let bodies = &world.bodies;
+        assert_eq!(islands.num_islands(), 2);
+        assert_eq!(islands.active_dynamic_bodies().len(), 3);
+
+        for handle in &handles[..3] {
+            assert_eq!(islands.active_island_id(bodies, *handle), None);
+        }
+
+        for (i, handle) in islands.active_dynamic_bodies().iter().enumerate() {
+            let island_id = islands.active_island_id(bodies, *handle).unwrap();
+            let island = islands.active_island(island_id);
+            assert_eq!(island[bodies[*handle].rb_ids.active_set_offset], *handle);
+            assert_eq!(bodies[*handle].rb_ids.active_set_id, i);
+        }
+
+        assert_eq!(islands.active_island(0), &handles[3..4]);
+        assert_eq!(islands.active_island(1), &handles[4..6]);
+    }
+}
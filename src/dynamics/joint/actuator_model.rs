@@ -0,0 +1,138 @@
+use crate::math::Real;
+use crate::utils::WReal;
+
+/// The model of the actuator driving a joint motor.
+///
+/// The actuator model limits the force (or torque, for angular motors) the motor can deliver,
+/// depending on the current velocity of the motorized degree of freedom. These limits are
+/// further clamped by the motor’s `max_force`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+pub enum ActuatorModel {
+    /// An ideal actuator, able to deliver up to the motor’s `max_force` in both directions,
+    /// at any velocity.
+    Ideal,
+    /// A DC motor with a linear force-velocity curve.
+    ///
+    /// The motor delivers its stall force at rest, and the force it can deliver in the
+    /// direction of motion decreases linearly until it reaches zero at the no-load velocity.
+    /// Conversely, it can deliver more braking force (up to the stall force) when moving.
+    DcMotor {
+        /// The maximum force delivered by the motor at rest.
+        stall_force: Real,
+        /// The velocity at which the motor can’t deliver any force in the direction of motion.
+        no_load_velocity: Real,
+    },
+    /// A servo with asymmetric force limits and a maximum velocity.
+    ///
+    /// The motor’s target velocity is clamped to `[-max_velocity, max_velocity]`.
+    Servo {
+        /// The maximum force delivered towards the positive direction of the motorized axis.
+        max_forward_force: Real,
+        /// The maximum force delivered towards the negative direction of the motorized axis.
+        max_backward_force: Real,
+        /// The maximum velocity the servo can be driven at.
+        max_velocity: Real,
+    },
+}
+
+impl Default for ActuatorModel {
+    fn default() -> Self {
+        ActuatorModel::Ideal
+    }
+}
+
+impl ActuatorModel {
+    /// Creates a DC motor model from its stall force and no-load velocity.
+    pub fn dc_motor(stall_force: Real, no_load_velocity: Real) -> Self {
+        ActuatorModel::DcMotor {
+            stall_force,
+            no_load_velocity,
+        }
+    }
+
+    /// Creates a servo model from its maximum forces in both directions, and its maximum velocity.
+    pub fn servo(max_forward_force: Real, max_backward_force: Real, max_velocity: Real) -> Self {
+        ActuatorModel::Servo {
+            max_forward_force,
+            max_backward_force,
+            max_velocity,
+        }
+    }
+
+    /// Clamps the target velocity of the motor to the velocities this actuator can reach.
+    pub fn clamp_target_velocity(&self, target_vel: Real) -> Real {
+        match self {
+            ActuatorModel::Servo { max_velocity, .. } => {
+                target_vel.max(-*max_velocity).min(*max_velocity)
+            }
+            _ => target_vel,
+        }
+    }
+
+    /// The `[min, max]` force this actuator can deliver when moving at the given velocity.
+    ///
+    /// Positive forces push the motorized degree of freedom towards its positive direction.
+    pub fn force_bounds<N: WReal>(&self, max_force: N, vel: N) -> [N; 2] {
+        let zero = N::zero();
+        match *self {
+            ActuatorModel::Ideal => [-max_force, max_force],
+            ActuatorModel::DcMotor {
+                stall_force,
+                no_load_velocity,
+            } => {
+                let stall = N::splat(stall_force);
+                let slope = N::splat(stall_force * crate::utils::inv(no_load_velocity));
+                let limit = stall.simd_min(max_force);
+                let forward = (stall - slope * vel).simd_clamp(zero, limit);
+                let backward = (stall + slope * vel).simd_clamp(zero, limit);
+                [-backward, forward]
+            }
+            ActuatorModel::Servo {
+                max_forward_force,
+                max_backward_force,
+                ..
+            } => [
+                -N::splat(max_backward_force).simd_min(max_force),
+                N::splat(max_forward_force).simd_min(max_force),
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ActuatorModel;
+    use crate::dynamics::{RevoluteJointBuilder, RigidBodyBuilder};
+    use crate::math::Vector;
+    use crate::test_utils::TestWorld;
+
+    #[test]
+    fn dc_motor_saturates_at_no_load_velocity() {
+        let mut world = TestWorld::without_gravity();
+
+        // A wheel driven by a DC motor asked to reach a velocity it can’t reach.
+        #[cfg(feature = "dim2")]
+        let revolute = RevoluteJointBuilder::new();
+        #[cfg(feature = "dim3")]
+        let revolute = RevoluteJointBuilder::new(Vector::x_axis());
+
+        let fixed = world.bodies.insert(RigidBodyBuilder::fixed().build());
+        let wheel = world.add_ball(Vector::zeros(), 1.0);
+        world.impulse_joints.insert(
+            fixed,
+            wheel,
+            revolute
+                .motor_velocity(100.0, 1.0e3)
+                .motor_actuator(ActuatorModel::dc_motor(10.0, 5.0)),
+        );
+
+        world.step_n(300);
+
+        #[cfg(feature = "dim2")]
+        let angvel = world.bodies[wheel].angvel();
+        #[cfg(feature = "dim3")]
+        let angvel = world.bodies[wheel].angvel().x;
+        assert!(angvel > 4.9 && angvel < 5.0 + 1.0e-3);
+    }
+}
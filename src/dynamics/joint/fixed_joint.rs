@@ -1,4 +1,4 @@
-use crate::dynamics::{GenericJoint, GenericJointBuilder, JointAxesMask};
+use crate::dynamics::{GenericJoint, GenericJointBuilder, JointAxesMask, JointSoftness};
 use crate::math::{Isometry, Point, Real};
 
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
@@ -70,6 +70,59 @@ impl FixedJoint {
         self.data.set_local_anchor2(anchor2);
         self
     }
+
+    /// The maximum linear force this joint can apply before breaking.
+    #[must_use]
+    pub fn max_force(&self) -> Real {
+        self.data.max_force
+    }
+
+    /// Sets the maximum linear force this joint can apply before breaking.
+    pub fn set_max_force(&mut self, max_force: Real) -> &mut Self {
+        self.data.set_max_force(max_force);
+        self
+    }
+
+    /// The maximum torque this joint can apply before breaking.
+    #[must_use]
+    pub fn max_torque(&self) -> Real {
+        self.data.max_torque
+    }
+
+    /// Sets the maximum torque this joint can apply before breaking.
+    pub fn set_max_torque(&mut self, max_torque: Real) -> &mut Self {
+        self.data.set_max_torque(max_torque);
+        self
+    }
+
+    /// The softness of this joint’s constraints, if it overrides the global one.
+    #[must_use]
+    pub fn softness(&self) -> Option<JointSoftness> {
+        self.data.softness
+    }
+
+    /// Sets the softness of this joint’s constraints.
+    ///
+    /// Set to `None` to use the global `IntegrationParameters::joint_erp` and
+    /// `IntegrationParameters::joint_damping_ratio`.
+    pub fn set_softness(&mut self, softness: Option<JointSoftness>) -> &mut Self {
+        self.data.set_softness(softness);
+        self
+    }
+
+    /// Are contacts between the colliders attached to the two rigid-bodies linked by this
+    /// joint enabled?
+    #[must_use]
+    pub fn contacts_enabled(&self) -> bool {
+        self.data.contacts_enabled()
+    }
+
+    /// Enables or disables contacts between the colliders attached to the two rigid-bodies
+    /// linked by this joint.
+    pub fn set_contacts_enabled(&mut self, enabled: bool) -> &mut Self {
+        self.data.set_contacts_enabled(enabled);
+        self
+    }
 }
 
 impl Into<GenericJoint> for FixedJoint {
@@ -117,6 +170,35 @@ impl FixedJointBuilder {
         self
     }
 
+    /// Sets the maximum linear force this joint can apply before breaking.
+    #[must_use]
+    pub fn max_force(mut self, max_force: Real) -> Self {
+        self.0.set_max_force(max_force);
+        self
+    }
+
+    /// Sets the maximum torque this joint can apply before breaking.
+    #[must_use]
+    pub fn max_torque(mut self, max_torque: Real) -> Self {
+        self.0.set_max_torque(max_torque);
+        self
+    }
+
+    /// Sets the softness of this joint’s constraints.
+    #[must_use]
+    pub fn softness(mut self, softness: JointSoftness) -> Self {
+        self.0.set_softness(Some(softness));
+        self
+    }
+
+    /// Enables or disables contacts between the colliders attached to the two rigid-bodies
+    /// linked by this joint.
+    #[must_use]
+    pub fn contacts_enabled(mut self, enabled: bool) -> Self {
+        self.0.set_contacts_enabled(enabled);
+        self
+    }
+
     /// Build the fixed joint.
     #[must_use]
     pub fn build(self) -> FixedJoint {
@@ -0,0 +1,251 @@
+use crate::dynamics::joint::{GenericJoint, GenericJointBuilder, JointAxesMask};
+use crate::dynamics::{JointCoupling, JointCouplingKind};
+use crate::math::{Point, Real};
+
+#[cfg(feature = "dim3")]
+use crate::math::UnitVector;
+
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(transparent)]
+/// A gear joint, couples the rotations of two bodies around the joint’s principal axes.
+///
+/// The angular velocities of both bodies satisfy `angvel1 + ratio * angvel2 = 0`. With a
+/// positive ratio, both bodies rotate in opposite directions, like two meshed gears whose
+/// radii are related by `radius2 = ratio * radius1`. This joint doesn’t constrain the relative
+/// position of both bodies: it is generally combined with revolute joints attaching each gear
+/// to its support.
+///
+/// This joint is only supported as an impulse joint between bodies that aren’t part of a
+/// multibody.
+pub struct GearJoint {
+    data: GenericJoint,
+}
+
+impl GearJoint {
+    /// Creates a new gear joint with the given gear ratio.
+    pub fn new(ratio: Real) -> Self {
+        let data = GenericJointBuilder::new(JointAxesMask::empty())
+            .coupling(JointCoupling::new(JointCouplingKind::Gear, ratio))
+            .build();
+        Self { data }
+    }
+
+    /// The underlying generic joint.
+    pub fn data(&self) -> &GenericJoint {
+        &self.data
+    }
+
+    /// The joint’s anchor, expressed in the local-space of the first rigid-body.
+    #[must_use]
+    pub fn local_anchor1(&self) -> Point<Real> {
+        self.data.local_anchor1()
+    }
+
+    /// Sets the joint’s anchor, expressed in the local-space of the first rigid-body.
+    pub fn set_local_anchor1(&mut self, anchor1: Point<Real>) -> &mut Self {
+        self.data.set_local_anchor1(anchor1);
+        self
+    }
+
+    /// The joint’s anchor, expressed in the local-space of the second rigid-body.
+    #[must_use]
+    pub fn local_anchor2(&self) -> Point<Real> {
+        self.data.local_anchor2()
+    }
+
+    /// Sets the joint’s anchor, expressed in the local-space of the second rigid-body.
+    pub fn set_local_anchor2(&mut self, anchor2: Point<Real>) -> &mut Self {
+        self.data.set_local_anchor2(anchor2);
+        self
+    }
+
+    /// The rotation axis of the first gear, expressed in the local-space of the first rigid-body.
+    #[cfg(feature = "dim3")]
+    #[must_use]
+    pub fn local_axis1(&self) -> UnitVector<Real> {
+        self.data.local_axis1()
+    }
+
+    /// Sets the rotation axis of the first gear, expressed in the local-space of the first rigid-body.
+    #[cfg(feature = "dim3")]
+    pub fn set_local_axis1(&mut self, axis1: UnitVector<Real>) -> &mut Self {
+        self.data.set_local_axis1(axis1);
+        self
+    }
+
+    /// The rotation axis of the second gear, expressed in the local-space of the second rigid-body.
+    #[cfg(feature = "dim3")]
+    #[must_use]
+    pub fn local_axis2(&self) -> UnitVector<Real> {
+        self.data.local_axis2()
+    }
+
+    /// Sets the rotation axis of the second gear, expressed in the local-space of the second rigid-body.
+    #[cfg(feature = "dim3")]
+    pub fn set_local_axis2(&mut self, axis2: UnitVector<Real>) -> &mut Self {
+        self.data.set_local_axis2(axis2);
+        self
+    }
+
+    /// The gear ratio.
+    #[must_use]
+    pub fn ratio(&self) -> Real {
+        self.data.coupling.map(|c| c.ratio).unwrap_or(0.0)
+    }
+
+    /// Sets the gear ratio.
+    pub fn set_ratio(&mut self, ratio: Real) -> &mut Self {
+        if let Some(coupling) = &mut self.data.coupling {
+            coupling.ratio = ratio;
+        }
+        self
+    }
+
+    /// Are contacts between the colliders attached to the two rigid-bodies linked by this
+    /// joint enabled?
+    #[must_use]
+    pub fn contacts_enabled(&self) -> bool {
+        self.data.contacts_enabled()
+    }
+
+    /// Enables or disables contacts between the colliders attached to the two rigid-bodies
+    /// linked by this joint.
+    pub fn set_contacts_enabled(&mut self, enabled: bool) -> &mut Self {
+        self.data.set_contacts_enabled(enabled);
+        self
+    }
+}
+
+impl Into<GenericJoint> for GearJoint {
+    fn into(self) -> GenericJoint {
+        self.data
+    }
+}
+
+/// Create gear joints using the builder pattern.
+///
+/// A gear joint couples the rotations of two bodies around the joint’s principal axes.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GearJointBuilder(GearJoint);
+
+impl GearJointBuilder {
+    /// Creates a new builder for gear joints with the given gear ratio.
+    pub fn new(ratio: Real) -> Self {
+        Self(GearJoint::new(ratio))
+    }
+
+    /// Sets the joint’s anchor, expressed in the local-space of the first rigid-body.
+    #[must_use]
+    pub fn local_anchor1(mut self, anchor1: Point<Real>) -> Self {
+        self.0.set_local_anchor1(anchor1);
+        self
+    }
+
+    /// Sets the joint’s anchor, expressed in the local-space of the second rigid-body.
+    #[must_use]
+    pub fn local_anchor2(mut self, anchor2: Point<Real>) -> Self {
+        self.0.set_local_anchor2(anchor2);
+        self
+    }
+
+    /// Sets the rotation axis of the first gear, expressed in the local-space of the first rigid-body.
+    #[cfg(feature = "dim3")]
+    #[must_use]
+    pub fn local_axis1(mut self, axis1: UnitVector<Real>) -> Self {
+        self.0.set_local_axis1(axis1);
+        self
+    }
+
+    /// Sets the rotation axis of the second gear, expressed in the local-space of the second rigid-body.
+    #[cfg(feature = "dim3")]
+    #[must_use]
+    pub fn local_axis2(mut self, axis2: UnitVector<Real>) -> Self {
+        self.0.set_local_axis2(axis2);
+        self
+    }
+
+    /// Sets the gear ratio.
+    #[must_use]
+    pub fn ratio(mut self, ratio: Real) -> Self {
+        self.0.set_ratio(ratio);
+        self
+    }
+
+    /// Enables or disables contacts between the colliders attached to the two rigid-bodies
+    /// linked by this joint.
+    #[must_use]
+    pub fn contacts_enabled(mut self, enabled: bool) -> Self {
+        self.0.set_contacts_enabled(enabled);
+        self
+    }
+
+    /// Builds the gear joint.
+    #[must_use]
+    pub fn build(self) -> GearJoint {
+        self.0
+    }
+}
+
+impl Into<GenericJoint> for GearJointBuilder {
+    fn into(self) -> GenericJoint {
+        self.0.into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::GearJointBuilder;
+    use crate::dynamics::{RevoluteJointBuilder, RigidBodyBuilder};
+    use crate::geometry::ColliderBuilder;
+    use crate::math::Vector;
+    use crate::test_utils::TestWorld;
+    use crate::utils::WDot;
+
+    #[test]
+    fn gear_joint_counter_rotation() {
+        let mut world = TestWorld::without_gravity();
+
+        // Two wheels pinned to the ground, the first one spinning, the second one at rest.
+        #[cfg(feature = "dim2")]
+        let (angvel, revolute) = (1.0, RevoluteJointBuilder::new());
+        #[cfg(feature = "dim3")]
+        let (angvel, revolute) = (Vector::x(), RevoluteJointBuilder::new(Vector::x_axis()));
+
+        let fixed = world.bodies.insert(RigidBodyBuilder::fixed().build());
+        let wheel1 = world
+            .bodies
+            .insert(RigidBodyBuilder::dynamic().angvel(angvel).build());
+        world.colliders.insert_with_parent(
+            ColliderBuilder::ball(1.0).build(),
+            wheel1,
+            &mut world.bodies,
+        );
+        let wheel2 = world.add_ball(Vector::y() * 3.0, 1.0);
+        world.impulse_joints.insert(fixed, wheel1, revolute);
+        world.impulse_joints.insert(
+            fixed,
+            wheel2,
+            revolute.local_anchor1((Vector::y() * 3.0).into()),
+        );
+        world
+            .impulse_joints
+            .insert(wheel1, wheel2, GearJointBuilder::new(1.0));
+
+        world.step_n(10);
+
+        // Both wheels have the same inertia, so they end up spinning at half the initial
+        // speed, in opposite directions.
+        #[cfg(feature = "dim2")]
+        let (angvel1, angvel2) = (world.bodies[wheel1].angvel(), world.bodies[wheel2].angvel());
+        #[cfg(feature = "dim3")]
+        let (angvel1, angvel2) = (
+            *world.bodies[wheel1].angvel(),
+            *world.bodies[wheel2].angvel(),
+        );
+        let sum = angvel1 + angvel2;
+        let err = angvel1 - angvel * 0.5;
+        assert!(sum.gdot(sum) < 1.0e-6);
+        assert!(err.gdot(err) < 1.0e-4);
+    }
+}
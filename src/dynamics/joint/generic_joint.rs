@@ -1,10 +1,18 @@
 use crate::dynamics::solver::MotorParameters;
-use crate::dynamics::{FixedJoint, MotorModel, PrismaticJoint, RevoluteJoint};
-use crate::math::{Isometry, Point, Real, Rotation, UnitVector, Vector, SPATIAL_DIM};
+use crate::dynamics::{
+    ActuatorModel, FixedJoint, IntegrationParameters, JointCoupling, MotorModel, PrismaticJoint,
+    RevoluteJoint,
+};
+use crate::math::{
+    Isometry, Point, Real, Rotation, SpacialVector, UnitVector, Vector, SPATIAL_DIM,
+};
 use crate::utils::{WBasis, WReal};
+use na::RealField;
 
 #[cfg(feature = "dim3")]
 use crate::dynamics::SphericalJoint;
+#[cfg(feature = "dim3")]
+use crate::math::DIM;
 
 #[cfg(feature = "dim3")]
 bitflags::bitflags! {
@@ -125,6 +133,86 @@ impl<N: WReal> Default for JointLimits<N> {
     }
 }
 
+/// The dry friction and viscous damping of a joint along one of its degrees of freedom.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct JointFriction {
+    /// The maximum force (or torque) the dry (Coulomb) friction can apply.
+    pub max_force: Real,
+    /// The viscous damping coefficient, i.e., the force applied per unit of relative velocity.
+    pub damping: Real,
+    /// The impulse applied by the friction and damping.
+    pub impulse: Real,
+}
+
+impl Default for JointFriction {
+    fn default() -> Self {
+        Self {
+            max_force: 0.0,
+            damping: 0.0,
+            impulse: 0.0,
+        }
+    }
+}
+
+impl JointFriction {
+    /// Is any friction or damping applied along this degree of freedom?
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.max_force > 0.0 || self.damping > 0.0
+    }
+
+    /// The `[min, max]` impulse the friction and damping can apply given the current
+    /// relative velocity along their degree of freedom.
+    ///
+    /// The damping is applied explicitly, and the constraint only ever slows the motion
+    /// down, so it can’t make it change direction.
+    pub(crate) fn impulse_bounds(&self, dt: Real, vel: Real) -> [Real; 2] {
+        let max_impulse = (self.max_force + self.damping * vel.abs()) * dt;
+        [-max_impulse, max_impulse]
+    }
+}
+
+/// The softness of a joint’s constraints, modeled as a damped spring pulling the joint back
+/// toward the configuration it enforces.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct JointSoftness {
+    /// The natural frequency of the spring, in Hertz. Must be strictly positive.
+    ///
+    /// Higher frequencies make the joint stiffer.
+    pub natural_frequency: Real,
+    /// The damping ratio of the spring, `1.0` being critical damping.
+    pub damping_ratio: Real,
+}
+
+impl JointSoftness {
+    /// Creates a joint softness from the natural frequency (in Hertz) and damping ratio
+    /// of its spring.
+    pub fn new(natural_frequency: Real, damping_ratio: Real) -> Self {
+        Self {
+            natural_frequency,
+            damping_ratio,
+        }
+    }
+
+    /// The ERP coefficient equivalent to this softness for a timestep of length `dt`.
+    #[must_use]
+    pub fn erp(&self, dt: Real) -> Real {
+        let omega_dt = self.natural_frequency * Real::two_pi() * dt;
+        omega_dt / (omega_dt + 2.0 * self.damping_ratio)
+    }
+
+    /// The CFM coefficient equivalent to this softness for a timestep of length `dt`.
+    ///
+    /// See `IntegrationParameters::joint_cfm_coeff` for its meaning.
+    #[must_use]
+    pub fn cfm_coeff(&self, dt: Real) -> Real {
+        let omega_dt = self.natural_frequency * Real::two_pi() * dt;
+        1.0 / (omega_dt * (omega_dt + 2.0 * self.damping_ratio))
+    }
+}
+
 /// A joint’s motor along one of its degrees of freedom.
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -143,6 +231,8 @@ pub struct JointMotor {
     pub impulse: Real,
     /// The spring-like model used for simulating this motor.
     pub model: MotorModel,
+    /// The model of the actuator limiting the force this motor can deliver.
+    pub actuator: ActuatorModel,
 }
 
 impl Default for JointMotor {
@@ -155,6 +245,7 @@ impl Default for JointMotor {
             max_force: Real::MAX,
             impulse: 0.0,
             model: MotorModel::AccelerationBased,
+            actuator: ActuatorModel::Ideal,
         }
     }
 }
@@ -170,15 +261,20 @@ impl JointMotor {
             cfm_gain,
             // keep_lhs,
             target_pos: self.target_pos,
-            target_vel: self.target_vel,
-            max_impulse: self.max_force * dt,
+            target_vel: self.actuator.clamp_target_velocity(self.target_vel),
+            max_force: self.max_force,
+            dt,
+            actuator: self.actuator,
         }
     }
 }
 
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-serialize", serde(default))]
 #[derive(Copy, Clone, Debug, PartialEq)]
 /// A generic joint.
+///
+/// When deserialized, missing fields are set to their value in `GenericJoint::default()`.
 pub struct GenericJoint {
     /// The joint’s frame, expressed in the first rigid-body’s local-space.
     pub local_frame1: Isometry<Real>,
@@ -200,6 +296,36 @@ pub struct GenericJoint {
     ///
     /// Note that the mostor must also be explicitly enabled by the `motors` bitmask.
     pub motors: [JointMotor; SPATIAL_DIM],
+    /// The dry friction and viscous damping, along each degrees of freedom of this joint.
+    ///
+    /// They only apply to the free degrees of freedom that aren’t coupled, and are only
+    /// supported by impulse joints.
+    pub frictions: [JointFriction; SPATIAL_DIM],
+    /// The maximum linear force this joint can apply before breaking.
+    ///
+    /// Set to `Real::MAX` (the default) for unbreakable joints. Only impulse joints can break.
+    pub max_force: Real,
+    /// The maximum torque this joint can apply before breaking.
+    ///
+    /// Set to `Real::MAX` (the default) for unbreakable joints. Only impulse joints can break.
+    pub max_torque: Real,
+    /// The coupling between the velocities of the two bodies attached to this joint, if any.
+    ///
+    /// Couplings are only supported by impulse joints.
+    pub coupling: Option<JointCoupling>,
+    /// The softness of this joint’s constraints.
+    ///
+    /// If `None` (the default), the joint uses the global `IntegrationParameters::joint_erp`
+    /// and `IntegrationParameters::joint_damping_ratio`.
+    pub softness: Option<JointSoftness>,
+    /// Is this joint enabled?
+    ///
+    /// A disabled joint isn’t solved, but keeps its handle and its accumulated impulses.
+    /// Only impulse joints can be disabled.
+    pub enabled: bool,
+    /// Are contacts between the colliders attached to the two rigid-bodies linked by this
+    /// joint enabled?
+    pub contacts_enabled: bool,
 }
 
 impl Default for GenericJoint {
@@ -213,6 +339,13 @@ impl Default for GenericJoint {
             coupled_axes: JointAxesMask::empty(),
             limits: [JointLimits::default(); SPATIAL_DIM],
             motors: [JointMotor::default(); SPATIAL_DIM],
+            frictions: [JointFriction::default(); SPATIAL_DIM],
+            max_force: Real::MAX,
+            max_torque: Real::MAX,
+            coupling: None,
+            softness: None,
+            enabled: true,
+            contacts_enabled: true,
         }
     }
 }
@@ -227,7 +360,34 @@ impl GenericJoint {
     #[cfg(feature = "simd-is-enabled")]
     /// Can this joint use SIMD-accelerated constraint formulations?
     pub(crate) fn supports_simd_constraints(&self) -> bool {
-        self.limit_axes.is_empty() && self.motor_axes.is_empty()
+        self.limit_axes.is_empty()
+            && self.motor_axes.is_empty()
+            && self.coupling.is_none()
+            && self.softness.is_none()
+            && self.friction_axes() == 0
+    }
+
+    /// The ERP coefficient multiplied by the inverse timestep length, and the CFM coefficient,
+    /// used to solve the constraints of this joint.
+    pub(crate) fn erp_inv_dt_and_cfm_coeff(&self, params: &IntegrationParameters) -> (Real, Real) {
+        match &self.softness {
+            Some(softness) => (
+                softness.erp(params.dt) * params.inv_dt(),
+                softness.cfm_coeff(params.dt),
+            ),
+            None => (params.joint_erp_inv_dt(), params.joint_cfm_coeff()),
+        }
+    }
+
+    /// The free and uncoupled degrees of freedom with friction or damping.
+    pub(crate) fn friction_axes(&self) -> u8 {
+        let mut result = 0;
+        for i in 0..SPATIAL_DIM {
+            if self.frictions[i].is_enabled() {
+                result |= 1 << i;
+            }
+        }
+        result & !self.locked_axes.bits() & !self.coupled_axes.bits()
     }
 
     fn complete_ang_frame(axis: UnitVector<Real>) -> Rotation<Real> {
@@ -386,6 +546,12 @@ impl GenericJoint {
         self
     }
 
+    /// Sets the model of the actuator driving the motor along the specified axis.
+    pub fn set_motor_actuator(&mut self, axis: JointAxis, actuator: ActuatorModel) -> &mut Self {
+        self.motors[axis as usize].actuator = actuator;
+        self
+    }
+
     /// The motor affecting the joint’s degree of freedom along the specified axis.
     #[must_use]
     pub fn motor(&self, axis: JointAxis) -> Option<&JointMotor> {
@@ -414,6 +580,163 @@ impl GenericJoint {
         self.motors[i].damping = damping;
         self
     }
+
+    /// Sets the maximum linear force this joint can apply before breaking.
+    pub fn set_max_force(&mut self, max_force: Real) -> &mut Self {
+        self.max_force = max_force;
+        self
+    }
+
+    /// Sets the maximum torque this joint can apply before breaking.
+    pub fn set_max_torque(&mut self, max_torque: Real) -> &mut Self {
+        self.max_torque = max_torque;
+        self
+    }
+
+    /// The dry friction and viscous damping along the specified axis of this joint.
+    #[must_use]
+    pub fn friction(&self, axis: JointAxis) -> &JointFriction {
+        &self.frictions[axis as usize]
+    }
+
+    /// Sets the maximum force (or torque) the dry friction can apply along the specified axis.
+    pub fn set_friction(&mut self, axis: JointAxis, max_force: Real) -> &mut Self {
+        self.frictions[axis as usize].max_force = max_force;
+        self
+    }
+
+    /// Sets the viscous damping coefficient along the specified axis.
+    pub fn set_damping(&mut self, axis: JointAxis, damping: Real) -> &mut Self {
+        self.frictions[axis as usize].damping = damping;
+        self
+    }
+
+    /// Sets the coupling between the velocities of the two bodies attached to this joint.
+    pub fn set_coupling(&mut self, coupling: Option<JointCoupling>) -> &mut Self {
+        self.coupling = coupling;
+        self
+    }
+
+    /// Sets the softness of this joint’s constraints.
+    ///
+    /// Set to `None` to use the global `IntegrationParameters::joint_erp` and
+    /// `IntegrationParameters::joint_damping_ratio`.
+    pub fn set_softness(&mut self, softness: Option<JointSoftness>) -> &mut Self {
+        self.softness = softness;
+        self
+    }
+
+    /// Is this joint enabled?
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Enables or disables this joint.
+    ///
+    /// A disabled joint isn’t solved, but keeps its handle and its accumulated impulses.
+    /// Only impulse joints can be disabled.
+    pub fn set_enabled(&mut self, enabled: bool) -> &mut Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Are contacts between the colliders attached to the two rigid-bodies linked by this
+    /// joint enabled?
+    #[must_use]
+    pub fn contacts_enabled(&self) -> bool {
+        self.contacts_enabled
+    }
+
+    /// Enables or disables contacts between the colliders attached to the two rigid-bodies
+    /// linked by this joint.
+    pub fn set_contacts_enabled(&mut self, enabled: bool) -> &mut Self {
+        self.contacts_enabled = enabled;
+        self
+    }
+
+    /// Can this joint break if the force or torque it applies is too large?
+    #[must_use]
+    pub fn is_breakable(&self) -> bool {
+        self.max_force != Real::MAX || self.max_torque != Real::MAX
+    }
+
+    /// The impulses applied by the limits, motors, and friction of this joint during the last
+    /// timestep.
+    pub(crate) fn limit_motor_and_friction_impulses(&self) -> SpacialVector<Real> {
+        let mut result = SpacialVector::zeros();
+        let friction_axes = self.friction_axes();
+
+        for i in 0..SPATIAL_DIM {
+            let bit = 1 << i;
+
+            if self.limit_axes.bits() & bit != 0 {
+                result[i] += self.limits[i].impulse;
+            }
+            if self.motor_axes.bits() & bit != 0 {
+                result[i] += self.motors[i].impulse;
+            }
+            if friction_axes & bit != 0 {
+                result[i] += self.frictions[i].impulse;
+            }
+        }
+
+        result
+    }
+
+    /// The twist axis of the cone-twist limit of this joint.
+    ///
+    /// This is the first angular axis that isn’t both limited and coupled. If all three
+    /// angular axes are limited and coupled, the X axis is used as the twist axis.
+    #[cfg(feature = "dim3")]
+    pub(crate) fn cone_twist_axis(&self) -> usize {
+        let free_bits = !(self.limit_axes & self.coupled_axes).bits() >> DIM;
+
+        if free_bits & 0b111 == 0 {
+            0
+        } else {
+            free_bits.trailing_zeros() as usize
+        }
+    }
+
+    /// The maximum swing angle allowed by the cone-twist limit of this joint around
+    /// the given swing axis, expressed in the joint’s first frame.
+    ///
+    /// The swing cone is elliptical: its semi-axes are given by the maximum limits of the two
+    /// angular axes orthogonal to the twist axis. An axis that isn’t both limited and coupled
+    /// doesn’t restrict the swing, i.e., its semi-axis is `π`.
+    #[cfg(feature = "dim3")]
+    pub(crate) fn max_swing_angle(&self, local_swing_axis: &Vector<Real>) -> Real {
+        let swing_bits = (self.limit_axes & self.coupled_axes).bits() >> DIM;
+        let twist_axis = self.cone_twist_axis();
+        let k1 = (twist_axis + 1) % 3;
+        let k2 = (twist_axis + 2) % 3;
+        let semi_axis = |k: usize| {
+            if swing_bits & (1 << k) != 0 {
+                self.limits[DIM + k].max
+            } else {
+                Real::pi()
+            }
+        };
+
+        ellipse_radius(
+            [local_swing_axis[k1], local_swing_axis[k2]],
+            [semi_axis(k1), semi_axis(k2)],
+        )
+    }
+}
+
+/// The distance between the center of an ellipse and its boundary, along the unit direction `dir`.
+#[cfg(feature = "dim3")]
+fn ellipse_radius(dir: [Real; 2], semi_axes: [Real; 2]) -> Real {
+    let denom = ((dir[0] * semi_axes[1]).powi(2) + (dir[1] * semi_axes[0]).powi(2)).sqrt();
+
+    if denom > 0.0 {
+        semi_axes[0] * semi_axes[1] / denom
+    } else {
+        // Degenerate ellipse, with `dir` aligned with its only non-zero semi-axis.
+        semi_axes[0].max(semi_axes[1])
+    }
 }
 
 macro_rules! joint_conversion_methods(
@@ -597,6 +920,63 @@ impl GenericJointBuilder {
         self
     }
 
+    /// Sets the model of the actuator driving the motor along the specified axis.
+    #[must_use]
+    pub fn motor_actuator(mut self, axis: JointAxis, actuator: ActuatorModel) -> Self {
+        self.0.set_motor_actuator(axis, actuator);
+        self
+    }
+
+    /// Sets the maximum linear force this joint can apply before breaking.
+    #[must_use]
+    pub fn max_force(mut self, max_force: Real) -> Self {
+        self.0.set_max_force(max_force);
+        self
+    }
+
+    /// Sets the maximum torque this joint can apply before breaking.
+    #[must_use]
+    pub fn max_torque(mut self, max_torque: Real) -> Self {
+        self.0.set_max_torque(max_torque);
+        self
+    }
+
+    /// Sets the maximum force (or torque) the dry friction can apply along the specified axis.
+    #[must_use]
+    pub fn friction(mut self, axis: JointAxis, max_force: Real) -> Self {
+        self.0.set_friction(axis, max_force);
+        self
+    }
+
+    /// Sets the viscous damping coefficient along the specified axis.
+    #[must_use]
+    pub fn damping(mut self, axis: JointAxis, damping: Real) -> Self {
+        self.0.set_damping(axis, damping);
+        self
+    }
+
+    /// Sets the coupling between the velocities of the two bodies attached to this joint.
+    #[must_use]
+    pub fn coupling(mut self, coupling: JointCoupling) -> Self {
+        self.0.set_coupling(Some(coupling));
+        self
+    }
+
+    /// Sets the softness of this joint’s constraints.
+    #[must_use]
+    pub fn softness(mut self, softness: JointSoftness) -> Self {
+        self.0.set_softness(Some(softness));
+        self
+    }
+
+    /// Enables or disables contacts between the colliders attached to the two rigid-bodies
+    /// linked by this joint.
+    #[must_use]
+    pub fn contacts_enabled(mut self, enabled: bool) -> Self {
+        self.0.set_contacts_enabled(enabled);
+        self
+    }
+
     /// Builds the generic joint.
     #[must_use]
     pub fn build(self) -> GenericJoint {
@@ -609,3 +989,68 @@ impl Into<GenericJoint> for GenericJointBuilder {
         self.0
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::JointSoftness;
+    use crate::dynamics::{FixedJointBuilder, RevoluteJointBuilder, RigidBodyBuilder};
+    use crate::math::Vector;
+    use crate::test_utils::TestWorld;
+
+    #[test]
+    fn soft_joint_sags_more_than_rigid_joint() {
+        let mut world = TestWorld::new();
+
+        // Two balls hanging side by side, far enough to not touch each other.
+        let offset = Vector::x() * 2.0;
+        let fixed = world.bodies.insert(RigidBodyBuilder::fixed().build());
+        let rigid = world.add_ball(Vector::zeros(), 0.5);
+        let soft = world.add_ball(offset, 0.5);
+        world
+            .impulse_joints
+            .insert(fixed, rigid, FixedJointBuilder::new());
+        world.impulse_joints.insert(
+            fixed,
+            soft,
+            FixedJointBuilder::new()
+                .local_anchor1(offset.into())
+                .softness(JointSoftness::new(1.0, 1.0)),
+        );
+
+        world.step_n(60);
+
+        let rigid_sag = -world.bodies[rigid].translation().y;
+        let soft_sag = -world.bodies[soft].translation().y;
+        assert!(rigid_sag < 0.01);
+        assert!(soft_sag > 0.1);
+    }
+
+    #[test]
+    fn joint_friction_stops_free_wheel() {
+        let mut world = TestWorld::without_gravity();
+
+        // A spinning wheel with a lot of friction on its axle.
+        #[cfg(feature = "dim2")]
+        let (revolute, angvel) = (RevoluteJointBuilder::new(), 5.0);
+        #[cfg(feature = "dim3")]
+        let (revolute, angvel) = (
+            RevoluteJointBuilder::new(Vector::x_axis()),
+            Vector::x() * 5.0,
+        );
+
+        let fixed = world.bodies.insert(RigidBodyBuilder::fixed().build());
+        let wheel = world.add_ball(Vector::zeros(), 1.0);
+        world.bodies[wheel].set_angvel(angvel, true);
+        world
+            .impulse_joints
+            .insert(fixed, wheel, revolute.friction(1.0e3));
+
+        world.step_n(10);
+
+        #[cfg(feature = "dim2")]
+        let angvel = world.bodies[wheel].angvel();
+        #[cfg(feature = "dim3")]
+        let angvel = world.bodies[wheel].angvel().x;
+        assert!(angvel.abs() < 1.0e-3);
+    }
+}
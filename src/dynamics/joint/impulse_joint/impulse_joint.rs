@@ -1,5 +1,5 @@
 use crate::dynamics::{GenericJoint, ImpulseJointHandle, RigidBodyHandle};
-use crate::math::{Real, SpacialVector};
+use crate::math::{AngVector, Real, SpacialVector, Vector, ANG_DIM, DIM, SPATIAL_DIM};
 
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, PartialEq)]
@@ -19,3 +19,203 @@ pub struct ImpulseJoint {
     // A joint needs to know its handle to simplify its removal.
     pub(crate) handle: ImpulseJointHandle,
 }
+
+impl ImpulseJoint {
+    /// Is this joint enabled?
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.data.is_enabled()
+    }
+
+    /// Enables or disables this joint.
+    ///
+    /// A disabled joint isn’t solved and doesn’t link the islands of its attached rigid-bodies,
+    /// but it keeps its handle and its impulses, which are used to warm-start the solver once the
+    /// joint is enabled again.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.data.set_enabled(enabled);
+    }
+
+    /// Are contacts between the colliders attached to the two rigid-bodies linked by this
+    /// joint enabled?
+    #[must_use]
+    pub fn contacts_enabled(&self) -> bool {
+        self.data.contacts_enabled()
+    }
+
+    /// Enables or disables contacts between the colliders attached to the two rigid-bodies
+    /// linked by this joint.
+    pub fn set_contacts_enabled(&mut self, enabled: bool) {
+        self.data.set_contacts_enabled(enabled);
+    }
+
+    /// The impulses applied along each degree of freedom of this joint during the last timestep,
+    /// including the impulses applied by its limits, motors, and friction.
+    ///
+    /// The first `DIM` components are the linear impulses and the remaining ones are the angular
+    /// impulses. They are expressed in the world-space orientation of the joint’s first local
+    /// frame (i.e. `body1.position() * joint.data.local_frame1`) at the time of the last step.
+    pub fn total_impulses(&self) -> SpacialVector<Real> {
+        let mut result = self.data.limit_motor_and_friction_impulses();
+
+        for i in 0..SPATIAL_DIM {
+            if self.data.locked_axes.bits() & (1 << i) != 0 {
+                result[i] += self.impulses[i];
+            }
+        }
+
+        result
+    }
+
+    /// The linear impulse applied by this joint during the last timestep.
+    ///
+    /// See [`ImpulseJoint::total_impulses`] for details on the frame it is expressed in.
+    pub fn linear_impulse(&self) -> Vector<Real> {
+        self.total_impulses().fixed_rows::<DIM>(0).into_owned()
+    }
+
+    /// The angular impulse applied by this joint during the last timestep.
+    ///
+    /// See [`ImpulseJoint::total_impulses`] for details on the frame it is expressed in.
+    pub fn angular_impulse(&self) -> AngVector<Real> {
+        let impulses = self.total_impulses();
+
+        #[cfg(feature = "dim2")]
+        return impulses[DIM];
+        #[cfg(feature = "dim3")]
+        return impulses.fixed_rows::<ANG_DIM>(DIM).into_owned();
+    }
+
+    /// Should this joint break given the impulses applied during a timestep of length `dt`?
+    ///
+    /// Returns the norms of the linear force and torque applied by this joint if it
+    /// must break, `None` otherwise.
+    pub(crate) fn check_break(&self, dt: Real) -> Option<(Real, Real)> {
+        if !self.data.is_enabled() || !self.data.is_breakable() {
+            return None;
+        }
+
+        let inv_dt = crate::utils::inv(dt);
+        let impulses = self.total_impulses();
+        let force = impulses.fixed_rows::<DIM>(0).norm() * inv_dt;
+        let torque = impulses.fixed_rows::<ANG_DIM>(DIM).norm() * inv_dt;
+
+        if force > self.data.max_force || torque > self.data.max_torque {
+            Some((force, torque))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::dynamics::{FixedJointBuilder, PrismaticJointBuilder, RigidBodyBuilder};
+    use crate::math::{Point, Vector};
+    use crate::pipeline::ChannelEventCollector;
+    use crate::test_utils::TestWorld;
+
+    #[test]
+    fn breakable_joint() {
+        let mut world = TestWorld::new();
+
+        let (collision_send, _collision_recv) = crossbeam::channel::unbounded();
+        let (joint_broken_send, joint_broken_recv) = crossbeam::channel::unbounded();
+        let event_handler = ChannelEventCollector::new(collision_send)
+            .with_joint_broken_event_sender(joint_broken_send);
+
+        // A ball hanging from a fixed body with a joint too weak to hold its weight.
+        let fixed = world.bodies.insert(RigidBodyBuilder::fixed().build());
+        let ball = world.add_ball(Vector::zeros(), 1.0);
+        let joint = FixedJointBuilder::new().max_force(1.0);
+        let joint_handle = world.impulse_joints.insert(fixed, ball, joint);
+
+        world.step_with_events(&event_handler);
+
+        assert!(world.impulse_joints.get(joint_handle).is_none());
+        let event = joint_broken_recv.try_recv().unwrap();
+        assert_eq!(event.joint, joint_handle);
+        assert_eq!((event.body1, event.body2), (fixed, ball));
+        assert!(event.force > 1.0);
+    }
+
+    #[test]
+    fn joint_reaction_impulses() {
+        let mut world = TestWorld::new();
+
+        // A ball hanging from a fixed body.
+        let fixed = world.bodies.insert(RigidBodyBuilder::fixed().build());
+        let ball = world.add_ball(Vector::zeros(), 1.0);
+        let joint_handle = world
+            .impulse_joints
+            .insert(fixed, ball, FixedJointBuilder::new());
+
+        // A ball held on a vertical slider by the friction of the slider only.
+        let offset = Vector::x() * 5.0;
+        let slider_ball = world.add_ball(offset, 1.0);
+        let slider_handle = world.impulse_joints.insert(
+            fixed,
+            slider_ball,
+            PrismaticJointBuilder::new(Vector::y_axis())
+                .local_anchor1(offset.into())
+                .friction(1.0e3),
+        );
+
+        world.step_n(10);
+
+        // At rest, the joint compensates the weight of the ball.
+        let expected =
+            world.bodies[ball].mass() * world.gravity.norm() * world.integration_parameters.dt;
+        let joint = world.impulse_joints.get(joint_handle).unwrap();
+        let impulse = joint.linear_impulse().norm();
+        assert!((impulse - expected).abs() < expected * 0.1);
+
+        // The friction impulses are part of the reaction impulses.
+        let slider = world.impulse_joints.get(slider_handle).unwrap();
+        let impulse = slider.linear_impulse().norm();
+        assert!(world.bodies[slider_ball].translation().y.abs() < 1.0e-3);
+        assert!((impulse - expected).abs() < expected * 0.1);
+    }
+
+    #[test]
+    fn enabled_joint_without_contacts_lets_body_fall_through() {
+        let mut world = TestWorld::new();
+
+        // A ball sliding along a vertical axis attached to the ground, which would stop the
+        // ball if contacts were enabled.
+        let ground = world.add_ground();
+        let ball = world.add_ball(Vector::y() * 2.0, 0.5);
+        let joint = PrismaticJointBuilder::new(Vector::y_axis())
+            .local_anchor2(Point::from(Vector::y() * -2.0))
+            .contacts_enabled(false);
+        world.impulse_joints.insert(ground, ball, joint);
+
+        world.step_n(60);
+
+        let translation = world.bodies[ball].translation();
+        assert!(translation.y < -1.0);
+        assert!(translation.x.abs() < 1.0e-3);
+    }
+
+    #[test]
+    fn disabled_joint_with_contacts_lets_body_rest_on_ground() {
+        let mut world = TestWorld::new();
+
+        // The joint would keep the ball at the ground’s origin if it was enabled.
+        let ground = world.add_ground();
+        let ball = world.add_ball(Vector::y() * 2.0, 0.5);
+        let joint = world
+            .impulse_joints
+            .insert(ground, ball, FixedJointBuilder::new());
+        world
+            .impulse_joints
+            .get_mut(joint)
+            .unwrap()
+            .set_enabled(false);
+
+        world.step_n(120);
+
+        assert!((world.bodies[ball].translation().y - 0.5).abs() < 0.05);
+        assert!(world.impulse_joints.get(joint).is_some());
+    }
+}
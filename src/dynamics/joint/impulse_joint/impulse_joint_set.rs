@@ -3,8 +3,13 @@ use crate::geometry::{InteractionGraph, RigidBodyGraphIndex, TemporaryInteractio
 
 use crate::data::arena::Arena;
 use crate::data::{BundleSet, Coarena, ComponentSet, ComponentSetMut};
-use crate::dynamics::{GenericJoint, RigidBodyHandle};
-use crate::dynamics::{IslandManager, RigidBodyActivation, RigidBodyIds, RigidBodyType};
+use crate::dynamics::solver::JointVelocityConstraintBuilder;
+use crate::dynamics::{GenericJoint, JointState, RigidBodyHandle};
+use crate::dynamics::{
+    IslandManager, RigidBodyActivation, RigidBodyIds, RigidBodyMassProps, RigidBodyPosition,
+    RigidBodyType, RigidBodyVelocity,
+};
+use crate::math::{Isometry, Real};
 
 /// The unique identifier of a joint added to the joint set.
 /// The unique identifier of a collider added to a collider set.
@@ -81,6 +86,78 @@ impl ImpulseJointSet {
             .flat_map(move |id| self.joint_graph.interactions_with(*id))
     }
 
+    /// Iterates through all the impulse_joints attached to both of the given rigid-bodies.
+    pub fn joints_between<'a>(
+        &'a self,
+        body1: RigidBodyHandle,
+        body2: RigidBodyHandle,
+    ) -> impl Iterator<Item = &'a ImpulseJoint> {
+        self.joints_with(body1)
+            .filter(move |inter| crate::utils::select_other((inter.0, inter.1), body1) == body2)
+            .map(|inter| inter.2)
+    }
+
+    /// The world-space frames of the joint with the given handle, attached to its first and
+    /// second body respectively.
+    pub fn world_frames<Bodies>(
+        &self,
+        bodies: &Bodies,
+        handle: ImpulseJointHandle,
+    ) -> Option<(Isometry<Real>, Isometry<Real>)>
+    where
+        Bodies: ComponentSet<RigidBodyPosition>,
+    {
+        let joint = self.get(handle)?;
+        let rb_pos1: &RigidBodyPosition = bodies.index(joint.body1.0);
+        let rb_pos2: &RigidBodyPosition = bodies.index(joint.body2.0);
+
+        Some((
+            rb_pos1.position * joint.data.local_frame1,
+            rb_pos2.position * joint.data.local_frame2,
+        ))
+    }
+
+    /// The current world-space frames, coordinates and velocities of the joint with the
+    /// given handle.
+    ///
+    /// The coordinates and velocities along each [`JointAxis`](crate::dynamics::JointAxis) are
+    /// the ones seen by the joint’s limits and motors during the constraints resolution.
+    pub fn joint_state<Bodies>(
+        &self,
+        bodies: &Bodies,
+        handle: ImpulseJointHandle,
+    ) -> Option<JointState>
+    where
+        Bodies: ComponentSet<RigidBodyPosition>
+            + ComponentSet<RigidBodyVelocity>
+            + ComponentSet<RigidBodyMassProps>,
+    {
+        let joint = self.get(handle)?;
+        let (frame1, frame2) = self.world_frames(bodies, handle)?;
+        let (vels1, mprops1): (&RigidBodyVelocity, &RigidBodyMassProps) =
+            bodies.index_bundle(joint.body1.0);
+        let (vels2, mprops2): (&RigidBodyVelocity, &RigidBodyMassProps) =
+            bodies.index_bundle(joint.body2.0);
+
+        // The error reduction parameters don’t affect the readouts.
+        let builder = JointVelocityConstraintBuilder::new(
+            &frame1,
+            &frame2,
+            &mprops1.world_com,
+            &mprops2.world_com,
+            joint.data.locked_axes.bits(),
+            0.0,
+            0.0,
+        );
+
+        Some(JointState {
+            frame1,
+            frame2,
+            coordinates: builder.coordinates(),
+            velocities: builder.velocities(vels1, vels2),
+        })
+    }
+
     /// Is the given joint handle valid?
     pub fn contains(&self, handle: ImpulseJointHandle) -> bool {
         self.joint_ids.contains(handle.0)
@@ -233,6 +310,10 @@ impl ImpulseJointSet {
         for (i, edge) in self.joint_graph.graph.edges.iter().enumerate() {
             let joint = &edge.weight;
 
+            if !joint.data.is_enabled() {
+                continue;
+            }
+
             let (status1, activation1, ids1): (
                 &RigidBodyType,
                 &RigidBodyActivation,
@@ -0,0 +1,57 @@
+use crate::math::{Point, Real};
+
+/// The relation enforced by a [`JointCoupling`] between the two bodies attached to a joint.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum JointCouplingKind {
+    /// Couples the angular velocities of both bodies around the principal (local X) axes of the
+    /// joint’s frames: `angvel1 + ratio * angvel2 = 0`.
+    Gear,
+    /// Couples the angular velocity of the first body (the pinion) around the principal axis of the
+    /// joint’s first frame, to the linear velocity of the second body’s anchor (the rack) along the
+    /// principal axis of the joint’s second frame: `angvel1 + ratio * linvel2 = 0`.
+    RackAndPinion,
+    /// Couples the distances between the anchors of both bodies and two fixed world-space points,
+    /// as if both bodies were hanging from a rope passing through two pulleys:
+    /// `length1 + ratio * length2 <= length`.
+    Pulley {
+        /// The world-space point the first body’s anchor hangs from.
+        ground_anchor1: Point<Real>,
+        /// The world-space point the second body’s anchor hangs from.
+        ground_anchor2: Point<Real>,
+        /// The total length of the rope.
+        length: Real,
+    },
+}
+
+/// A coupling between the degrees of freedom of the two bodies attached to a joint.
+///
+/// Unlike the coupled axes of a joint, which act on the relative motion of the bodies, a coupling
+/// relates the world-space velocities of both bodies. The gear and rack-and-pinion couplings
+/// are velocity-only: their positional drift isn’t corrected.
+///
+/// Couplings are only supported by impulse joints between rigid-bodies that aren’t part of
+/// a multibody.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct JointCoupling {
+    /// The relation enforced by this coupling.
+    pub kind: JointCouplingKind,
+    /// The ratio between the velocities of the two bodies.
+    pub ratio: Real,
+    /// The impulse applied by this coupling during the last timestep.
+    ///
+    /// It is used to warm-start the solver at the next timestep.
+    pub impulse: Real,
+}
+
+impl JointCoupling {
+    /// Creates a new coupling of the given kind and ratio.
+    pub fn new(kind: JointCouplingKind, ratio: Real) -> Self {
+        Self {
+            kind,
+            ratio,
+            impulse: 0.0,
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use crate::dynamics::JointAxis;
+use crate::math::{Isometry, Real, SpacialVector};
+
+/// The current configuration of a joint, as seen by its limits and motors.
+///
+/// This is computed from the current positions and velocities of the two rigid-bodies
+/// attached to the joint, with the same formulas used by the constraints solver.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct JointState {
+    /// The joint’s frame attached to the first body, expressed in world-space.
+    pub frame1: Isometry<Real>,
+    /// The joint’s frame attached to the second body, expressed in world-space.
+    pub frame2: Isometry<Real>,
+    /// The coordinates of the joint along each of its degrees of freedom, indexed by [`JointAxis`].
+    ///
+    /// The linear coordinates are the components of the translation from `frame1` to `frame2`,
+    /// expressed in `frame1`. The angular coordinates are the rotation angles between both frames.
+    /// In 3D, each angle is extracted independently from the relative rotation, so they are only
+    /// exact when the frames are rotated around a single axis.
+    pub coordinates: SpacialVector<Real>,
+    /// The velocities of the joint along each of its degrees of freedom, indexed by [`JointAxis`].
+    pub velocities: SpacialVector<Real>,
+}
+
+impl JointState {
+    /// The coordinate of the joint along the given degree of freedom.
+    #[must_use]
+    pub fn coordinate(&self, axis: JointAxis) -> Real {
+        self.coordinates[axis as usize]
+    }
+
+    /// The velocity of the joint along the given degree of freedom.
+    #[must_use]
+    pub fn velocity(&self, axis: JointAxis) -> Real {
+        self.velocities[axis as usize]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::dynamics::{JointAxis, RevoluteJointBuilder, RigidBodyBuilder};
+    use crate::geometry::ColliderBuilder;
+    #[cfg(feature = "dim3")]
+    use crate::math::Vector;
+    use crate::test_utils::TestWorld;
+
+    #[test]
+    fn joint_state_reads_revolute_angle_and_velocity() {
+        let mut world = TestWorld::without_gravity();
+
+        // A wheel spinning freely around its axle.
+        #[cfg(feature = "dim2")]
+        let (revolute, angvel) = (RevoluteJointBuilder::new(), 2.0);
+        #[cfg(feature = "dim3")]
+        let (revolute, angvel) = (
+            RevoluteJointBuilder::new(Vector::x_axis()),
+            Vector::x() * 2.0,
+        );
+
+        let fixed = world.bodies.insert(RigidBodyBuilder::fixed().build());
+        let wheel = world
+            .bodies
+            .insert(RigidBodyBuilder::dynamic().angvel(angvel).build());
+        world.colliders.insert_with_parent(
+            ColliderBuilder::ball(1.0).build(),
+            wheel,
+            &mut world.bodies,
+        );
+        let joint = world.impulse_joints.insert(fixed, wheel, revolute);
+
+        world.step_n(10);
+
+        let state = world
+            .impulse_joints
+            .joint_state(&world.bodies, joint)
+            .unwrap();
+        let expected_angle = 2.0 * 10.0 * world.integration_parameters.dt;
+        assert!((state.coordinate(JointAxis::AngX) - expected_angle).abs() < 1.0e-3);
+        assert!((state.velocity(JointAxis::AngX) - 2.0).abs() < 1.0e-3);
+        assert!(state.coordinate(JointAxis::X).abs() < 1.0e-3);
+    }
+}
@@ -1,21 +1,37 @@
+pub use self::actuator_model::ActuatorModel;
 pub use self::fixed_joint::*;
+pub use self::gear_joint::*;
 pub use self::generic_joint::*;
 pub use self::impulse_joint::*;
+pub use self::joint_coupling::{JointCoupling, JointCouplingKind};
+pub use self::joint_state::JointState;
 pub use self::motor_model::MotorModel;
 pub use self::multibody_joint::*;
 pub use self::prismatic_joint::*;
+pub use self::pulley_joint::*;
+pub use self::rack_and_pinion_joint::*;
 pub use self::revolute_joint::*;
+pub use self::rope_joint::*;
+pub use self::spring_joint::*;
 
 #[cfg(feature = "dim3")]
 pub use self::spherical_joint::*;
 
+mod actuator_model;
 mod fixed_joint;
+mod gear_joint;
 mod generic_joint;
 mod impulse_joint;
+mod joint_coupling;
+mod joint_state;
 mod motor_model;
 mod multibody_joint;
 mod prismatic_joint;
+mod pulley_joint;
+mod rack_and_pinion_joint;
 mod revolute_joint;
+mod rope_joint;
+mod spring_joint;
 
 #[cfg(feature = "dim3")]
 mod spherical_joint;
@@ -70,6 +70,7 @@ pub struct Multibody {
     pub(crate) velocities: DVector<Real>,
     pub(crate) damping: DVector<Real>,
     pub(crate) accelerations: DVector<Real>,
+    pub(crate) forces: DVector<Real>,
 
     body_jacobians: Vec<Jacobian<Real>>,
     // TODO: use sparse matrices?
@@ -104,6 +105,7 @@ impl Multibody {
             velocities: DVector::zeros(0),
             damping: DVector::zeros(0),
             accelerations: DVector::zeros(0),
+            forces: DVector::zeros(0),
             body_jacobians: Vec::new(),
             augmented_mass: DMatrix::zeros(0, 0),
             inv_augmented_mass: LU::new(DMatrix::zeros(0, 0)),
@@ -176,6 +178,9 @@ impl Multibody {
                 mb.accelerations
                     .rows_mut(assembly_id, link_ndofs)
                     .copy_from(&self.accelerations.rows(link.assembly_id, link_ndofs));
+                mb.forces
+                    .rows_mut(assembly_id, link_ndofs)
+                    .copy_from(&self.forces.rows(link.assembly_id, link_ndofs));
 
                 link.internal_id = i;
                 link.assembly_id = assembly_id;
@@ -224,6 +229,9 @@ impl Multibody {
             self.accelerations
                 .rows_mut(rhs_copy_shift, rhs_copy_ndofs)
                 .copy_from(&rhs.accelerations.rows(rhs_root_ndofs, rhs_copy_ndofs));
+            self.forces
+                .rows_mut(rhs_copy_shift, rhs_copy_ndofs)
+                .copy_from(&rhs.forces.rows(rhs_root_ndofs, rhs_copy_ndofs));
         }
 
         rhs.links[0]
@@ -268,6 +276,17 @@ impl Multibody {
         self.links.get_mut(id)
     }
 
+    /// The body jacobian of the link with the given id.
+    ///
+    /// It maps the generalized velocity of this multibody to the world-space linear velocity of
+    /// the link’s center-of-mass (first `DIM` rows) and its angular velocity (remaining rows).
+    /// It is up-to-date with the joint coordinates after each call to
+    /// [`Multibody::forward_kinematics`].
+    #[inline]
+    pub fn body_jacobian(&self, link_id: usize) -> &Jacobian<Real> {
+        &self.body_jacobians[link_id]
+    }
+
     /// The number of links on this multibody.
     pub fn num_links(&self) -> usize {
         self.links.len()
@@ -299,6 +318,49 @@ impl Multibody {
         &mut self.damping
     }
 
+    /// The generalized forces applied to this multibody during the next timestep.
+    ///
+    /// These forces (or torques, for angular degrees of freedom) are expressed in the
+    /// generalized coordinates of the multibody, and are reset to zero at the end of each timestep.
+    #[inline]
+    pub fn generalized_forces(&self) -> DVectorSlice<Real> {
+        self.forces.rows(0, self.ndofs)
+    }
+
+    /// The mutable generalized forces applied to this multibody during the next timestep.
+    #[inline]
+    pub fn generalized_forces_mut(&mut self) -> DVectorSliceMut<Real> {
+        self.forces.rows_mut(0, self.ndofs)
+    }
+
+    /// Adds a generalized force (or torque) to the degree of freedom `dof_id` of this multibody.
+    ///
+    /// This force is applied during the next timestep only.
+    pub fn add_generalized_force(&mut self, dof_id: usize, force: Real) {
+        self.forces[dof_id] += force;
+    }
+
+    /// Adds generalized forces (or torques) to the degrees of freedom of the joint of a link.
+    ///
+    /// The `forces` slice must contain one element per degree of freedom of the link’s joint.
+    /// These forces are applied during the next timestep only.
+    pub fn add_joint_forces(&mut self, link_id: usize, forces: &[Real]) {
+        let link = &self.links[link_id];
+        let ndofs = link.joint.ndofs();
+        assert_eq!(
+            forces.len(),
+            ndofs,
+            "One force must be given per degree of freedom of the joint."
+        );
+        let mut link_forces = self.forces.rows_mut(link.assembly_id, ndofs);
+        link_forces += DVectorSlice::from_slice(forces, ndofs);
+    }
+
+    /// Resets to zero all the generalized forces applied to this multibody.
+    pub fn reset_generalized_forces(&mut self) {
+        self.forces.fill(0.0);
+    }
+
     pub(crate) fn add_link(
         &mut self,
         parent: Option<usize>, // FIXME: should be a RigidBodyHandle?
@@ -365,6 +427,7 @@ impl Multibody {
         self.velocities.resize_vertically_mut(len + ndofs, 0.0);
         self.damping.resize_vertically_mut(len + ndofs, 0.0);
         self.accelerations.resize_vertically_mut(len + ndofs, 0.0);
+        self.forces.resize_vertically_mut(len + ndofs, 0.0);
         self.body_jacobians
             .extend((0..num_jacobians).map(|_| Jacobian::zeros(0)));
     }
@@ -379,7 +442,31 @@ impl Multibody {
             return; // Nothing to do.
         }
 
-        self.accelerations.fill(0.0);
+        let mut accs = std::mem::take(&mut self.workspace.accs);
+        let mut accelerations = std::mem::replace(&mut self.accelerations, DVector::zeros(0));
+
+        self.compute_generalized_forces(bodies, &mut accs, &mut accelerations);
+        accelerations += &self.forces;
+        self.acc_inv_augmented_mass.solve_mut(&mut accelerations);
+
+        self.workspace.accs = accs;
+        self.accelerations = accelerations;
+    }
+
+    /// Computes the generalized forces resulting from the external forces applied to the
+    /// rigid-bodies, the velocity-dependent (gyroscopic, centrifugal, and coriolis) forces,
+    /// and the joint damping.
+    fn compute_generalized_forces<Bodies>(
+        &self,
+        bodies: &Bodies,
+        accs: &mut [RigidBodyVelocity],
+        out: &mut DVector<Real>,
+    ) where
+        Bodies: ComponentSet<RigidBodyMassProps>
+            + ComponentSet<RigidBodyForces>
+            + ComponentSet<RigidBodyVelocity>,
+    {
+        out.fill(0.0);
 
         for i in 0..self.links.len() {
             let link = &self.links[i];
@@ -397,7 +484,7 @@ impl Multibody {
                 let parent_link = &self.links[parent_id];
                 let parent_rb_vels: &RigidBodyVelocity = bodies.index(parent_link.rigid_body.0);
 
-                acc += self.workspace.accs[parent_id];
+                acc += accs[parent_id];
                 // The 2.0 originates from the two identical terms of Jdot (the terms become
                 // identical once they are multiplied by the generalized velocities).
                 acc.linvel += 2.0 * parent_rb_vels.angvel.gcross(link.joint_velocity.linvel);
@@ -409,13 +496,13 @@ impl Multibody {
                 acc.linvel += parent_rb_vels
                     .angvel
                     .gcross(parent_rb_vels.angvel.gcross(link.shift02));
-                acc.linvel += self.workspace.accs[parent_id].angvel.gcross(link.shift02);
+                acc.linvel += accs[parent_id].angvel.gcross(link.shift02);
             }
 
             acc.linvel += rb_vels.angvel.gcross(rb_vels.angvel.gcross(link.shift23));
-            acc.linvel += self.workspace.accs[i].angvel.gcross(link.shift23);
+            acc.linvel += accs[i].angvel.gcross(link.shift23);
 
-            self.workspace.accs[i] = acc;
+            accs[i] = acc;
 
             // TODO: should gyroscopic forces already be computed by the rigid-body itself
             //       (at the same time that we add the gravity force)?
@@ -436,7 +523,7 @@ impl Multibody {
                 rb_forces.force - rb_mass.component_mul(&acc.linvel),
                 rb_forces.torque - gyroscopic - rb_inertia * acc.angvel,
             );
-            self.accelerations.gemv_tr(
+            out.gemv_tr(
                 1.0,
                 &self.body_jacobians[i],
                 external_forces.as_vector(),
@@ -444,11 +531,75 @@ impl Multibody {
             );
         }
 
-        self.accelerations
-            .cmpy(-1.0, &self.damping, &self.velocities, 1.0);
+        out.cmpy(-1.0, &self.damping, &self.velocities, 1.0);
+    }
+
+    /// Computes the generalized forces that must be applied to this multibody so that
+    /// its generalized acceleration becomes `desired_acc`.
+    ///
+    /// This is the inverse of the forward dynamics computed by the `PhysicsPipeline` at each
+    /// timestep: it accounts for the external forces currently applied to the rigid-bodies
+    /// (including gravity), the velocity-dependent forces, and the joint damping. It relies on
+    /// the mass matrix and coriolis terms assembled during the last timestep so it should be
+    /// called between two calls to `PhysicsPipeline::step`. The generalized forces already added
+    /// to this multibody with [`Multibody::add_generalized_force`] are not taken into account.
+    pub fn inverse_dynamics<Bodies>(
+        &self,
+        bodies: &Bodies,
+        desired_acc: &DVector<Real>,
+    ) -> DVector<Real>
+    where
+        Bodies: ComponentSet<RigidBodyMassProps>
+            + ComponentSet<RigidBodyForces>
+            + ComponentSet<RigidBodyVelocity>,
+    {
+        assert_eq!(
+            desired_acc.len(),
+            self.ndofs,
+            "The desired acceleration must have one component per degree of freedom."
+        );
+
+        let mut result = DVector::zeros(self.ndofs);
+
+        if self.ndofs == 0 || self.acc_augmented_mass.ncols() != self.ndofs {
+            return result;
+        }
+
+        let mut accs = self.workspace.accs.clone();
+        self.compute_generalized_forces(bodies, &mut accs, &mut result);
+        result.gemv(1.0, &self.acc_augmented_mass, desired_acc, -1.0);
+        result
+    }
+
+    /// Computes the generalized forces that must be applied to this multibody to cancel
+    /// the effect of the given `gravity` on its links.
+    ///
+    /// The gravity scale of each rigid-body is taken into account.
+    pub fn gravity_compensation<Bodies>(
+        &self,
+        bodies: &Bodies,
+        gravity: &Vector<Real>,
+    ) -> DVector<Real>
+    where
+        Bodies: ComponentSet<RigidBodyMassProps> + ComponentSet<RigidBodyForces>,
+    {
+        let mut result = DVector::zeros(self.ndofs);
+
+        if self.ndofs == 0 {
+            return result;
+        }
+
+        for (link, body_jacobian) in self.links.iter().zip(self.body_jacobians.iter()) {
+            let (rb_mprops, rb_forces): (&RigidBodyMassProps, &RigidBodyForces) =
+                bodies.index_bundle(link.rigid_body.0);
+            let weight = Force::new(
+                gravity.component_mul(&rb_mprops.effective_mass()) * rb_forces.gravity_scale,
+                na::zero(),
+            );
+            result.gemv_tr(-1.0, body_jacobian, weight.as_vector(), 1.0);
+        }
 
-        self.acc_inv_augmented_mass
-            .solve_mut(&mut self.accelerations);
+        result
     }
 
     /// Computes the constant terms of the dynamics.
@@ -810,6 +961,7 @@ impl Multibody {
                     self.damping = self.damping.clone().insert_rows(0, SPATIAL_DIM, 0.0);
                     self.accelerations =
                         self.accelerations.clone().insert_rows(0, SPATIAL_DIM, 0.0);
+                    self.forces = self.forces.clone().insert_rows(0, SPATIAL_DIM, 0.0);
 
                     for link in &mut self.links[1..] {
                         link.assembly_id += SPATIAL_DIM - prev_root_ndofs;
@@ -818,6 +970,7 @@ impl Multibody {
                     assert!(self.velocities.len() >= SPATIAL_DIM);
                     assert!(self.damping.len() >= SPATIAL_DIM);
                     assert!(self.accelerations.len() >= SPATIAL_DIM);
+                    assert!(self.forces.len() >= SPATIAL_DIM);
 
                     let fixed_joint = MultibodyJoint::fixed(rb_pos.position);
                     let prev_root_ndofs = self.links[0].joint().ndofs();
@@ -829,6 +982,7 @@ impl Multibody {
                         self.velocities = DVector::zeros(0);
                         self.damping = DVector::zeros(0);
                         self.accelerations = DVector::zeros(0);
+                        self.forces = DVector::zeros(0);
                     } else {
                         self.velocities =
                             self.velocities.index((prev_root_ndofs.., 0)).into_owned();
@@ -837,6 +991,7 @@ impl Multibody {
                             .accelerations
                             .index((prev_root_ndofs.., 0))
                             .into_owned();
+                        self.forces = self.forces.index((prev_root_ndofs.., 0)).into_owned();
                     }
 
                     for link in &mut self.links[1..] {
@@ -863,22 +1018,42 @@ impl Multibody {
             + ComponentSetMut<RigidBodyMassProps>
             + ComponentSetMut<RigidBodyPosition>,
     {
-        // Special case for the root, which has no parent.
-        {
-            let link = &mut self.links[0];
-            link.local_to_parent = link.joint.body_to_parent();
-            link.local_to_world = link.local_to_parent;
+        self.update_kinematics(bodies);
 
+        for (i, link) in self.links.iter().enumerate() {
             bodies.map_mut_internal(link.rigid_body.0, |rb_pos: &mut RigidBodyPosition| {
                 rb_pos.next_position = link.local_to_world;
             });
 
+            if i != 0 {
+                let rb_type: &RigidBodyType = bodies.index(link.rigid_body.0);
+                assert_eq!(
+                    *rb_type,
+                    RigidBodyType::Dynamic,
+                    "A rigid-body that is not at the root of a multibody must be dynamic."
+                );
+            }
+
             if update_mass_props {
-                bodies.map_mut_internal(link.rigid_body.0, |mprops: &mut RigidBodyMassProps| {
-                    mprops.update_world_mass_properties(&link.local_to_world)
+                bodies.map_mut_internal(link.rigid_body.0, |rb_mprops: &mut RigidBodyMassProps| {
+                    rb_mprops.update_world_mass_properties(&link.local_to_world)
                 });
             }
         }
+    }
+
+    /// Computes the poses and body jacobians of all the links of this multibody from
+    /// its current joint coordinates, without modifying the rigid-bodies.
+    pub(crate) fn update_kinematics<Bodies>(&mut self, bodies: &Bodies)
+    where
+        Bodies: ComponentSet<RigidBodyMassProps>,
+    {
+        // Special case for the root, which has no parent.
+        {
+            let link = &mut self.links[0];
+            link.local_to_parent = link.joint.body_to_parent();
+            link.local_to_world = link.local_to_parent;
+        }
 
         // Handle the children. They all have a parent within this multibody.
         for i in 1..self.links.len() {
@@ -887,34 +1062,15 @@ impl Multibody {
             link.local_to_parent = link.joint.body_to_parent();
             link.local_to_world = parent_link.local_to_world * link.local_to_parent;
 
-            {
-                let parent_rb_mprops: &RigidBodyMassProps = bodies.index(parent_link.rigid_body.0);
-                let rb_mprops: &RigidBodyMassProps = bodies.index(link.rigid_body.0);
-                let c0 = parent_link.local_to_world * parent_rb_mprops.local_mprops.local_com;
-                let c2 = link.local_to_world
-                    * Point::from(link.joint.data.local_frame2.translation.vector);
-                let c3 = link.local_to_world * rb_mprops.local_mprops.local_com;
-
-                link.shift02 = c2 - c0;
-                link.shift23 = c3 - c2;
-            }
-
-            bodies.map_mut_internal(link.rigid_body.0, |rb_pos: &mut RigidBodyPosition| {
-                rb_pos.next_position = link.local_to_world;
-            });
-
-            let rb_type: &RigidBodyType = bodies.index(link.rigid_body.0);
-            assert_eq!(
-                *rb_type,
-                RigidBodyType::Dynamic,
-                "A rigid-body that is not at the root of a multibody must be dynamic."
-            );
+            let parent_rb_mprops: &RigidBodyMassProps = bodies.index(parent_link.rigid_body.0);
+            let rb_mprops: &RigidBodyMassProps = bodies.index(link.rigid_body.0);
+            let c0 = parent_link.local_to_world * parent_rb_mprops.local_mprops.local_com;
+            let c2 =
+                link.local_to_world * Point::from(link.joint.data.local_frame2.translation.vector);
+            let c3 = link.local_to_world * rb_mprops.local_mprops.local_com;
 
-            if update_mass_props {
-                bodies.map_mut_internal(link.rigid_body.0, |rb_mprops: &mut RigidBodyMassProps| {
-                    rb_mprops.update_world_mass_properties(&link.local_to_world)
-                });
-            }
+            link.shift02 = c2 - c0;
+            link.shift23 = c3 - c2;
         }
 
         /*
@@ -1025,3 +1181,121 @@ impl Multibody {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::dynamics::RigidBodyBuilder;
+    use crate::geometry::ColliderBuilder;
+    use crate::math::{Real, Vector};
+    use crate::test_utils::{cuboid, TestWorld};
+    use na::DVector;
+
+    #[test]
+    fn inverse_dynamics_round_trip() {
+        let mut world = TestWorld::new();
+        let joints = world.add_multibody_arm(Vector::zeros(), 2);
+        // Let the arm swing so the velocity-dependent forces aren’t zero.
+        world.step_n(10);
+
+        let (multibody, _) = world.multibody_joints.get_mut_internal(joints[0]).unwrap();
+        assert!(multibody.generalized_velocity().norm() > 0.1);
+
+        let desired_acc = DVector::from_column_slice(&[1.0, -0.5]);
+        let forces = multibody.inverse_dynamics(&world.bodies, &desired_acc);
+        multibody.generalized_forces_mut().copy_from(&forces);
+        multibody.update_acceleration(&world.bodies);
+
+        assert!((multibody.generalized_acceleration() - desired_acc).norm() < 1.0e-3);
+    }
+
+    #[test]
+    fn gravity_compensation_holds_arm() {
+        // The largest vertical displacement of the tip of the arm, initially horizontal.
+        let max_tip_displacement = |compensate: bool| {
+            let mut world = TestWorld::new();
+            let joints = world.add_multibody_arm(Vector::zeros(), 2);
+
+            let (multibody, tip_id) = world.multibody_joints.get_mut_internal(joints[1]).unwrap();
+            let tip = multibody.link(tip_id).unwrap().rigid_body_handle();
+            // Compute the jacobians needed before the first step.
+            multibody.forward_kinematics(&mut world.bodies, true);
+
+            let mut max_displacement: Real = 0.0;
+
+            for _ in 0..100 {
+                if compensate {
+                    let (multibody, _) =
+                        world.multibody_joints.get_mut_internal(joints[1]).unwrap();
+                    let forces = multibody.gravity_compensation(&world.bodies, &world.gravity);
+                    multibody.generalized_forces_mut().copy_from(&forces);
+                }
+
+                world.step();
+                max_displacement = max_displacement.max(world.bodies[tip].translation().y.abs());
+            }
+
+            max_displacement
+        };
+
+        assert!(max_tip_displacement(true) < 1.0e-3);
+        assert!(max_tip_displacement(false) > 0.5);
+    }
+
+    #[test]
+    fn generalized_forces_apply_to_every_ccd_substep() {
+        // The number of substeps and the generalized velocity of a single-link arm after one
+        // timestep with a unit generalized force.
+        let step_with_force = |ccd_impact: bool| {
+            let mut world = TestWorld::without_gravity();
+            world.integration_parameters.max_ccd_substeps = 4;
+            let joints = world.add_multibody_arm(Vector::zeros(), 1);
+            // The first step fixes the root of the multibody.
+            world.step();
+
+            if ccd_impact {
+                // A fast projectile hitting a wall far from the arm, which splits the
+                // timestep into several substeps.
+                let wall = world.bodies.insert(
+                    RigidBodyBuilder::fixed()
+                        .translation(Vector::x() * 20.0)
+                        .build(),
+                );
+                world.colliders.insert_with_parent(
+                    cuboid(0.1, 1.0, 1.0).build(),
+                    wall,
+                    &mut world.bodies,
+                );
+                let projectile = world.bodies.insert(
+                    RigidBodyBuilder::dynamic()
+                        .translation(Vector::x() * 15.0)
+                        .linvel(Vector::x() * 1000.0)
+                        .ccd_enabled(true)
+                        .build(),
+                );
+                world.colliders.insert_with_parent(
+                    ColliderBuilder::ball(0.1).build(),
+                    projectile,
+                    &mut world.bodies,
+                );
+            }
+
+            let (multibody, _) = world.multibody_joints.get_mut_internal(joints[0]).unwrap();
+            multibody.generalized_forces_mut().fill(1.0);
+
+            let substeps_before = world.pipeline.counters.ccd.num_substeps;
+            world.step();
+            let substeps = world.pipeline.counters.ccd.num_substeps - substeps_before;
+
+            let (multibody, _) = world.multibody_joints.get(joints[0]).unwrap();
+            assert_eq!(multibody.generalized_forces().norm(), 0.0);
+            (substeps, multibody.generalized_velocity()[0])
+        };
+
+        let (substeps, velocity) = step_with_force(false);
+        let (ccd_substeps, ccd_velocity) = step_with_force(true);
+        assert_eq!(substeps, 1);
+        assert!(ccd_substeps > 1);
+        assert!(velocity > 0.0);
+        assert!((ccd_velocity - velocity).abs() < velocity * 1.0e-3);
+    }
+}
@@ -0,0 +1,282 @@
+use crate::data::ComponentSet;
+use crate::dynamics::{JointAxesMask, Multibody, RigidBodyMassProps};
+use crate::math::{Isometry, Point, Real, SpacialVector, ANG_DIM, DIM, SPATIAL_DIM};
+use crate::utils::WCrossMatrix;
+use na::{DVector, SMatrix};
+
+/// The method used by the inverse kinematics solver to compute the joint displacements
+/// at each iteration.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum InverseKinematicsMethod {
+    /// The damped least-squares method (also known as the Levenberg-Marquardt method).
+    ///
+    /// A larger `damping` makes the solver more robust near singular configurations,
+    /// at the cost of a slower convergence.
+    DampedLeastSquares {
+        /// The damping factor.
+        damping: Real,
+    },
+    /// The Jacobian-transpose method.
+    ///
+    /// Each iteration is cheaper than with the damped least-squares method, but more
+    /// iterations are generally needed to converge.
+    JacobianTranspose,
+}
+
+/// Options for the inverse kinematics solver of multibodies.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct InverseKinematicsOption {
+    /// The method used to compute the joint displacements at each iteration.
+    pub method: InverseKinematicsMethod,
+    /// The maximum number of iterations of the solver.
+    pub max_iters: usize,
+    /// The degrees of freedom of the link that must match the target, along the world-space axes.
+    ///
+    /// The other degrees of freedom of the link are left free.
+    pub constrained_axes: JointAxesMask,
+    /// The linear error below which the target is considered reached.
+    pub epsilon_linear: Real,
+    /// The angular error below which the target is considered reached.
+    pub epsilon_angular: Real,
+}
+
+impl Default for InverseKinematicsOption {
+    fn default() -> Self {
+        Self {
+            method: InverseKinematicsMethod::DampedLeastSquares { damping: 0.1 },
+            max_iters: 50,
+            constrained_axes: JointAxesMask::all(),
+            epsilon_linear: 1.0e-3,
+            epsilon_angular: 1.0e-3,
+        }
+    }
+}
+
+impl Multibody {
+    /// Computes the generalized displacements bringing the link `link_id` to the `target_pose`.
+    ///
+    /// The displacements, relative to the current joint coordinates, are written to
+    /// `displacements` and can be applied with [`Multibody::apply_displacements`]. The
+    /// joint limits are enforced by the solver. Neither this multibody nor the rigid-bodies are
+    /// modified, so this can be called without stepping the simulation. If the root of this
+    /// multibody is dynamic, its free degrees of freedom are moved by the solver too.
+    ///
+    /// Returns `true` if the target was reached (up to the tolerances set in `options`) within
+    /// `options.max_iters` iterations. Otherwise, the displacements bring the link as close as
+    /// the solver could to the target.
+    pub fn inverse_kinematics<Bodies>(
+        &self,
+        bodies: &Bodies,
+        link_id: usize,
+        options: &InverseKinematicsOption,
+        target_pose: &Isometry<Real>,
+        displacements: &mut DVector<Real>,
+    ) -> bool
+    where
+        Bodies: ComponentSet<RigidBodyMassProps>,
+    {
+        self.solve_inverse_kinematics(
+            bodies,
+            link_id,
+            &Point::origin(),
+            target_pose,
+            options.constrained_axes,
+            options,
+            displacements,
+        )
+    }
+
+    /// Computes the generalized displacements bringing the point `local_point` of the link
+    /// `link_id` to the world-space `target_point`.
+    ///
+    /// The orientation of the link is left free, and only the linear axes in
+    /// `options.constrained_axes` are taken into account. See [`Multibody::inverse_kinematics`]
+    /// for details.
+    pub fn inverse_kinematics_point<Bodies>(
+        &self,
+        bodies: &Bodies,
+        link_id: usize,
+        options: &InverseKinematicsOption,
+        local_point: &Point<Real>,
+        target_point: &Point<Real>,
+        displacements: &mut DVector<Real>,
+    ) -> bool
+    where
+        Bodies: ComponentSet<RigidBodyMassProps>,
+    {
+        self.solve_inverse_kinematics(
+            bodies,
+            link_id,
+            local_point,
+            &Isometry::new(target_point.coords, na::zero()),
+            options.constrained_axes & JointAxesMask::LIN_AXES,
+            options,
+            displacements,
+        )
+    }
+
+    fn solve_inverse_kinematics<Bodies>(
+        &self,
+        bodies: &Bodies,
+        link_id: usize,
+        local_point: &Point<Real>,
+        target: &Isometry<Real>,
+        constrained_axes: JointAxesMask,
+        options: &InverseKinematicsOption,
+        displacements: &mut DVector<Real>,
+    ) -> bool
+    where
+        Bodies: ComponentSet<RigidBodyMassProps>,
+    {
+        let ndofs = self.ndofs();
+        let mut delta = DVector::zeros(ndofs);
+
+        if displacements.len() != ndofs {
+            *displacements = DVector::zeros(ndofs);
+        } else {
+            displacements.fill(0.0);
+        }
+
+        // Work on a copy so the poses and jacobians of `self` are left untouched.
+        let mut mb = self.clone();
+
+        for i in 0..=options.max_iters {
+            mb.update_kinematics(bodies);
+
+            let link = mb.link(link_id).expect("Invalid multibody link id.");
+            let pose = link.local_to_world;
+            let point = pose * local_point;
+
+            let mut error = SpacialVector::zeros();
+            error
+                .fixed_rows_mut::<DIM>(0)
+                .copy_from(&(target.translation.vector - point.coords));
+            #[cfg(feature = "dim2")]
+            {
+                error[DIM] = (target.rotation * pose.rotation.inverse()).angle();
+            }
+            #[cfg(feature = "dim3")]
+            {
+                error
+                    .fixed_rows_mut::<ANG_DIM>(DIM)
+                    .copy_from(&(target.rotation * pose.rotation.inverse()).scaled_axis());
+            }
+
+            let mut jacobian = mb.body_jacobian(link_id).clone();
+
+            // The body jacobian gives the velocity of the link’s center-of-mass. Shift it
+            // to get the velocity of the point we want to move.
+            {
+                let anchor = pose * Point::from(link.joint.data.local_frame2.translation.vector);
+                let com = anchor + link.shift23;
+                let shift_tr = (point - com).gcross_matrix_tr();
+                let (mut jacobian_v, jacobian_w) =
+                    jacobian.rows_range_pair_mut(0..DIM, DIM..DIM + ANG_DIM);
+                jacobian_v.gemm(1.0, &shift_tr, &jacobian_w, 1.0);
+            }
+
+            for k in 0..SPATIAL_DIM {
+                if constrained_axes.bits() & (1 << k) == 0 {
+                    error[k] = 0.0;
+                    jacobian.row_mut(k).fill(0.0);
+                }
+            }
+
+            if error.fixed_rows::<DIM>(0).norm() <= options.epsilon_linear
+                && error.fixed_rows::<ANG_DIM>(DIM).norm() <= options.epsilon_angular
+            {
+                return true;
+            }
+
+            if i == options.max_iters {
+                break;
+            }
+
+            match options.method {
+                InverseKinematicsMethod::DampedLeastSquares { damping } => {
+                    let jjt = &jacobian * jacobian.transpose()
+                        + SMatrix::<Real, SPATIAL_DIM, SPATIAL_DIM>::identity()
+                            * (damping * damping);
+                    let rhs = jjt.lu().solve(&error).unwrap_or_else(SpacialVector::zeros);
+                    delta.gemv_tr(1.0, &jacobian, &rhs, 0.0);
+                }
+                InverseKinematicsMethod::JacobianTranspose => {
+                    delta.gemv_tr(1.0, &jacobian, &error, 0.0);
+
+                    // Pick the step length minimizing the linearized error.
+                    let j_delta = &jacobian * &delta;
+                    let denom = j_delta.norm_squared();
+
+                    if denom == 0.0 {
+                        break;
+                    }
+
+                    delta *= error.dot(&j_delta) / denom;
+                }
+            }
+
+            for link in mb.links() {
+                link.joint
+                    .clamp_displacement_to_limits(&mut delta.as_mut_slice()[link.assembly_id..]);
+            }
+
+            mb.apply_displacements(delta.as_slice());
+            *displacements += &delta;
+        }
+
+        false
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{InverseKinematicsMethod, InverseKinematicsOption};
+    use crate::math::{Point, Vector};
+    use crate::test_utils::TestWorld;
+    use na::DVector;
+
+    #[test]
+    fn inverse_kinematics_two_link_arm() {
+        let methods = [
+            (
+                InverseKinematicsMethod::DampedLeastSquares { damping: 0.1 },
+                50,
+            ),
+            (InverseKinematicsMethod::JacobianTranspose, 500),
+        ];
+
+        for (method, max_iters) in methods {
+            let mut world = TestWorld::without_gravity();
+            let joints = world.add_multibody_arm(Vector::zeros(), 2);
+            let target = Point::from(Vector::x() * 1.2 + Vector::y() * 1.2);
+            let options = InverseKinematicsOption {
+                method,
+                max_iters,
+                ..InverseKinematicsOption::default()
+            };
+
+            let (multibody, tip) = world.multibody_joints.get_mut_internal(joints[1]).unwrap();
+            let mut displacements = DVector::zeros(0);
+            assert!(multibody.inverse_kinematics_point(
+                &world.bodies,
+                tip,
+                &options,
+                &Point::origin(),
+                &target,
+                &mut displacements,
+            ));
+
+            multibody.apply_displacements(displacements.as_slice());
+            multibody.forward_kinematics(&mut world.bodies, false);
+            let reached = multibody
+                .link(tip)
+                .unwrap()
+                .local_to_world
+                .translation
+                .vector;
+            assert!((reached - target.coords).norm() <= options.epsilon_linear);
+        }
+    }
+}
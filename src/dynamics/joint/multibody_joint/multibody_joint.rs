@@ -1,4 +1,4 @@
-use crate::dynamics::solver::AnyJointVelocityConstraint;
+use crate::dynamics::solver::{AnyJointVelocityConstraint, WritebackId};
 use crate::dynamics::{
     joint, FixedJointBuilder, GenericJoint, IntegrationParameters, Multibody, MultibodyLink,
     RigidBodyVelocity,
@@ -9,11 +9,20 @@ use crate::math::{
 };
 use na::{DVector, DVectorSliceMut};
 #[cfg(feature = "dim3")]
-use na::{UnitQuaternion, Vector3};
+use {
+    crate::dynamics::JointAxesMask,
+    crate::math::DEFAULT_EPSILON,
+    crate::utils::WBasis,
+    na::{UnitQuaternion, Vector3},
+};
 
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-serialize", serde(default))]
 #[derive(Copy, Clone, Debug)]
 /// An joint attached to two bodies based on the reduced coordinates formalism.
+///
+/// When deserialized, missing fields are set to their value in `MultibodyJoint::default()`,
+/// i.e., a joint with a default description at its rest configuration.
 pub struct MultibodyJoint {
     /// The joint’s description.
     pub data: GenericJoint,
@@ -21,6 +30,12 @@ pub struct MultibodyJoint {
     pub(crate) joint_rot: Rotation<Real>,
 }
 
+impl Default for MultibodyJoint {
+    fn default() -> Self {
+        Self::new(GenericJoint::default())
+    }
+}
+
 impl MultibodyJoint {
     /// Creates a new multibody joint from its description.
     pub fn new(data: GenericJoint) -> Self {
@@ -125,6 +140,44 @@ impl MultibodyJoint {
         self.integrate(1.0, disp);
     }
 
+    /// Clamps the displacement `disp` so that applying it to this multibody_joint doesn’t
+    /// move its coordinates outside of its limits.
+    ///
+    /// Limits are ignored for the angular degrees of freedom of joints with more than one
+    /// free angular degree of freedom.
+    pub(crate) fn clamp_displacement_to_limits(&self, disp: &mut [Real]) {
+        let locked_bits = self.data.locked_axes.bits();
+        let limit_bits = self.data.limit_axes.bits();
+        let mut curr_free_dof = 0;
+
+        for i in 0..DIM {
+            if (locked_bits & (1 << i)) == 0 {
+                if (limit_bits & (1 << i)) != 0 {
+                    let limits = &self.data.limits[i];
+                    let new_coord = (self.coords[i] + disp[curr_free_dof])
+                        .max(limits.min)
+                        .min(limits.max);
+                    disp[curr_free_dof] = new_coord - self.coords[i];
+                }
+                curr_free_dof += 1;
+            }
+        }
+
+        let locked_ang_bits = locked_bits >> DIM;
+        let num_free_ang_dofs = ANG_DIM - locked_ang_bits.count_ones() as usize;
+        if num_free_ang_dofs == 1 {
+            let i = DIM + (!locked_ang_bits).trailing_zeros() as usize;
+
+            if (limit_bits & (1 << i)) != 0 {
+                let limits = &self.data.limits[i];
+                let new_coord = (self.coords[i] + disp[curr_free_dof])
+                    .max(limits.min)
+                    .min(limits.max);
+                disp[curr_free_dof] = new_coord - self.coords[i];
+            }
+        }
+    }
+
     /// Sets in `out` the non-zero entries of the multibody_joint jacobian transformed by `transform`.
     pub fn jacobian(&self, transform: &Rotation<Real>, out: &mut JacobianSliceMut<Real>) {
         let locked_bits = self.data.locked_axes.bits();
@@ -234,9 +287,19 @@ impl MultibodyJoint {
         let motor_bits = self.data.motor_axes.bits();
         let mut num_constraints = 0;
 
+        #[allow(unused_mut)] // The mut is needed for 3D
+        let mut unit_limit_bits = limit_bits;
+
+        #[cfg(feature = "dim3")]
+        if let Some(twist_axis) = self.cone_twist_axis() {
+            // One coupled swing limit, plus the twist limit.
+            num_constraints += 1 + ((limit_bits >> DIM) & (1 << twist_axis) != 0) as usize;
+            unit_limit_bits &= !JointAxesMask::ANG_AXES.bits();
+        }
+
         for i in 0..SPATIAL_DIM {
             if (locked_bits & (1 << i)) == 0 {
-                if (limit_bits & (1 << i)) != 0 {
+                if (unit_limit_bits & (1 << i)) != 0 {
                     num_constraints += 1;
                 }
                 if (motor_bits & (1 << i)) != 0 {
@@ -282,6 +345,7 @@ impl MultibodyJoint {
                         self.coords[i],
                         limits,
                         dof_id + curr_free_dof,
+                        WritebackId::Motor(i),
                         j_id,
                         jacobians,
                         constraints,
@@ -297,6 +361,7 @@ impl MultibodyJoint {
                         [self.data.limits[i].min, self.data.limits[i].max],
                         self.coords[i],
                         dof_id + curr_free_dof,
+                        WritebackId::Limit(i),
                         j_id,
                         jacobians,
                         constraints,
@@ -307,6 +372,25 @@ impl MultibodyJoint {
             }
         }
 
+        #[allow(unused_mut)] // The mut is needed for 3D
+        let mut unit_limit_bits = limit_bits;
+
+        #[cfg(feature = "dim3")]
+        if let Some(twist_axis) = self.cone_twist_axis() {
+            self.cone_twist_limit_constraints(
+                params,
+                multibody,
+                link,
+                twist_axis,
+                dof_id + curr_free_dof,
+                j_id,
+                jacobians,
+                constraints,
+                insert_at,
+            );
+            unit_limit_bits &= !JointAxesMask::ANG_AXES.bits();
+        }
+
         /*
         let locked_ang_bits = locked_bits >> DIM;
         let num_free_ang_dofs = ANG_DIM - locked_ang_bits.count_ones() as usize;
@@ -323,7 +407,7 @@ impl MultibodyJoint {
         // TODO: we should make special cases for multi-angular-dofs limits/motors
         for i in DIM..SPATIAL_DIM {
             if (locked_bits & (1 << i)) == 0 {
-                let limits = if (limit_bits & (1 << i)) != 0 {
+                let limits = if (unit_limit_bits & (1 << i)) != 0 {
                     let limits = [self.data.limits[i].min, self.data.limits[i].max];
                     joint::unit_joint_limit_constraint(
                         params,
@@ -332,6 +416,7 @@ impl MultibodyJoint {
                         limits,
                         self.coords[i],
                         dof_id + curr_free_dof,
+                        WritebackId::Limit(i),
                         j_id,
                         jacobians,
                         constraints,
@@ -351,6 +436,7 @@ impl MultibodyJoint {
                         self.coords[i],
                         limits,
                         dof_id + curr_free_dof,
+                        WritebackId::Motor(i),
                         j_id,
                         jacobians,
                         constraints,
@@ -361,4 +447,91 @@ impl MultibodyJoint {
             }
         }
     }
+
+    /// The twist axis of this multibody_joint, if it has a cone-twist limit.
+    ///
+    /// A multibody_joint has a cone-twist limit if all its angular degrees of freedom are free,
+    /// and at least one of them is both limited and coupled. See `GenericJoint::cone_twist_axis`.
+    #[cfg(feature = "dim3")]
+    fn cone_twist_axis(&self) -> Option<usize> {
+        let locked_ang_bits = self.data.locked_axes.bits() >> DIM;
+        let swing_bits = (self.data.limit_axes & self.data.coupled_axes).bits() >> DIM;
+
+        if locked_ang_bits == 0 && swing_bits != 0 {
+            Some(self.data.cone_twist_axis())
+        } else {
+            None
+        }
+    }
+
+    /// Generates the swing and twist limit constraints of a multibody_joint with a cone-twist limit.
+    ///
+    /// The angular degrees of freedom of such a multibody_joint start at `ang_dof_id`, and are the
+    /// relative angular velocity expressed in the joint’s first frame.
+    #[cfg(feature = "dim3")]
+    fn cone_twist_limit_constraints(
+        &self,
+        params: &IntegrationParameters,
+        multibody: &Multibody,
+        link: &MultibodyLink,
+        twist_axis: usize,
+        ang_dof_id: usize,
+        j_id: &mut usize,
+        jacobians: &mut DVector<Real>,
+        constraints: &mut Vec<AnyJointVelocityConstraint>,
+        insert_at: &mut Option<usize>,
+    ) {
+        let swing_bits = (self.data.limit_axes & self.data.coupled_axes).bits() >> DIM;
+        let axis1 = Vector::ith(twist_axis, 1.0);
+        let axis2 = self.joint_rot * axis1;
+
+        let swing = Rotation::rotation_between(&axis1, &axis2).unwrap_or_else(Rotation::identity);
+        let (swing_axis, swing_angle) = swing
+            .axis_angle()
+            .map(|(axis, angle)| (axis.into_inner(), angle))
+            .unwrap_or_else(|| (axis1.orthonormal_basis()[0], 0.0));
+
+        joint::angular_joint_limit_constraint(
+            params,
+            multibody,
+            link,
+            &swing_axis,
+            [0.0, self.data.max_swing_angle(&swing_axis)],
+            swing_angle,
+            ang_dof_id,
+            WritebackId::Limit(DIM + swing_bits.trailing_zeros() as usize),
+            j_id,
+            jacobians,
+            constraints,
+            insert_at,
+        );
+
+        let i = DIM + twist_axis;
+        if self.data.limit_axes.bits() & (1 << i) != 0 {
+            // Rotations around the bisector of both twist axes don’t affect the swing angle.
+            let bisector = (axis1 + axis2)
+                .try_normalize(DEFAULT_EPSILON)
+                .unwrap_or(axis1);
+            let mut rot = self.joint_rot;
+            if rot.w < 0.0 {
+                rot = UnitQuaternion::new_unchecked(-rot.into_inner());
+            }
+            let twist_angle = 2.0 * rot.imag()[twist_axis].atan2(rot.w);
+
+            joint::angular_joint_limit_constraint(
+                params,
+                multibody,
+                link,
+                &bisector,
+                [self.data.limits[i].min, self.data.limits[i].max],
+                twist_angle,
+                ang_dof_id,
+                WritebackId::Limit(i),
+                j_id,
+                jacobians,
+                constraints,
+                insert_at,
+            );
+        }
+    }
 }
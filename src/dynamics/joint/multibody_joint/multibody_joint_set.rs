@@ -5,6 +5,7 @@ use crate::dynamics::{
     RigidBodyIds, RigidBodyType,
 };
 use crate::geometry::{InteractionGraph, RigidBodyGraphIndex};
+use crate::math::Real;
 use crate::parry::partitioning::IndexedData;
 
 /// The unique handle of an multibody_joint added to a `MultibodyJointSet`.
@@ -321,6 +322,28 @@ impl MultibodyJointSet {
         Some((multibody, link.id))
     }
 
+    /// Adds generalized forces (or torques) to the degrees of freedom of the multibody joint
+    /// identified by `handle`.
+    ///
+    /// The `forces` slice must contain one element per degree of freedom of the joint. These
+    /// forces are applied during the next timestep only. They have no effect if the multibody
+    /// is sleeping, so make sure to wake up one of its rigid-bodies first.
+    pub fn add_joint_forces(&mut self, handle: MultibodyJointHandle, forces: &[Real]) {
+        if let Some((multibody, link_id)) = self.get_mut_internal(handle) {
+            multibody.add_joint_forces(link_id, forces);
+        }
+    }
+
+    /// Enables or disables contacts between the colliders attached to the rigid-body of the
+    /// link identified by `handle` and the ones attached to its parent link.
+    pub fn set_contacts_enabled(&mut self, handle: MultibodyJointHandle, enabled: bool) {
+        if let Some((multibody, link_id)) = self.get_mut_internal(handle) {
+            if let Some(link) = multibody.link_mut(link_id) {
+                link.joint.data.set_contacts_enabled(enabled);
+            }
+        }
+    }
+
     /// Gets the joint with the given handle without a known generation.
     ///
     /// This is useful when you know you want the joint at index `i` but
@@ -375,3 +398,32 @@ impl std::ops::Index<MultibodyIndex> for MultibodyJointSet {
 //         &self.multibodies[index.0]
 //     }
 // }
+
+#[cfg(test)]
+mod test {
+    use crate::dynamics::PrismaticJointBuilder;
+    use crate::math::{Point, Real, Vector};
+    use crate::test_utils::TestWorld;
+
+    // Drops a multibody link sliding along a vertical axis attached to the ground, and
+    // returns its final height.
+    fn falling_link_height(contacts_enabled: bool) -> Real {
+        let mut world = TestWorld::new();
+
+        let ground = world.add_ground();
+        let ball = world.add_ball(Vector::y() * 2.0, 0.5);
+        let joint = PrismaticJointBuilder::new(Vector::y_axis())
+            .local_anchor2(Point::from(Vector::y() * -2.0))
+            .contacts_enabled(contacts_enabled);
+        world.multibody_joints.insert(ground, ball, joint).unwrap();
+
+        world.step_n(120);
+        world.bodies[ball].translation().y
+    }
+
+    #[test]
+    fn multibody_link_without_contacts_falls_through_parent() {
+        assert!(falling_link_height(false) < -1.0);
+        assert!((falling_link_height(true) - 0.5).abs() < 0.05);
+    }
+}
@@ -1,7 +1,7 @@
 use std::ops::{Deref, DerefMut};
 
 use crate::dynamics::{MultibodyJoint, RigidBodyHandle};
-use crate::math::{Isometry, Real, Vector};
+use crate::math::{AngVector, Isometry, Real, SpacialVector, Vector, DIM};
 use crate::prelude::RigidBodyVelocity;
 
 /// One link of a multibody.
@@ -100,6 +100,38 @@ impl MultibodyLink {
     pub fn local_to_parent(&self) -> &Isometry<Real> {
         &self.local_to_parent
     }
+
+    /// The impulses applied along each axis of this link’s joint by its limits and motors
+    /// during the last timestep.
+    ///
+    /// The first `DIM` components are the linear impulses and the remaining ones are the angular
+    /// impulses, expressed in the joint’s local frame. Locked axes are enforced by the
+    /// reduced-coordinates formulation of the multibody itself so their components are
+    /// always zero.
+    pub fn total_impulses(&self) -> SpacialVector<Real> {
+        self.joint.data.limit_motor_and_friction_impulses()
+    }
+
+    /// The linear impulse applied by this link’s joint limits and motors during the last timestep.
+    ///
+    /// See [`MultibodyLink::total_impulses`] for details.
+    pub fn linear_impulse(&self) -> Vector<Real> {
+        self.total_impulses().fixed_rows::<DIM>(0).into_owned()
+    }
+
+    /// The angular impulse applied by this link’s joint limits and motors during the last timestep.
+    ///
+    /// See [`MultibodyLink::total_impulses`] for details.
+    pub fn angular_impulse(&self) -> AngVector<Real> {
+        let impulses = self.total_impulses();
+
+        #[cfg(feature = "dim2")]
+        return impulses[DIM];
+        #[cfg(feature = "dim3")]
+        return impulses
+            .fixed_rows::<{ crate::math::ANG_DIM }>(DIM)
+            .into_owned();
+    }
 }
 
 // FIXME: keep this even if we already have the Index2 traits?
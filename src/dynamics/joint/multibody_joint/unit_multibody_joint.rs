@@ -6,6 +6,8 @@ use crate::dynamics::solver::{
 };
 use crate::dynamics::{IntegrationParameters, JointMotor, Multibody};
 use crate::math::Real;
+#[cfg(feature = "dim3")]
+use crate::math::Vector;
 use na::DVector;
 
 /// Initializes and generate the velocity constraints applicable to the multibody links attached
@@ -17,6 +19,7 @@ pub fn unit_joint_limit_constraint(
     limits: [Real; 2],
     curr_pos: Real,
     dof_id: usize,
+    writeback_id: WritebackId,
     j_id: &mut usize,
     jacobians: &mut DVector<Real>,
     constraints: &mut Vec<AnyJointVelocityConstraint>,
@@ -27,8 +30,7 @@ pub fn unit_joint_limit_constraint(
 
     let min_enabled = curr_pos < limits[0];
     let max_enabled = limits[1] < curr_pos;
-    let erp_inv_dt = params.joint_erp_inv_dt();
-    let cfm_coeff = params.joint_cfm_coeff();
+    let (erp_inv_dt, cfm_coeff) = link.joint.data.erp_inv_dt_and_cfm_coeff(params);
     let rhs_bias = ((curr_pos - limits[1]).max(0.0) - (limits[0] - curr_pos).max(0.0)) * erp_inv_dt;
     let rhs_wo_bias = joint_velocity[dof_id];
 
@@ -51,6 +53,7 @@ pub fn unit_joint_limit_constraint(
         ndofs2: ndofs,
         j_id2: *j_id,
         joint_id: usize::MAX,
+        multibody_link: Some(link.rigid_body),
         impulse: 0.0,
         impulse_bounds,
         inv_lhs: crate::utils::inv(lhs),
@@ -58,7 +61,79 @@ pub fn unit_joint_limit_constraint(
         rhs_wo_bias,
         cfm_coeff,
         cfm_gain: 0.0,
-        writeback_id: WritebackId::Limit(dof_id),
+        writeback_id,
+    };
+
+    if let Some(at) = insert_at {
+        constraints[*at] = AnyJointVelocityConstraint::JointGenericGroundConstraint(constraint);
+        *at += 1;
+    } else {
+        constraints.push(AnyJointVelocityConstraint::JointGenericGroundConstraint(
+            constraint,
+        ));
+    }
+    *j_id += 2 * ndofs;
+}
+
+/// Initializes and generate a velocity constraint limiting the relative rotation of a multibody link
+/// around the given axis.
+///
+/// The axis is expressed in the first frame of the multibody_joint, and `dof_id` is the first of its
+/// three angular degrees of freedom.
+#[cfg(feature = "dim3")]
+pub fn angular_joint_limit_constraint(
+    params: &IntegrationParameters,
+    multibody: &Multibody,
+    link: &MultibodyLink,
+    axis: &Vector<Real>,
+    limits: [Real; 2],
+    curr_pos: Real,
+    dof_id: usize,
+    writeback_id: WritebackId,
+    j_id: &mut usize,
+    jacobians: &mut DVector<Real>,
+    constraints: &mut Vec<AnyJointVelocityConstraint>,
+    insert_at: &mut Option<usize>,
+) {
+    let ndofs = multibody.ndofs();
+    let joint_velocity = multibody.joint_velocity(link);
+
+    let min_enabled = curr_pos < limits[0];
+    let max_enabled = limits[1] < curr_pos;
+    let (erp_inv_dt, cfm_coeff) = link.joint.data.erp_inv_dt_and_cfm_coeff(params);
+    let rhs_bias = ((curr_pos - limits[1]).max(0.0) - (limits[0] - curr_pos).max(0.0)) * erp_inv_dt;
+    let rhs_wo_bias = axis.dot(&joint_velocity.fixed_rows::<3>(dof_id));
+
+    let dof_j_id = *j_id + dof_id + link.assembly_id;
+    jacobians.rows_mut(*j_id, ndofs * 2).fill(0.0);
+    jacobians.fixed_rows_mut::<3>(dof_j_id).copy_from(axis);
+    jacobians
+        .fixed_rows_mut::<3>(dof_j_id + ndofs)
+        .copy_from(axis);
+    multibody
+        .inv_augmented_mass()
+        .solve_mut(&mut jacobians.rows_mut(*j_id + ndofs, ndofs));
+
+    let lhs = axis.dot(&jacobians.fixed_rows::<3>(dof_j_id + ndofs)); // = J^t * M^-1 J
+    let impulse_bounds = [
+        min_enabled as u32 as Real * -Real::MAX,
+        max_enabled as u32 as Real * Real::MAX,
+    ];
+
+    let constraint = JointGenericVelocityGroundConstraint {
+        mj_lambda2: multibody.solver_id,
+        ndofs2: ndofs,
+        j_id2: *j_id,
+        joint_id: usize::MAX,
+        multibody_link: Some(link.rigid_body),
+        impulse: 0.0,
+        impulse_bounds,
+        inv_lhs: crate::utils::inv(lhs),
+        rhs: rhs_wo_bias + rhs_bias,
+        rhs_wo_bias,
+        cfm_coeff,
+        cfm_gain: 0.0,
+        writeback_id,
     };
 
     if let Some(at) = insert_at {
@@ -82,6 +157,7 @@ pub fn unit_joint_motor_constraint(
     curr_pos: Real,
     limits: Option<[Real; 2]>,
     dof_id: usize,
+    writeback_id: WritebackId,
     j_id: &mut usize,
     jacobians: &mut DVector<Real>,
     constraints: &mut Vec<AnyJointVelocityConstraint>,
@@ -102,7 +178,6 @@ pub fn unit_joint_motor_constraint(
         .solve_mut(&mut jacobians.rows_mut(*j_id + ndofs, ndofs));
 
     let lhs = jacobians[dof_j_id + ndofs]; // = J^t * M^-1 J
-    let impulse_bounds = [-motor_params.max_impulse, motor_params.max_impulse];
 
     let mut rhs_wo_bias = 0.0;
     if motor_params.erp_inv_dt != 0.0 {
@@ -119,12 +194,14 @@ pub fn unit_joint_motor_constraint(
 
     let dvel = joint_velocity[dof_id];
     rhs_wo_bias += dvel - target_vel;
+    let impulse_bounds = motor_params.impulse_bounds(dvel);
 
     let constraint = JointGenericVelocityGroundConstraint {
         mj_lambda2: multibody.solver_id,
         ndofs2: ndofs,
         j_id2: *j_id,
         joint_id: usize::MAX,
+        multibody_link: Some(link.rigid_body),
         impulse: 0.0,
         impulse_bounds,
         cfm_coeff: motor_params.cfm_coeff,
@@ -132,7 +209,7 @@ pub fn unit_joint_motor_constraint(
         inv_lhs: crate::utils::inv(lhs),
         rhs: rhs_wo_bias,
         rhs_wo_bias,
-        writeback_id: WritebackId::Limit(dof_id),
+        writeback_id,
     };
 
     if let Some(at) = insert_at {
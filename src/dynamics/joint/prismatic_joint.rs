@@ -1,5 +1,5 @@
-use crate::dynamics::joint::{GenericJoint, GenericJointBuilder, JointAxesMask};
-use crate::dynamics::{JointAxis, MotorModel};
+use crate::dynamics::joint::{GenericJoint, GenericJointBuilder, JointAxesMask, JointSoftness};
+use crate::dynamics::{ActuatorModel, JointAxis, JointFriction, MotorModel};
 use crate::math::{Point, Real, UnitVector};
 
 use super::{JointLimits, JointMotor};
@@ -127,6 +127,30 @@ impl PrismaticJoint {
         self
     }
 
+    /// Sets the model of the actuator driving the motor.
+    pub fn set_motor_actuator(&mut self, actuator: ActuatorModel) -> &mut Self {
+        self.data.set_motor_actuator(JointAxis::X, actuator);
+        self
+    }
+
+    /// The dry friction and viscous damping along the joint’s principal axis.
+    #[must_use]
+    pub fn friction(&self) -> &JointFriction {
+        self.data.friction(JointAxis::X)
+    }
+
+    /// Sets the maximum force the dry friction can apply along the joint’s principal axis.
+    pub fn set_friction(&mut self, max_force: Real) -> &mut Self {
+        self.data.set_friction(JointAxis::X, max_force);
+        self
+    }
+
+    /// Sets the viscous damping coefficient along the joint’s principal axis.
+    pub fn set_damping(&mut self, damping: Real) -> &mut Self {
+        self.data.set_damping(JointAxis::X, damping);
+        self
+    }
+
     /// The limit distance attached bodies can translate along the joint’s principal axis.
     #[must_use]
     pub fn limits(&self) -> Option<&JointLimits<Real>> {
@@ -138,6 +162,59 @@ impl PrismaticJoint {
         self.data.set_limits(JointAxis::X, limits);
         self
     }
+
+    /// The maximum linear force this joint can apply before breaking.
+    #[must_use]
+    pub fn max_force(&self) -> Real {
+        self.data.max_force
+    }
+
+    /// Sets the maximum linear force this joint can apply before breaking.
+    pub fn set_max_force(&mut self, max_force: Real) -> &mut Self {
+        self.data.set_max_force(max_force);
+        self
+    }
+
+    /// The maximum torque this joint can apply before breaking.
+    #[must_use]
+    pub fn max_torque(&self) -> Real {
+        self.data.max_torque
+    }
+
+    /// Sets the maximum torque this joint can apply before breaking.
+    pub fn set_max_torque(&mut self, max_torque: Real) -> &mut Self {
+        self.data.set_max_torque(max_torque);
+        self
+    }
+
+    /// The softness of this joint’s constraints, if it overrides the global one.
+    #[must_use]
+    pub fn softness(&self) -> Option<JointSoftness> {
+        self.data.softness
+    }
+
+    /// Sets the softness of this joint’s constraints.
+    ///
+    /// Set to `None` to use the global `IntegrationParameters::joint_erp` and
+    /// `IntegrationParameters::joint_damping_ratio`.
+    pub fn set_softness(&mut self, softness: Option<JointSoftness>) -> &mut Self {
+        self.data.set_softness(softness);
+        self
+    }
+
+    /// Are contacts between the colliders attached to the two rigid-bodies linked by this
+    /// joint enabled?
+    #[must_use]
+    pub fn contacts_enabled(&self) -> bool {
+        self.data.contacts_enabled()
+    }
+
+    /// Enables or disables contacts between the colliders attached to the two rigid-bodies
+    /// linked by this joint.
+    pub fn set_contacts_enabled(&mut self, enabled: bool) -> &mut Self {
+        self.data.set_contacts_enabled(enabled);
+        self
+    }
 }
 
 impl Into<GenericJoint> for PrismaticJoint {
@@ -229,6 +306,27 @@ impl PrismaticJointBuilder {
         self
     }
 
+    /// Sets the model of the actuator driving the motor.
+    #[must_use]
+    pub fn motor_actuator(mut self, actuator: ActuatorModel) -> Self {
+        self.0.set_motor_actuator(actuator);
+        self
+    }
+
+    /// Sets the maximum force the dry friction can apply along the joint’s principal axis.
+    #[must_use]
+    pub fn friction(mut self, max_force: Real) -> Self {
+        self.0.set_friction(max_force);
+        self
+    }
+
+    /// Sets the viscous damping coefficient along the joint’s principal axis.
+    #[must_use]
+    pub fn damping(mut self, damping: Real) -> Self {
+        self.0.set_damping(damping);
+        self
+    }
+
     /// Sets the `[min,max]` limit distances attached bodies can translate along the joint’s principal axis.
     #[must_use]
     pub fn limits(mut self, limits: [Real; 2]) -> Self {
@@ -236,6 +334,35 @@ impl PrismaticJointBuilder {
         self
     }
 
+    /// Sets the maximum linear force this joint can apply before breaking.
+    #[must_use]
+    pub fn max_force(mut self, max_force: Real) -> Self {
+        self.0.set_max_force(max_force);
+        self
+    }
+
+    /// Sets the maximum torque this joint can apply before breaking.
+    #[must_use]
+    pub fn max_torque(mut self, max_torque: Real) -> Self {
+        self.0.set_max_torque(max_torque);
+        self
+    }
+
+    /// Sets the softness of this joint’s constraints.
+    #[must_use]
+    pub fn softness(mut self, softness: JointSoftness) -> Self {
+        self.0.set_softness(Some(softness));
+        self
+    }
+
+    /// Enables or disables contacts between the colliders attached to the two rigid-bodies
+    /// linked by this joint.
+    #[must_use]
+    pub fn contacts_enabled(mut self, enabled: bool) -> Self {
+        self.0.set_contacts_enabled(enabled);
+        self
+    }
+
     /// Builds the prismatic joint.
     #[must_use]
     pub fn build(self) -> PrismaticJoint {
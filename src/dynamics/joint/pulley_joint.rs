@@ -0,0 +1,208 @@
+use crate::dynamics::joint::{GenericJoint, GenericJointBuilder, JointAxesMask};
+use crate::dynamics::{JointCoupling, JointCouplingKind};
+use crate::math::{Point, Real};
+
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(transparent)]
+/// A pulley joint, hangs two bodies from a rope passing through two fixed pulleys.
+///
+/// The distances between each anchor and its ground anchor satisfy
+/// `length1 + ratio * length2 <= length`: when one body goes down, the other one goes up.
+/// The rope is slack (and applies no force) while this inequality is strict. The relative
+/// rotation of both bodies is left free.
+///
+/// This joint is only supported as an impulse joint between bodies that aren’t part of a
+/// multibody.
+pub struct PulleyJoint {
+    data: GenericJoint,
+}
+
+impl PulleyJoint {
+    /// Creates a new pulley joint with the given world-space ground anchors and rope length.
+    pub fn new(ground_anchor1: Point<Real>, ground_anchor2: Point<Real>, length: Real) -> Self {
+        let kind = JointCouplingKind::Pulley {
+            ground_anchor1,
+            ground_anchor2,
+            length,
+        };
+        let data = GenericJointBuilder::new(JointAxesMask::empty())
+            .coupling(JointCoupling::new(kind, 1.0))
+            .build();
+        Self { data }
+    }
+
+    /// The underlying generic joint.
+    pub fn data(&self) -> &GenericJoint {
+        &self.data
+    }
+
+    /// The joint’s anchor, expressed in the local-space of the first rigid-body.
+    #[must_use]
+    pub fn local_anchor1(&self) -> Point<Real> {
+        self.data.local_anchor1()
+    }
+
+    /// Sets the joint’s anchor, expressed in the local-space of the first rigid-body.
+    pub fn set_local_anchor1(&mut self, anchor1: Point<Real>) -> &mut Self {
+        self.data.set_local_anchor1(anchor1);
+        self
+    }
+
+    /// The joint’s anchor, expressed in the local-space of the second rigid-body.
+    #[must_use]
+    pub fn local_anchor2(&self) -> Point<Real> {
+        self.data.local_anchor2()
+    }
+
+    /// Sets the joint’s anchor, expressed in the local-space of the second rigid-body.
+    pub fn set_local_anchor2(&mut self, anchor2: Point<Real>) -> &mut Self {
+        self.data.set_local_anchor2(anchor2);
+        self
+    }
+
+    /// The world-space points the two bodies hang from.
+    #[must_use]
+    pub fn ground_anchors(&self) -> [Point<Real>; 2] {
+        match self.data.coupling.map(|c| c.kind) {
+            Some(JointCouplingKind::Pulley {
+                ground_anchor1,
+                ground_anchor2,
+                ..
+            }) => [ground_anchor1, ground_anchor2],
+            _ => [Point::origin(); 2],
+        }
+    }
+
+    /// Sets the world-space points the two bodies hang from.
+    pub fn set_ground_anchors(
+        &mut self,
+        ground_anchor1: Point<Real>,
+        ground_anchor2: Point<Real>,
+    ) -> &mut Self {
+        if let Some(JointCouplingKind::Pulley {
+            ground_anchor1: anchor1,
+            ground_anchor2: anchor2,
+            ..
+        }) = self.data.coupling.as_mut().map(|c| &mut c.kind)
+        {
+            *anchor1 = ground_anchor1;
+            *anchor2 = ground_anchor2;
+        }
+        self
+    }
+
+    /// The total length of the rope.
+    #[must_use]
+    pub fn length(&self) -> Real {
+        match self.data.coupling.map(|c| c.kind) {
+            Some(JointCouplingKind::Pulley { length, .. }) => length,
+            _ => 0.0,
+        }
+    }
+
+    /// Sets the total length of the rope.
+    pub fn set_length(&mut self, new_length: Real) -> &mut Self {
+        if let Some(JointCouplingKind::Pulley { length, .. }) =
+            self.data.coupling.as_mut().map(|c| &mut c.kind)
+        {
+            *length = new_length;
+        }
+        self
+    }
+
+    /// The pulley ratio, i.e., the mechanical advantage of the second body over the first one.
+    #[must_use]
+    pub fn ratio(&self) -> Real {
+        self.data.coupling.map(|c| c.ratio).unwrap_or(0.0)
+    }
+
+    /// Sets the pulley ratio.
+    pub fn set_ratio(&mut self, ratio: Real) -> &mut Self {
+        if let Some(coupling) = &mut self.data.coupling {
+            coupling.ratio = ratio;
+        }
+        self
+    }
+
+    /// Are contacts between the colliders attached to the two rigid-bodies linked by this
+    /// joint enabled?
+    #[must_use]
+    pub fn contacts_enabled(&self) -> bool {
+        self.data.contacts_enabled()
+    }
+
+    /// Enables or disables contacts between the colliders attached to the two rigid-bodies
+    /// linked by this joint.
+    pub fn set_contacts_enabled(&mut self, enabled: bool) -> &mut Self {
+        self.data.set_contacts_enabled(enabled);
+        self
+    }
+}
+
+impl Into<GenericJoint> for PulleyJoint {
+    fn into(self) -> GenericJoint {
+        self.data
+    }
+}
+
+/// Create pulley joints using the builder pattern.
+///
+/// A pulley joint hangs two bodies from a rope passing through two fixed pulleys.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PulleyJointBuilder(PulleyJoint);
+
+impl PulleyJointBuilder {
+    /// Creates a new builder for pulley joints with the given world-space ground anchors and rope length.
+    pub fn new(ground_anchor1: Point<Real>, ground_anchor2: Point<Real>, length: Real) -> Self {
+        Self(PulleyJoint::new(ground_anchor1, ground_anchor2, length))
+    }
+
+    /// Sets the joint’s anchor, expressed in the local-space of the first rigid-body.
+    #[must_use]
+    pub fn local_anchor1(mut self, anchor1: Point<Real>) -> Self {
+        self.0.set_local_anchor1(anchor1);
+        self
+    }
+
+    /// Sets the joint’s anchor, expressed in the local-space of the second rigid-body.
+    #[must_use]
+    pub fn local_anchor2(mut self, anchor2: Point<Real>) -> Self {
+        self.0.set_local_anchor2(anchor2);
+        self
+    }
+
+    /// Sets the total length of the rope.
+    #[must_use]
+    pub fn length(mut self, length: Real) -> Self {
+        self.0.set_length(length);
+        self
+    }
+
+    /// Sets the pulley ratio.
+    #[must_use]
+    pub fn ratio(mut self, ratio: Real) -> Self {
+        self.0.set_ratio(ratio);
+        self
+    }
+
+    /// Enables or disables contacts between the colliders attached to the two rigid-bodies
+    /// linked by this joint.
+    #[must_use]
+    pub fn contacts_enabled(mut self, enabled: bool) -> Self {
+        self.0.set_contacts_enabled(enabled);
+        self
+    }
+
+    /// Builds the pulley joint.
+    #[must_use]
+    pub fn build(self) -> PulleyJoint {
+        self.0
+    }
+}
+
+impl Into<GenericJoint> for PulleyJointBuilder {
+    fn into(self) -> GenericJoint {
+        self.0.into()
+    }
+}
@@ -0,0 +1,194 @@
+use crate::dynamics::joint::{GenericJoint, GenericJointBuilder, JointAxesMask};
+use crate::dynamics::{JointCoupling, JointCouplingKind};
+use crate::math::{Point, Real, UnitVector};
+
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(transparent)]
+/// A rack-and-pinion joint, couples the rotation of a pinion (the first body) to the translation
+/// of a rack (the second body).
+///
+/// The angular velocity of the pinion around its axis and the linear velocity of the rack’s
+/// anchor along the rack axis satisfy `angvel1 + ratio * linvel2 = 0`. The ratio is usually
+/// the inverse of the pinion’s radius. This joint doesn’t constrain the relative position of
+/// both bodies: it is generally combined with a revolute joint for the pinion and a prismatic
+/// joint for the rack.
+///
+/// This joint is only supported as an impulse joint between bodies that aren’t part of a
+/// multibody.
+pub struct RackAndPinionJoint {
+    data: GenericJoint,
+}
+
+impl RackAndPinionJoint {
+    /// Creates a new rack-and-pinion joint.
+    ///
+    /// The rack axis is expressed in the local-space of the second rigid-body.
+    pub fn new(rack_axis: UnitVector<Real>, ratio: Real) -> Self {
+        let data = GenericJointBuilder::new(JointAxesMask::empty())
+            .local_axis2(rack_axis)
+            .coupling(JointCoupling::new(JointCouplingKind::RackAndPinion, ratio))
+            .build();
+        Self { data }
+    }
+
+    /// The underlying generic joint.
+    pub fn data(&self) -> &GenericJoint {
+        &self.data
+    }
+
+    /// The joint’s anchor, expressed in the local-space of the first rigid-body.
+    #[must_use]
+    pub fn local_anchor1(&self) -> Point<Real> {
+        self.data.local_anchor1()
+    }
+
+    /// Sets the joint’s anchor, expressed in the local-space of the first rigid-body.
+    pub fn set_local_anchor1(&mut self, anchor1: Point<Real>) -> &mut Self {
+        self.data.set_local_anchor1(anchor1);
+        self
+    }
+
+    /// The joint’s anchor, expressed in the local-space of the second rigid-body.
+    #[must_use]
+    pub fn local_anchor2(&self) -> Point<Real> {
+        self.data.local_anchor2()
+    }
+
+    /// Sets the joint’s anchor, expressed in the local-space of the second rigid-body.
+    pub fn set_local_anchor2(&mut self, anchor2: Point<Real>) -> &mut Self {
+        self.data.set_local_anchor2(anchor2);
+        self
+    }
+
+    /// The rotation axis of the pinion, expressed in the local-space of the first rigid-body.
+    #[cfg(feature = "dim3")]
+    #[must_use]
+    pub fn pinion_axis(&self) -> UnitVector<Real> {
+        self.data.local_axis1()
+    }
+
+    /// Sets the rotation axis of the pinion, expressed in the local-space of the first rigid-body.
+    #[cfg(feature = "dim3")]
+    pub fn set_pinion_axis(&mut self, axis: UnitVector<Real>) -> &mut Self {
+        self.data.set_local_axis1(axis);
+        self
+    }
+
+    /// The translation axis of the rack, expressed in the local-space of the second rigid-body.
+    #[must_use]
+    pub fn rack_axis(&self) -> UnitVector<Real> {
+        self.data.local_axis2()
+    }
+
+    /// Sets the translation axis of the rack, expressed in the local-space of the second rigid-body.
+    pub fn set_rack_axis(&mut self, axis: UnitVector<Real>) -> &mut Self {
+        self.data.set_local_axis2(axis);
+        self
+    }
+
+    /// The ratio between the pinion’s angular velocity and the rack’s linear velocity.
+    #[must_use]
+    pub fn ratio(&self) -> Real {
+        self.data.coupling.map(|c| c.ratio).unwrap_or(0.0)
+    }
+
+    /// Sets the ratio between the pinion’s angular velocity and the rack’s linear velocity.
+    pub fn set_ratio(&mut self, ratio: Real) -> &mut Self {
+        if let Some(coupling) = &mut self.data.coupling {
+            coupling.ratio = ratio;
+        }
+        self
+    }
+
+    /// Are contacts between the colliders attached to the two rigid-bodies linked by this
+    /// joint enabled?
+    #[must_use]
+    pub fn contacts_enabled(&self) -> bool {
+        self.data.contacts_enabled()
+    }
+
+    /// Enables or disables contacts between the colliders attached to the two rigid-bodies
+    /// linked by this joint.
+    pub fn set_contacts_enabled(&mut self, enabled: bool) -> &mut Self {
+        self.data.set_contacts_enabled(enabled);
+        self
+    }
+}
+
+impl Into<GenericJoint> for RackAndPinionJoint {
+    fn into(self) -> GenericJoint {
+        self.data
+    }
+}
+
+/// Create rack-and-pinion joints using the builder pattern.
+///
+/// A rack-and-pinion joint couples the rotation of a pinion to the translation of a rack.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RackAndPinionJointBuilder(RackAndPinionJoint);
+
+impl RackAndPinionJointBuilder {
+    /// Creates a new builder for rack-and-pinion joints.
+    ///
+    /// The rack axis is expressed in the local-space of the second rigid-body.
+    pub fn new(rack_axis: UnitVector<Real>, ratio: Real) -> Self {
+        Self(RackAndPinionJoint::new(rack_axis, ratio))
+    }
+
+    /// Sets the joint’s anchor, expressed in the local-space of the first rigid-body.
+    #[must_use]
+    pub fn local_anchor1(mut self, anchor1: Point<Real>) -> Self {
+        self.0.set_local_anchor1(anchor1);
+        self
+    }
+
+    /// Sets the joint’s anchor, expressed in the local-space of the second rigid-body.
+    #[must_use]
+    pub fn local_anchor2(mut self, anchor2: Point<Real>) -> Self {
+        self.0.set_local_anchor2(anchor2);
+        self
+    }
+
+    /// Sets the rotation axis of the pinion, expressed in the local-space of the first rigid-body.
+    #[cfg(feature = "dim3")]
+    #[must_use]
+    pub fn pinion_axis(mut self, axis: UnitVector<Real>) -> Self {
+        self.0.set_pinion_axis(axis);
+        self
+    }
+
+    /// Sets the translation axis of the rack, expressed in the local-space of the second rigid-body.
+    #[must_use]
+    pub fn rack_axis(mut self, axis: UnitVector<Real>) -> Self {
+        self.0.set_rack_axis(axis);
+        self
+    }
+
+    /// Sets the ratio between the pinion’s angular velocity and the rack’s linear velocity.
+    #[must_use]
+    pub fn ratio(mut self, ratio: Real) -> Self {
+        self.0.set_ratio(ratio);
+        self
+    }
+
+    /// Enables or disables contacts between the colliders attached to the two rigid-bodies
+    /// linked by this joint.
+    #[must_use]
+    pub fn contacts_enabled(mut self, enabled: bool) -> Self {
+        self.0.set_contacts_enabled(enabled);
+        self
+    }
+
+    /// Builds the rack-and-pinion joint.
+    #[must_use]
+    pub fn build(self) -> RackAndPinionJoint {
+        self.0
+    }
+}
+
+impl Into<GenericJoint> for RackAndPinionJointBuilder {
+    fn into(self) -> GenericJoint {
+        self.0.into()
+    }
+}
@@ -0,0 +1,208 @@
+use crate::dynamics::joint::{GenericJoint, GenericJointBuilder, JointAxesMask, JointSoftness};
+use crate::dynamics::JointAxis;
+use crate::math::{Point, Real};
+
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(transparent)]
+/// A rope joint, limits the maximum distance between two anchors attached to two bodies.
+///
+/// The rope is slack (and applies no force) while the anchors are closer than its maximum
+/// distance. The relative rotation of both bodies is left free.
+///
+/// This joint relies on coupled axes, and is only supported as an impulse joint.
+pub struct RopeJoint {
+    data: GenericJoint,
+}
+
+impl RopeJoint {
+    /// Creates a new rope joint limiting the distance between both anchors to `max_dist`.
+    pub fn new(max_dist: Real) -> Self {
+        let data = GenericJointBuilder::new(JointAxesMask::empty())
+            .coupled_axes(JointAxesMask::LIN_AXES)
+            .limits(JointAxis::X, [0.0, max_dist])
+            .build();
+        Self { data }
+    }
+
+    /// The underlying generic joint.
+    pub fn data(&self) -> &GenericJoint {
+        &self.data
+    }
+
+    /// The joint’s anchor, expressed in the local-space of the first rigid-body.
+    #[must_use]
+    pub fn local_anchor1(&self) -> Point<Real> {
+        self.data.local_anchor1()
+    }
+
+    /// Sets the joint’s anchor, expressed in the local-space of the first rigid-body.
+    pub fn set_local_anchor1(&mut self, anchor1: Point<Real>) -> &mut Self {
+        self.data.set_local_anchor1(anchor1);
+        self
+    }
+
+    /// The joint’s anchor, expressed in the local-space of the second rigid-body.
+    #[must_use]
+    pub fn local_anchor2(&self) -> Point<Real> {
+        self.data.local_anchor2()
+    }
+
+    /// Sets the joint’s anchor, expressed in the local-space of the second rigid-body.
+    pub fn set_local_anchor2(&mut self, anchor2: Point<Real>) -> &mut Self {
+        self.data.set_local_anchor2(anchor2);
+        self
+    }
+
+    /// The maximum distance allowed between both anchors.
+    #[must_use]
+    pub fn max_distance(&self) -> Real {
+        self.data.limits[JointAxis::X as usize].max
+    }
+
+    /// Sets the maximum distance allowed between both anchors.
+    pub fn set_max_distance(&mut self, max_dist: Real) -> &mut Self {
+        self.data.set_limits(JointAxis::X, [0.0, max_dist]);
+        self
+    }
+
+    /// The maximum linear force this joint can apply before breaking.
+    #[must_use]
+    pub fn max_force(&self) -> Real {
+        self.data.max_force
+    }
+
+    /// Sets the maximum linear force this joint can apply before breaking.
+    pub fn set_max_force(&mut self, max_force: Real) -> &mut Self {
+        self.data.set_max_force(max_force);
+        self
+    }
+
+    /// The softness of this joint’s constraints, if it overrides the global one.
+    #[must_use]
+    pub fn softness(&self) -> Option<JointSoftness> {
+        self.data.softness
+    }
+
+    /// Sets the softness of this joint’s constraints.
+    ///
+    /// Set to `None` to use the global `IntegrationParameters::joint_erp` and
+    /// `IntegrationParameters::joint_damping_ratio`.
+    pub fn set_softness(&mut self, softness: Option<JointSoftness>) -> &mut Self {
+        self.data.set_softness(softness);
+        self
+    }
+
+    /// Are contacts between the colliders attached to the two rigid-bodies linked by this
+    /// joint enabled?
+    #[must_use]
+    pub fn contacts_enabled(&self) -> bool {
+        self.data.contacts_enabled()
+    }
+
+    /// Enables or disables contacts between the colliders attached to the two rigid-bodies
+    /// linked by this joint.
+    pub fn set_contacts_enabled(&mut self, enabled: bool) -> &mut Self {
+        self.data.set_contacts_enabled(enabled);
+        self
+    }
+}
+
+impl Into<GenericJoint> for RopeJoint {
+    fn into(self) -> GenericJoint {
+        self.data
+    }
+}
+
+/// Create rope joints using the builder pattern.
+///
+/// A rope joint limits the maximum distance between two anchors attached to two bodies.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RopeJointBuilder(RopeJoint);
+
+impl RopeJointBuilder {
+    /// Creates a new builder for rope joints limiting the distance between both anchors to `max_dist`.
+    pub fn new(max_dist: Real) -> Self {
+        Self(RopeJoint::new(max_dist))
+    }
+
+    /// Sets the joint’s anchor, expressed in the local-space of the first rigid-body.
+    #[must_use]
+    pub fn local_anchor1(mut self, anchor1: Point<Real>) -> Self {
+        self.0.set_local_anchor1(anchor1);
+        self
+    }
+
+    /// Sets the joint’s anchor, expressed in the local-space of the second rigid-body.
+    #[must_use]
+    pub fn local_anchor2(mut self, anchor2: Point<Real>) -> Self {
+        self.0.set_local_anchor2(anchor2);
+        self
+    }
+
+    /// Sets the maximum distance allowed between both anchors.
+    #[must_use]
+    pub fn max_distance(mut self, max_dist: Real) -> Self {
+        self.0.set_max_distance(max_dist);
+        self
+    }
+
+    /// Sets the maximum linear force this joint can apply before breaking.
+    #[must_use]
+    pub fn max_force(mut self, max_force: Real) -> Self {
+        self.0.set_max_force(max_force);
+        self
+    }
+
+    /// Sets the softness of this joint’s constraints.
+    #[must_use]
+    pub fn softness(mut self, softness: JointSoftness) -> Self {
+        self.0.set_softness(Some(softness));
+        self
+    }
+
+    /// Enables or disables contacts between the colliders attached to the two rigid-bodies
+    /// linked by this joint.
+    #[must_use]
+    pub fn contacts_enabled(mut self, enabled: bool) -> Self {
+        self.0.set_contacts_enabled(enabled);
+        self
+    }
+
+    /// Builds the rope joint.
+    #[must_use]
+    pub fn build(self) -> RopeJoint {
+        self.0
+    }
+}
+
+impl Into<GenericJoint> for RopeJointBuilder {
+    fn into(self) -> GenericJoint {
+        self.0.into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::RopeJointBuilder;
+    use crate::dynamics::RigidBodyBuilder;
+    use crate::math::Vector;
+    use crate::test_utils::TestWorld;
+
+    #[test]
+    fn rope_joint_max_distance() {
+        let mut world = TestWorld::new();
+
+        // A ball released close to its anchor, falling until the rope becomes taut.
+        let fixed = world.bodies.insert(RigidBodyBuilder::fixed().build());
+        let ball = world.add_ball(Vector::y() * -1.0, 0.5);
+        world
+            .impulse_joints
+            .insert(fixed, ball, RopeJointBuilder::new(2.0));
+
+        world.step_n(120);
+
+        let dist = world.bodies[ball].translation().norm();
+        assert!(dist > 1.9 && dist < 2.05);
+    }
+}
@@ -1,6 +1,6 @@
-use crate::dynamics::joint::{GenericJoint, GenericJointBuilder, JointAxesMask};
-use crate::dynamics::{JointAxis, JointMotor, MotorModel};
-use crate::math::{Point, Real};
+use crate::dynamics::joint::{GenericJoint, GenericJointBuilder, JointAxesMask, JointSoftness};
+use crate::dynamics::{ActuatorModel, JointAxis, JointFriction, JointMotor, MotorModel};
+use crate::math::{Isometry, Point, Real};
 
 use super::JointLimits;
 
@@ -54,6 +54,34 @@ impl SphericalJoint {
         self
     }
 
+    /// The joint’s frame, expressed in the local-space of the first rigid-body.
+    ///
+    /// The X axis of this frame is the twist axis of the joint’s cone-twist limit.
+    #[must_use]
+    pub fn local_frame1(&self) -> &Isometry<Real> {
+        &self.data.local_frame1
+    }
+
+    /// Sets the joint’s frame, expressed in the local-space of the first rigid-body.
+    pub fn set_local_frame1(&mut self, local_frame: Isometry<Real>) -> &mut Self {
+        self.data.set_local_frame1(local_frame);
+        self
+    }
+
+    /// The joint’s frame, expressed in the local-space of the second rigid-body.
+    ///
+    /// The X axis of this frame is the twist axis of the joint’s cone-twist limit.
+    #[must_use]
+    pub fn local_frame2(&self) -> &Isometry<Real> {
+        &self.data.local_frame2
+    }
+
+    /// Sets the joint’s frame, expressed in the local-space of the second rigid-body.
+    pub fn set_local_frame2(&mut self, local_frame: Isometry<Real>) -> &mut Self {
+        self.data.set_local_frame2(local_frame);
+        self
+    }
+
     /// The motor affecting the joint’s rotational degree of freedom along the specified axis.
     #[must_use]
     pub fn motor(&self, axis: JointAxis) -> Option<&JointMotor> {
@@ -110,6 +138,30 @@ impl SphericalJoint {
         self
     }
 
+    /// Sets the model of the actuator driving the motor along the specified axis.
+    pub fn set_motor_actuator(&mut self, axis: JointAxis, actuator: ActuatorModel) -> &mut Self {
+        self.data.set_motor_actuator(axis, actuator);
+        self
+    }
+
+    /// The dry friction and viscous damping along the specified axis.
+    #[must_use]
+    pub fn friction(&self, axis: JointAxis) -> &JointFriction {
+        self.data.friction(axis)
+    }
+
+    /// Sets the maximum torque the dry friction can apply along the specified axis.
+    pub fn set_friction(&mut self, axis: JointAxis, max_force: Real) -> &mut Self {
+        self.data.set_friction(axis, max_force);
+        self
+    }
+
+    /// Sets the viscous damping coefficient along the specified axis.
+    pub fn set_damping(&mut self, axis: JointAxis, damping: Real) -> &mut Self {
+        self.data.set_damping(axis, damping);
+        self
+    }
+
     /// The limit distance attached bodies can translate along the specified axis.
     #[must_use]
     pub fn limits(&self, axis: JointAxis) -> Option<&JointLimits<Real>> {
@@ -121,6 +173,110 @@ impl SphericalJoint {
         self.data.set_limits(axis, limits);
         self
     }
+
+    /// The maximum swing angles around the local Y and Z axes of the joint, if it has a cone-twist limit.
+    #[must_use]
+    pub fn swing_limits(&self) -> Option<[Real; 2]> {
+        let swing_axes = JointAxesMask::ANG_Y | JointAxesMask::ANG_Z;
+        if (self.data.limit_axes & self.data.coupled_axes).contains(swing_axes) {
+            Some([
+                self.data.limits[JointAxis::AngY as usize].max,
+                self.data.limits[JointAxis::AngZ as usize].max,
+            ])
+        } else {
+            None
+        }
+    }
+
+    /// Sets the maximum swing angles around the local Y and Z axes of the joint.
+    ///
+    /// This limits the joint’s local X axis (the twist axis) to an elliptical cone instead of
+    /// limiting each angular axis independently. The twist around the local X axis can be limited
+    /// with [`SphericalJoint::set_twist_limits`].
+    pub fn set_swing_limits(&mut self, max_swing_y: Real, max_swing_z: Real) -> &mut Self {
+        self.data.coupled_axes |= JointAxesMask::ANG_Y | JointAxesMask::ANG_Z;
+        self.data.set_limits(JointAxis::AngY, [0.0, max_swing_y]);
+        self.data.set_limits(JointAxis::AngZ, [0.0, max_swing_z]);
+        self
+    }
+
+    /// The twist limits around the local X axis of the joint.
+    #[must_use]
+    pub fn twist_limits(&self) -> Option<&JointLimits<Real>> {
+        self.data.limits(JointAxis::AngX)
+    }
+
+    /// Sets the `[min,max]` twist angles around the local X axis of the joint.
+    pub fn set_twist_limits(&mut self, limits: [Real; 2]) -> &mut Self {
+        self.data.set_limits(JointAxis::AngX, limits);
+        self
+    }
+
+    /// Sets both the swing and twist limits of the joint.
+    ///
+    /// See [`SphericalJoint::set_swing_limits`] and [`SphericalJoint::set_twist_limits`].
+    pub fn set_cone_twist_limits(
+        &mut self,
+        max_swing_y: Real,
+        max_swing_z: Real,
+        twist_limits: [Real; 2],
+    ) -> &mut Self {
+        self.set_swing_limits(max_swing_y, max_swing_z)
+            .set_twist_limits(twist_limits)
+    }
+
+    /// The maximum linear force this joint can apply before breaking.
+    #[must_use]
+    pub fn max_force(&self) -> Real {
+        self.data.max_force
+    }
+
+    /// Sets the maximum linear force this joint can apply before breaking.
+    pub fn set_max_force(&mut self, max_force: Real) -> &mut Self {
+        self.data.set_max_force(max_force);
+        self
+    }
+
+    /// The maximum torque this joint can apply before breaking.
+    #[must_use]
+    pub fn max_torque(&self) -> Real {
+        self.data.max_torque
+    }
+
+    /// Sets the maximum torque this joint can apply before breaking.
+    pub fn set_max_torque(&mut self, max_torque: Real) -> &mut Self {
+        self.data.set_max_torque(max_torque);
+        self
+    }
+
+    /// The softness of this joint’s constraints, if it overrides the global one.
+    #[must_use]
+    pub fn softness(&self) -> Option<JointSoftness> {
+        self.data.softness
+    }
+
+    /// Sets the softness of this joint’s constraints.
+    ///
+    /// Set to `None` to use the global `IntegrationParameters::joint_erp` and
+    /// `IntegrationParameters::joint_damping_ratio`.
+    pub fn set_softness(&mut self, softness: Option<JointSoftness>) -> &mut Self {
+        self.data.set_softness(softness);
+        self
+    }
+
+    /// Are contacts between the colliders attached to the two rigid-bodies linked by this
+    /// joint enabled?
+    #[must_use]
+    pub fn contacts_enabled(&self) -> bool {
+        self.data.contacts_enabled()
+    }
+
+    /// Enables or disables contacts between the colliders attached to the two rigid-bodies
+    /// linked by this joint.
+    pub fn set_contacts_enabled(&mut self, enabled: bool) -> &mut Self {
+        self.data.set_contacts_enabled(enabled);
+        self
+    }
 }
 
 impl Into<GenericJoint> for SphericalJoint {
@@ -160,6 +316,20 @@ impl SphericalJointBuilder {
         self
     }
 
+    /// Sets the joint’s frame, expressed in the local-space of the first rigid-body.
+    #[must_use]
+    pub fn local_frame1(mut self, local_frame: Isometry<Real>) -> Self {
+        self.0.set_local_frame1(local_frame);
+        self
+    }
+
+    /// Sets the joint’s frame, expressed in the local-space of the second rigid-body.
+    #[must_use]
+    pub fn local_frame2(mut self, local_frame: Isometry<Real>) -> Self {
+        self.0.set_local_frame2(local_frame);
+        self
+    }
+
     /// Set the spring-like model used by the motor to reach the desired target velocity and position.
     #[must_use]
     pub fn motor_model(mut self, axis: JointAxis, model: MotorModel) -> Self {
@@ -210,6 +380,27 @@ impl SphericalJointBuilder {
         self
     }
 
+    /// Sets the model of the actuator driving the motor along the specified axis.
+    #[must_use]
+    pub fn motor_actuator(mut self, axis: JointAxis, actuator: ActuatorModel) -> Self {
+        self.0.set_motor_actuator(axis, actuator);
+        self
+    }
+
+    /// Sets the maximum torque the dry friction can apply along the specified axis.
+    #[must_use]
+    pub fn friction(mut self, axis: JointAxis, max_force: Real) -> Self {
+        self.0.set_friction(axis, max_force);
+        self
+    }
+
+    /// Sets the viscous damping coefficient along the specified axis.
+    #[must_use]
+    pub fn damping(mut self, axis: JointAxis, damping: Real) -> Self {
+        self.0.set_damping(axis, damping);
+        self
+    }
+
     /// Sets the `[min,max]` limit distances attached bodies can rotate along the specified axis.
     #[must_use]
     pub fn limits(mut self, axis: JointAxis, limits: [Real; 2]) -> Self {
@@ -217,6 +408,51 @@ impl SphericalJointBuilder {
         self
     }
 
+    /// Sets the maximum swing angles around the local Y and Z axes of the joint.
+    ///
+    /// See [`SphericalJoint::set_swing_limits`].
+    #[must_use]
+    pub fn swing_limits(mut self, max_swing_y: Real, max_swing_z: Real) -> Self {
+        self.0.set_swing_limits(max_swing_y, max_swing_z);
+        self
+    }
+
+    /// Sets the `[min,max]` twist angles around the local X axis of the joint.
+    #[must_use]
+    pub fn twist_limits(mut self, limits: [Real; 2]) -> Self {
+        self.0.set_twist_limits(limits);
+        self
+    }
+
+    /// Sets the maximum linear force this joint can apply before breaking.
+    #[must_use]
+    pub fn max_force(mut self, max_force: Real) -> Self {
+        self.0.set_max_force(max_force);
+        self
+    }
+
+    /// Sets the maximum torque this joint can apply before breaking.
+    #[must_use]
+    pub fn max_torque(mut self, max_torque: Real) -> Self {
+        self.0.set_max_torque(max_torque);
+        self
+    }
+
+    /// Sets the softness of this joint’s constraints.
+    #[must_use]
+    pub fn softness(mut self, softness: JointSoftness) -> Self {
+        self.0.set_softness(Some(softness));
+        self
+    }
+
+    /// Enables or disables contacts between the colliders attached to the two rigid-bodies
+    /// linked by this joint.
+    #[must_use]
+    pub fn contacts_enabled(mut self, enabled: bool) -> Self {
+        self.0.set_contacts_enabled(enabled);
+        self
+    }
+
     /// Builds the spherical joint.
     #[must_use]
     pub fn build(self) -> SphericalJoint {
@@ -229,3 +465,88 @@ impl Into<GenericJoint> for SphericalJointBuilder {
         self.0.into()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::dynamics::{GenericJoint, GenericJointBuilder, JointAxesMask, JointAxis};
+    use crate::dynamics::{RigidBodyBuilder, RigidBodyHandle, SphericalJointBuilder};
+    use crate::math::{Point, Real, Vector};
+    use crate::test_utils::TestWorld;
+
+    const MAX_SWING: Real = 0.5;
+
+    /// A ball hanging from a fixed body along the `X` axis, and pulled down by gravity so
+    /// that it swings around the `Z` axis.
+    fn swinging_ball(
+        joint: impl Into<GenericJoint>,
+        multibody: bool,
+    ) -> (TestWorld, RigidBodyHandle) {
+        let mut world = TestWorld::new();
+        let root = world.bodies.insert(RigidBodyBuilder::fixed().build());
+        let ball = world.add_ball(Vector::x(), 0.1);
+        let joint = joint.into();
+
+        if multibody {
+            world.multibody_joints.insert(root, ball, joint).unwrap();
+        } else {
+            world.impulse_joints.insert(root, ball, joint);
+        }
+
+        (world, ball)
+    }
+
+    /// Steps the world and returns the largest swing angle of the ball.
+    fn max_swing_angle(world: &mut TestWorld, ball: RigidBodyHandle) -> Real {
+        (0..200)
+            .map(|_| {
+                world.step();
+                let twist_axis = world.bodies[ball].rotation() * Vector::x();
+                twist_axis.angle(&Vector::x())
+            })
+            .fold(0.0, Real::max)
+    }
+
+    fn cone_joint() -> SphericalJointBuilder {
+        SphericalJointBuilder::new()
+            .local_anchor2(Point::from(-Vector::x()))
+            .swing_limits(MAX_SWING, MAX_SWING)
+    }
+
+    #[test]
+    fn swing_limit_clamps_body_to_cone() {
+        for multibody in [false, true] {
+            let (mut world, ball) = swinging_ball(cone_joint(), multibody);
+            let angle = max_swing_angle(&mut world, ball);
+            assert!(
+                angle < MAX_SWING + 0.05,
+                "multibody: {}, swing angle: {}",
+                multibody,
+                angle
+            );
+        }
+
+        // Without the limit, the ball swings down well past the cone.
+        let free_joint = SphericalJointBuilder::new().local_anchor2(Point::from(-Vector::x()));
+        let (mut world, ball) = swinging_ball(free_joint, false);
+        assert!(max_swing_angle(&mut world, ball) > 1.0);
+    }
+
+    #[test]
+    fn swing_limit_with_a_single_coupled_axis() {
+        let joint = GenericJointBuilder::new(JointAxesMask::LIN_AXES)
+            .local_anchor2(Point::from(-Vector::x()))
+            .coupled_axes(JointAxesMask::ANG_Z)
+            .limits(JointAxis::AngZ, [0.0, MAX_SWING]);
+
+        for multibody in [false, true] {
+            let (mut world, ball) = swinging_ball(joint, multibody);
+            let angle = max_swing_angle(&mut world, ball);
+            assert!(
+                angle < MAX_SWING + 0.05,
+                "multibody: {}, swing angle: {}",
+                multibody,
+                angle
+            );
+        }
+    }
+}
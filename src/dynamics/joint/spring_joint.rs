@@ -0,0 +1,276 @@
+use crate::dynamics::joint::{GenericJoint, GenericJointBuilder, JointAxesMask, JointSoftness};
+use crate::dynamics::{JointAxis, MotorModel};
+use crate::math::{Point, Real};
+
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(transparent)]
+/// A spring joint, pulls or pushes two anchors attached to two bodies towards a rest length.
+///
+/// The spring applies a force proportional to the difference between the distance separating
+/// both anchors and its rest length, plus a damping force proportional to their relative
+/// velocity along the spring. The relative rotation of both bodies is left free.
+///
+/// This joint relies on coupled axes, and is only supported as an impulse joint.
+pub struct SpringJoint {
+    data: GenericJoint,
+}
+
+impl SpringJoint {
+    /// Creates a new spring joint with the given rest length, stiffness and damping.
+    pub fn new(rest_length: Real, stiffness: Real, damping: Real) -> Self {
+        let data = GenericJointBuilder::new(JointAxesMask::empty())
+            .coupled_axes(JointAxesMask::LIN_AXES)
+            .motor_position(JointAxis::X, rest_length, stiffness, damping)
+            .motor_model(JointAxis::X, MotorModel::ForceBased)
+            .build();
+        Self { data }
+    }
+
+    /// The underlying generic joint.
+    pub fn data(&self) -> &GenericJoint {
+        &self.data
+    }
+
+    /// The joint’s anchor, expressed in the local-space of the first rigid-body.
+    #[must_use]
+    pub fn local_anchor1(&self) -> Point<Real> {
+        self.data.local_anchor1()
+    }
+
+    /// Sets the joint’s anchor, expressed in the local-space of the first rigid-body.
+    pub fn set_local_anchor1(&mut self, anchor1: Point<Real>) -> &mut Self {
+        self.data.set_local_anchor1(anchor1);
+        self
+    }
+
+    /// The joint’s anchor, expressed in the local-space of the second rigid-body.
+    #[must_use]
+    pub fn local_anchor2(&self) -> Point<Real> {
+        self.data.local_anchor2()
+    }
+
+    /// Sets the joint’s anchor, expressed in the local-space of the second rigid-body.
+    pub fn set_local_anchor2(&mut self, anchor2: Point<Real>) -> &mut Self {
+        self.data.set_local_anchor2(anchor2);
+        self
+    }
+
+    /// The distance between both anchors at which the spring applies no force.
+    #[must_use]
+    pub fn rest_length(&self) -> Real {
+        self.data.motors[JointAxis::X as usize].target_pos
+    }
+
+    /// Sets the distance between both anchors at which the spring applies no force.
+    pub fn set_rest_length(&mut self, rest_length: Real) -> &mut Self {
+        self.data.motors[JointAxis::X as usize].target_pos = rest_length;
+        self
+    }
+
+    /// The stiffness of the spring.
+    #[must_use]
+    pub fn stiffness(&self) -> Real {
+        self.data.motors[JointAxis::X as usize].stiffness
+    }
+
+    /// Sets the stiffness of the spring.
+    pub fn set_stiffness(&mut self, stiffness: Real) -> &mut Self {
+        self.data.motors[JointAxis::X as usize].stiffness = stiffness;
+        self
+    }
+
+    /// The damping coefficient of the spring.
+    #[must_use]
+    pub fn damping(&self) -> Real {
+        self.data.motors[JointAxis::X as usize].damping
+    }
+
+    /// Sets the damping coefficient of the spring.
+    pub fn set_damping(&mut self, damping: Real) -> &mut Self {
+        self.data.motors[JointAxis::X as usize].damping = damping;
+        self
+    }
+
+    /// The spring-like model used to simulate this spring.
+    #[must_use]
+    pub fn spring_model(&self) -> MotorModel {
+        self.data.motors[JointAxis::X as usize].model
+    }
+
+    /// Sets the spring-like model used to simulate this spring.
+    ///
+    /// Defaults to [`MotorModel::ForceBased`].
+    pub fn set_spring_model(&mut self, model: MotorModel) -> &mut Self {
+        self.data.set_motor_model(JointAxis::X, model);
+        self
+    }
+
+    /// The maximum linear force this joint can apply before breaking.
+    #[must_use]
+    pub fn max_force(&self) -> Real {
+        self.data.max_force
+    }
+
+    /// Sets the maximum linear force this joint can apply before breaking.
+    pub fn set_max_force(&mut self, max_force: Real) -> &mut Self {
+        self.data.set_max_force(max_force);
+        self
+    }
+
+    /// The softness of this joint’s constraints, if it overrides the global one.
+    #[must_use]
+    pub fn softness(&self) -> Option<JointSoftness> {
+        self.data.softness
+    }
+
+    /// Sets the softness of this joint’s constraints.
+    ///
+    /// Set to `None` to use the global `IntegrationParameters::joint_erp` and
+    /// `IntegrationParameters::joint_damping_ratio`.
+    pub fn set_softness(&mut self, softness: Option<JointSoftness>) -> &mut Self {
+        self.data.set_softness(softness);
+        self
+    }
+
+    /// Are contacts between the colliders attached to the two rigid-bodies linked by this
+    /// joint enabled?
+    #[must_use]
+    pub fn contacts_enabled(&self) -> bool {
+        self.data.contacts_enabled()
+    }
+
+    /// Enables or disables contacts between the colliders attached to the two rigid-bodies
+    /// linked by this joint.
+    pub fn set_contacts_enabled(&mut self, enabled: bool) -> &mut Self {
+        self.data.set_contacts_enabled(enabled);
+        self
+    }
+}
+
+impl Into<GenericJoint> for SpringJoint {
+    fn into(self) -> GenericJoint {
+        self.data
+    }
+}
+
+/// Create spring joints using the builder pattern.
+///
+/// A spring joint pulls or pushes two anchors attached to two bodies towards a rest length.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SpringJointBuilder(SpringJoint);
+
+impl SpringJointBuilder {
+    /// Creates a new builder for spring joints with the given rest length, stiffness and damping.
+    pub fn new(rest_length: Real, stiffness: Real, damping: Real) -> Self {
+        Self(SpringJoint::new(rest_length, stiffness, damping))
+    }
+
+    /// Sets the joint’s anchor, expressed in the local-space of the first rigid-body.
+    #[must_use]
+    pub fn local_anchor1(mut self, anchor1: Point<Real>) -> Self {
+        self.0.set_local_anchor1(anchor1);
+        self
+    }
+
+    /// Sets the joint’s anchor, expressed in the local-space of the second rigid-body.
+    #[must_use]
+    pub fn local_anchor2(mut self, anchor2: Point<Real>) -> Self {
+        self.0.set_local_anchor2(anchor2);
+        self
+    }
+
+    /// Sets the distance between both anchors at which the spring applies no force.
+    #[must_use]
+    pub fn rest_length(mut self, rest_length: Real) -> Self {
+        self.0.set_rest_length(rest_length);
+        self
+    }
+
+    /// Sets the stiffness of the spring.
+    #[must_use]
+    pub fn stiffness(mut self, stiffness: Real) -> Self {
+        self.0.set_stiffness(stiffness);
+        self
+    }
+
+    /// Sets the damping coefficient of the spring.
+    #[must_use]
+    pub fn damping(mut self, damping: Real) -> Self {
+        self.0.set_damping(damping);
+        self
+    }
+
+    /// Sets the spring-like model used to simulate this spring.
+    #[must_use]
+    pub fn spring_model(mut self, model: MotorModel) -> Self {
+        self.0.set_spring_model(model);
+        self
+    }
+
+    /// Sets the maximum linear force this joint can apply before breaking.
+    #[must_use]
+    pub fn max_force(mut self, max_force: Real) -> Self {
+        self.0.set_max_force(max_force);
+        self
+    }
+
+    /// Sets the softness of this joint’s constraints.
+    #[must_use]
+    pub fn softness(mut self, softness: JointSoftness) -> Self {
+        self.0.set_softness(Some(softness));
+        self
+    }
+
+    /// Enables or disables contacts between the colliders attached to the two rigid-bodies
+    /// linked by this joint.
+    #[must_use]
+    pub fn contacts_enabled(mut self, enabled: bool) -> Self {
+        self.0.set_contacts_enabled(enabled);
+        self
+    }
+
+    /// Builds the spring joint.
+    #[must_use]
+    pub fn build(self) -> SpringJoint {
+        self.0
+    }
+}
+
+impl Into<GenericJoint> for SpringJointBuilder {
+    fn into(self) -> GenericJoint {
+        self.0.into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SpringJointBuilder;
+    use crate::dynamics::RigidBodyBuilder;
+    use crate::math::Vector;
+    use crate::test_utils::TestWorld;
+
+    #[test]
+    fn damped_spring_settles_at_rest_length() {
+        let mut world = TestWorld::new();
+        let (rest_length, stiffness) = (2.0, 50.0);
+
+        // A ball released right below its anchor, bouncing until the spring damps its motion.
+        let fixed = world.bodies.insert(RigidBodyBuilder::fixed().build());
+        let ball = world.add_ball(Vector::y() * -1.0, 0.5);
+        world.impulse_joints.insert(
+            fixed,
+            ball,
+            SpringJointBuilder::new(rest_length, stiffness, 10.0),
+        );
+
+        world.step_n(600);
+
+        // The spring is stretched by the weight of the ball.
+        let weight = world.bodies[ball].mass() * 9.81;
+        let expected = rest_length + weight / stiffness;
+        let dist = world.bodies[ball].translation().norm();
+        assert!(world.bodies[ball].linvel().norm() < 1.0e-2);
+        assert!((dist - expected).abs() < 1.0e-2, "{} != {}", dist, expected);
+    }
+}
@@ -894,6 +894,9 @@ pub struct RigidBodyBuilder {
     pub can_sleep: bool,
     /// Whether or not the rigid-body is to be created asleep.
     pub sleeping: bool,
+    /// Whether or not the rigid-body to be created emits a `BodyActivationEvent` whenever it falls
+    /// asleep or wakes up.
+    pub activation_events_enabled: bool,
     /// Whether continuous collision-detection is enabled for the rigid-body to be built.
     ///
     /// CCD prevents tunneling, but may still allow limited interpenetration of colliders.
@@ -919,6 +922,7 @@ impl RigidBodyBuilder {
             mass_properties: MassProperties::zero(),
             can_sleep: true,
             sleeping: false,
+            activation_events_enabled: false,
             ccd_enabled: false,
             dominance_group: 0,
             user_data: 0,
@@ -1188,6 +1192,13 @@ impl RigidBodyBuilder {
         self
     }
 
+    /// Sets whether or not the rigid-body to be created emits a `BodyActivationEvent` whenever
+    /// it falls asleep or wakes up.
+    pub fn activation_events_enabled(mut self, enabled: bool) -> Self {
+        self.activation_events_enabled = enabled;
+        self
+    }
+
     /// Build a new rigid-body with the parameters configured with this builder.
     pub fn build(&self) -> RigidBody {
         let mut rb = RigidBody::new();
@@ -1214,6 +1225,8 @@ impl RigidBodyBuilder {
             rb.rb_activation.angular_threshold = -1.0;
         }
 
+        rb.rb_activation.events_enabled = self.activation_events_enabled;
+
         rb
     }
 }
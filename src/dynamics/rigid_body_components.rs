@@ -781,13 +781,25 @@ pub struct RigidBodyIds {
     pub(crate) active_set_timestamp: u32,
 }
 
+impl RigidBodyIds {
+    /// The id of the active island this rigid-body was part of the last time it was simulated.
+    ///
+    /// This is only meaningful if the rigid-body is dynamic and awake. Use
+    /// `IslandManager::active_island_id` to check this id is up-to-date.
+    pub fn active_island_id(&self) -> usize {
+        self.active_island_id
+    }
+}
+
 impl Default for RigidBodyIds {
     fn default() -> Self {
         Self {
             active_island_id: 0,
             active_set_id: 0,
             active_set_offset: 0,
-            active_set_timestamp: 0,
+            // Never equal to the timestamp of the previous active set, so that the first
+            // simulation of this body is detected as a wake-up.
+            active_set_timestamp: u32::MAX,
         }
     }
 }
@@ -926,6 +938,8 @@ pub struct RigidBodyActivation {
     pub time_since_can_sleep: Real,
     /// Is this body sleeping?
     pub sleeping: bool,
+    /// Does this body emit a `BodyActivationEvent` whenever it falls asleep or wakes up?
+    pub events_enabled: bool,
 }
 
 impl Default for RigidBodyActivation {
@@ -958,6 +972,7 @@ impl RigidBodyActivation {
             angular_threshold: Self::default_angular_threshold(),
             time_since_can_sleep: 0.0,
             sleeping: false,
+            events_enabled: false,
         }
     }
 
@@ -968,6 +983,7 @@ impl RigidBodyActivation {
             angular_threshold: Self::default_angular_threshold(),
             sleeping: true,
             time_since_can_sleep: Self::default_time_until_sleep(),
+            events_enabled: false,
         }
     }
 
@@ -1,4 +1,7 @@
 use crate::data::{BundleSet, ComponentSet};
+use crate::dynamics::solver::joint_constraint::joint_coupling_velocity_constraint::{
+    JointCouplingVelocityConstraint, JointCouplingVelocityGroundConstraint,
+};
 use crate::dynamics::solver::joint_constraint::joint_generic_velocity_constraint::{
     JointGenericVelocityConstraint, JointGenericVelocityGroundConstraint,
 };
@@ -25,6 +28,8 @@ pub enum AnyJointVelocityConstraint {
     JointGroundConstraint(JointVelocityGroundConstraint<Real, 1>),
     JointGenericConstraint(JointGenericVelocityConstraint),
     JointGenericGroundConstraint(JointGenericVelocityGroundConstraint),
+    JointCouplingConstraint(JointCouplingVelocityConstraint),
+    JointCouplingGroundConstraint(JointCouplingVelocityGroundConstraint),
     #[cfg(feature = "simd-is-enabled")]
     JointConstraintSimd(JointVelocityConstraint<SimdReal, SIMD_WIDTH>),
     #[cfg(feature = "simd-is-enabled")]
@@ -47,7 +52,9 @@ impl AnyJointVelocityConstraint {
             + locked_axes.count_ones() as usize
             + (limit_axes & !coupled_axes).count_ones() as usize
             + ((limit_axes & coupled_axes) & JointAxesMask::ANG_AXES.bits() != 0) as usize
-            + ((limit_axes & coupled_axes) & JointAxesMask::LIN_AXES.bits() != 0) as usize;
+            + ((limit_axes & coupled_axes) & JointAxesMask::LIN_AXES.bits() != 0) as usize
+            + joint.friction_axes().count_ones() as usize
+            + joint.coupling.is_some() as usize;
         (num_constraints, num_constraints)
     }
 
@@ -132,7 +139,7 @@ impl AnyJointVelocityConstraint {
             }
 
             // TODO: find a way to avoid the temporary buffer.
-            let mut out_tmp = [JointGenericVelocityConstraint::invalid(); 12];
+            let mut out_tmp = [JointGenericVelocityConstraint::invalid(); 18];
             let out_tmp_len = JointGenericVelocityConstraint::lock_axes(
                 params,
                 joint_id,
@@ -159,7 +166,7 @@ impl AnyJointVelocityConstraint {
             }
         } else {
             // TODO: find a way to avoid the temporary buffer.
-            let mut out_tmp = [JointVelocityConstraint::invalid(); 12];
+            let mut out_tmp = [JointVelocityConstraint::invalid(); 18];
             let out_tmp_len = JointVelocityConstraint::<Real, 1>::lock_axes(
                 params,
                 joint_id,
@@ -180,6 +187,20 @@ impl AnyJointVelocityConstraint {
                     out.push(AnyJointVelocityConstraint::JointConstraint(c));
                 }
             }
+
+            if let Some(coupling) = &joint.data.coupling {
+                let c = AnyJointVelocityConstraint::JointCouplingConstraint(
+                    JointCouplingVelocityConstraint::new(
+                        params, joint_id, coupling, &body1, &body2, &frame1, &frame2,
+                    ),
+                );
+
+                if let Some(at) = insert_at {
+                    out[at + out_tmp_len] = c;
+                } else {
+                    out.push(c);
+                }
+            }
         }
     }
 
@@ -251,7 +272,7 @@ impl AnyJointVelocityConstraint {
         };
 
         // TODO: find a way to avoid the temporary buffer.
-        let mut out_tmp = [JointVelocityConstraint::invalid(); 12];
+        let mut out_tmp = [JointVelocityConstraint::invalid(); 18];
         let out_tmp_len = JointVelocityConstraint::<SimdReal, SIMD_WIDTH>::lock_axes(
             params,
             joint_id,
@@ -357,7 +378,7 @@ impl AnyJointVelocityConstraint {
             }
 
             // TODO: find a way to avoid the temporary buffer.
-            let mut out_tmp = [JointGenericVelocityGroundConstraint::invalid(); 12];
+            let mut out_tmp = [JointGenericVelocityGroundConstraint::invalid(); 18];
             let out_tmp_len = JointGenericVelocityGroundConstraint::lock_axes(
                 params,
                 joint_id,
@@ -383,7 +404,7 @@ impl AnyJointVelocityConstraint {
             }
         } else {
             // TODO: find a way to avoid the temporary buffer.
-            let mut out_tmp = [JointVelocityGroundConstraint::invalid(); 12];
+            let mut out_tmp = [JointVelocityGroundConstraint::invalid(); 18];
             let out_tmp_len = JointVelocityGroundConstraint::<Real, 1>::lock_axes(
                 params,
                 joint_id,
@@ -404,6 +425,27 @@ impl AnyJointVelocityConstraint {
                     out.push(AnyJointVelocityConstraint::JointGroundConstraint(c));
                 }
             }
+
+            if let Some(coupling) = &joint.data.coupling {
+                // The coupling is defined for the bodies in the order they are attached
+                // to the joint, so undo the flip.
+                let (body1, body2, frame1, frame2) = if flipped {
+                    (&body2, &body1, &frame2, &frame1)
+                } else {
+                    (&body1, &body2, &frame1, &frame2)
+                };
+                let c = AnyJointVelocityConstraint::JointCouplingGroundConstraint(
+                    JointCouplingVelocityGroundConstraint::new(
+                        params, joint_id, coupling, body1, body2, frame1, frame2, flipped,
+                    ),
+                );
+
+                if let Some(at) = insert_at {
+                    out[at + out_tmp_len] = c;
+                } else {
+                    out.push(c);
+                }
+            }
         }
     }
 
@@ -494,7 +536,7 @@ impl AnyJointVelocityConstraint {
         };
 
         // TODO: find a way to avoid the temporary buffer.
-        let mut out_tmp = [JointVelocityGroundConstraint::invalid(); 12];
+        let mut out_tmp = [JointVelocityGroundConstraint::invalid(); 18];
         let out_tmp_len = JointVelocityGroundConstraint::<SimdReal, SIMD_WIDTH>::lock_axes(
             params,
             joint_id,
@@ -517,6 +559,18 @@ impl AnyJointVelocityConstraint {
         }
     }
 
+    /// Applies the impulses carried over from the previous timestep.
+    ///
+    /// Only the coupling constraints are warm-started.
+    pub fn warmstart(&self, mj_lambdas: &mut [DeltaVel<Real>]) {
+        match self {
+            AnyJointVelocityConstraint::JointCouplingConstraint(c) => c.warmstart(mj_lambdas),
+            AnyJointVelocityConstraint::JointCouplingGroundConstraint(c) => c.warmstart(mj_lambdas),
+            AnyJointVelocityConstraint::Empty => unreachable!(),
+            _ => {}
+        }
+    }
+
     pub fn remove_bias_from_rhs(&mut self) {
         match self {
             AnyJointVelocityConstraint::JointConstraint(c) => c.remove_bias_from_rhs(),
@@ -527,6 +581,10 @@ impl AnyJointVelocityConstraint {
             AnyJointVelocityConstraint::JointGroundConstraintSimd(c) => c.remove_bias_from_rhs(),
             AnyJointVelocityConstraint::JointGenericConstraint(c) => c.remove_bias_from_rhs(),
             AnyJointVelocityConstraint::JointGenericGroundConstraint(c) => c.remove_bias_from_rhs(),
+            AnyJointVelocityConstraint::JointCouplingConstraint(c) => c.remove_bias_from_rhs(),
+            AnyJointVelocityConstraint::JointCouplingGroundConstraint(c) => {
+                c.remove_bias_from_rhs()
+            }
             AnyJointVelocityConstraint::Empty => unreachable!(),
         }
     }
@@ -550,11 +608,17 @@ impl AnyJointVelocityConstraint {
             AnyJointVelocityConstraint::JointGenericGroundConstraint(c) => {
                 c.solve(jacobians, mj_lambdas, generic_mj_lambdas)
             }
+            AnyJointVelocityConstraint::JointCouplingConstraint(c) => c.solve(mj_lambdas),
+            AnyJointVelocityConstraint::JointCouplingGroundConstraint(c) => c.solve(mj_lambdas),
             AnyJointVelocityConstraint::Empty => unreachable!(),
         }
     }
 
-    pub fn writeback_impulses(&self, joints_all: &mut [JointGraphEdge]) {
+    pub fn writeback_impulses(
+        &self,
+        joints_all: &mut [JointGraphEdge],
+        multibodies: &mut MultibodyJointSet,
+    ) {
         match self {
             AnyJointVelocityConstraint::JointConstraint(c) => c.writeback_impulses(joints_all),
             AnyJointVelocityConstraint::JointGroundConstraint(c) => {
@@ -570,6 +634,12 @@ impl AnyJointVelocityConstraint {
                 c.writeback_impulses(joints_all)
             }
             AnyJointVelocityConstraint::JointGenericGroundConstraint(c) => {
+                c.writeback_impulses(joints_all, multibodies)
+            }
+            AnyJointVelocityConstraint::JointCouplingConstraint(c) => {
+                c.writeback_impulses(joints_all)
+            }
+            AnyJointVelocityConstraint::JointCouplingGroundConstraint(c) => {
                 c.writeback_impulses(joints_all)
             }
             AnyJointVelocityConstraint::Empty => unreachable!(),
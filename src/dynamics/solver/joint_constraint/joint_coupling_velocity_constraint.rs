@@ -0,0 +1,301 @@
+use crate::dynamics::solver::joint_constraint::SolverBody;
+use crate::dynamics::solver::DeltaVel;
+use crate::dynamics::{
+    IntegrationParameters, JointCoupling, JointCouplingKind, JointGraphEdge, JointIndex,
+};
+use crate::math::{AngVector, Isometry, Real, Vector, DEFAULT_EPSILON};
+use crate::utils::{WCross, WDot};
+
+/// The jacobians and velocity error of a joint coupling, for both bodies in the
+/// order they are attached to the joint.
+struct CouplingJacobians {
+    lin_jac1: Vector<Real>,
+    ang_jac1: AngVector<Real>,
+    lin_jac2: Vector<Real>,
+    ang_jac2: AngVector<Real>,
+    impulse_bounds: [Real; 2],
+    rhs: Real,
+    rhs_wo_bias: Real,
+}
+
+impl CouplingJacobians {
+    fn new(
+        params: &IntegrationParameters,
+        coupling: &JointCoupling,
+        body1: &SolverBody<Real, 1>,
+        body2: &SolverBody<Real, 1>,
+        frame1: &Isometry<Real>,
+        frame2: &Isometry<Real>,
+    ) -> Self {
+        let ratio = coupling.ratio;
+        let r1 = frame1.translation.vector - body1.world_com.coords;
+        let r2 = frame2.translation.vector - body2.world_com.coords;
+
+        #[cfg(feature = "dim2")]
+        let (axis1, axis2) = (1.0, 1.0);
+        #[cfg(feature = "dim3")]
+        let (axis1, axis2) = (frame1 * Vector::x(), frame2 * Vector::x());
+
+        let (lin_jac1, ang_jac1, lin_jac2, ang_jac2) = match coupling.kind {
+            JointCouplingKind::Gear => (Vector::zeros(), axis1, Vector::zeros(), axis2 * ratio),
+            JointCouplingKind::RackAndPinion => {
+                let lin_axis2 = frame2 * Vector::x();
+                (
+                    Vector::zeros(),
+                    axis1,
+                    lin_axis2 * ratio,
+                    r2.gcross(lin_axis2) * ratio,
+                )
+            }
+            JointCouplingKind::Pulley {
+                ground_anchor1,
+                ground_anchor2,
+                ..
+            } => {
+                let dir1 = (frame1.translation.vector - ground_anchor1.coords)
+                    .try_normalize(DEFAULT_EPSILON)
+                    .unwrap_or_else(Vector::zeros);
+                let dir2 = (frame2.translation.vector - ground_anchor2.coords)
+                    .try_normalize(DEFAULT_EPSILON)
+                    .unwrap_or_else(Vector::zeros);
+                (dir1, r1.gcross(dir1), dir2 * ratio, r2.gcross(dir2) * ratio)
+            }
+        };
+
+        let dvel = lin_jac1.dot(&body1.linvel)
+            + ang_jac1.gdot(body1.angvel)
+            + lin_jac2.dot(&body2.linvel)
+            + ang_jac2.gdot(body2.angvel);
+
+        let (impulse_bounds, rhs_wo_bias, rhs_bias) = match coupling.kind {
+            JointCouplingKind::Pulley {
+                ground_anchor1,
+                ground_anchor2,
+                length,
+            } => {
+                // The rope can only pull, and is allowed to get slack.
+                let len1 = (frame1.translation.vector - ground_anchor1.coords).norm();
+                let len2 = (frame2.translation.vector - ground_anchor2.coords).norm();
+                let err = len1 + ratio * len2 - length;
+                (
+                    [0.0, Real::MAX],
+                    dvel + err.min(0.0) * params.inv_dt(),
+                    err.max(0.0) * params.joint_erp_inv_dt(),
+                )
+            }
+            // Gears and racks don’t correct their positional drift.
+            _ => ([-Real::MAX, Real::MAX], dvel, 0.0),
+        };
+
+        Self {
+            lin_jac1,
+            ang_jac1: body1.sqrt_ii * ang_jac1,
+            lin_jac2,
+            ang_jac2: body2.sqrt_ii * ang_jac2,
+            impulse_bounds,
+            rhs: rhs_wo_bias + rhs_bias,
+            rhs_wo_bias,
+        }
+    }
+}
+
+/// A velocity constraint enforcing the coupling of a joint between two dynamic bodies.
+///
+/// Contrary to the other joint constraints, its jacobians aren’t expressed relative to
+/// the joint’s frames, and its impulse is carried across timesteps to warm-start the solver.
+#[derive(Debug, Copy, Clone)]
+pub struct JointCouplingVelocityConstraint {
+    pub mj_lambda1: usize,
+    pub mj_lambda2: usize,
+
+    pub joint_id: JointIndex,
+
+    pub impulse: Real,
+    pub impulse_bounds: [Real; 2],
+    pub lin_jac1: Vector<Real>,
+    pub ang_jac1: AngVector<Real>,
+    pub lin_jac2: Vector<Real>,
+    pub ang_jac2: AngVector<Real>,
+
+    pub inv_lhs: Real,
+    pub rhs: Real,
+    pub rhs_wo_bias: Real,
+
+    pub im1: Vector<Real>,
+    pub im2: Vector<Real>,
+}
+
+impl JointCouplingVelocityConstraint {
+    pub fn new(
+        params: &IntegrationParameters,
+        joint_id: JointIndex,
+        coupling: &JointCoupling,
+        body1: &SolverBody<Real, 1>,
+        body2: &SolverBody<Real, 1>,
+        frame1: &Isometry<Real>,
+        frame2: &Isometry<Real>,
+    ) -> Self {
+        let jacs = CouplingJacobians::new(params, coupling, body1, body2, frame1, frame2);
+        let dot_jj = jacs.lin_jac1.dot(&body1.im.component_mul(&jacs.lin_jac1))
+            + jacs.ang_jac1.gdot(jacs.ang_jac1)
+            + jacs.lin_jac2.dot(&body2.im.component_mul(&jacs.lin_jac2))
+            + jacs.ang_jac2.gdot(jacs.ang_jac2);
+
+        Self {
+            mj_lambda1: body1.mj_lambda[0],
+            mj_lambda2: body2.mj_lambda[0],
+            joint_id,
+            impulse: coupling
+                .impulse
+                .max(jacs.impulse_bounds[0])
+                .min(jacs.impulse_bounds[1]),
+            impulse_bounds: jacs.impulse_bounds,
+            lin_jac1: jacs.lin_jac1,
+            ang_jac1: jacs.ang_jac1,
+            lin_jac2: jacs.lin_jac2,
+            ang_jac2: jacs.ang_jac2,
+            inv_lhs: crate::utils::inv(dot_jj),
+            rhs: jacs.rhs,
+            rhs_wo_bias: jacs.rhs_wo_bias,
+            im1: body1.im,
+            im2: body2.im,
+        }
+    }
+
+    fn apply_impulse(&self, impulse: Real, mj_lambdas: &mut [DeltaVel<Real>]) {
+        let mj_lambda1 = &mut mj_lambdas[self.mj_lambda1];
+        mj_lambda1.linear -= self.lin_jac1.component_mul(&self.im1) * impulse;
+        mj_lambda1.angular -= self.ang_jac1 * impulse;
+
+        let mj_lambda2 = &mut mj_lambdas[self.mj_lambda2];
+        mj_lambda2.linear -= self.lin_jac2.component_mul(&self.im2) * impulse;
+        mj_lambda2.angular -= self.ang_jac2 * impulse;
+    }
+
+    pub fn warmstart(&self, mj_lambdas: &mut [DeltaVel<Real>]) {
+        self.apply_impulse(self.impulse, mj_lambdas);
+    }
+
+    pub fn solve(&mut self, mj_lambdas: &mut [DeltaVel<Real>]) {
+        let mj_lambda1 = mj_lambdas[self.mj_lambda1];
+        let mj_lambda2 = mj_lambdas[self.mj_lambda2];
+
+        let dvel = self.lin_jac1.dot(&mj_lambda1.linear)
+            + self.ang_jac1.gdot(mj_lambda1.angular)
+            + self.lin_jac2.dot(&mj_lambda2.linear)
+            + self.ang_jac2.gdot(mj_lambda2.angular)
+            + self.rhs;
+        let total_impulse = (self.impulse + self.inv_lhs * dvel)
+            .max(self.impulse_bounds[0])
+            .min(self.impulse_bounds[1]);
+        let delta_impulse = total_impulse - self.impulse;
+        self.impulse = total_impulse;
+
+        self.apply_impulse(delta_impulse, mj_lambdas);
+    }
+
+    pub fn remove_bias_from_rhs(&mut self) {
+        self.rhs = self.rhs_wo_bias;
+    }
+
+    pub fn writeback_impulses(&self, joints_all: &mut [JointGraphEdge]) {
+        if let Some(coupling) = &mut joints_all[self.joint_id].weight.data.coupling {
+            coupling.impulse = self.impulse;
+        }
+    }
+}
+
+/// A velocity constraint enforcing the coupling of a joint between a dynamic body
+/// and a non-dynamic body.
+#[derive(Debug, Copy, Clone)]
+pub struct JointCouplingVelocityGroundConstraint {
+    pub mj_lambda: usize,
+
+    pub joint_id: JointIndex,
+
+    pub impulse: Real,
+    pub impulse_bounds: [Real; 2],
+    pub lin_jac: Vector<Real>,
+    pub ang_jac: AngVector<Real>,
+
+    pub inv_lhs: Real,
+    pub rhs: Real,
+    pub rhs_wo_bias: Real,
+
+    pub im: Vector<Real>,
+}
+
+impl JointCouplingVelocityGroundConstraint {
+    /// Creates the coupling constraint of a joint attached to a single dynamic body.
+    ///
+    /// The bodies and frames must be given in the order they are attached to the joint.
+    /// The dynamic one is `body2` if `flipped` is `false` and `body1` otherwise.
+    pub fn new(
+        params: &IntegrationParameters,
+        joint_id: JointIndex,
+        coupling: &JointCoupling,
+        body1: &SolverBody<Real, 1>,
+        body2: &SolverBody<Real, 1>,
+        frame1: &Isometry<Real>,
+        frame2: &Isometry<Real>,
+        flipped: bool,
+    ) -> Self {
+        let jacs = CouplingJacobians::new(params, coupling, body1, body2, frame1, frame2);
+        let (lin_jac, ang_jac, body) = if flipped {
+            (jacs.lin_jac1, jacs.ang_jac1, body1)
+        } else {
+            (jacs.lin_jac2, jacs.ang_jac2, body2)
+        };
+        let dot_jj = lin_jac.dot(&body.im.component_mul(&lin_jac)) + ang_jac.gdot(ang_jac);
+
+        Self {
+            mj_lambda: body.mj_lambda[0],
+            joint_id,
+            impulse: coupling
+                .impulse
+                .max(jacs.impulse_bounds[0])
+                .min(jacs.impulse_bounds[1]),
+            impulse_bounds: jacs.impulse_bounds,
+            lin_jac,
+            ang_jac,
+            inv_lhs: crate::utils::inv(dot_jj),
+            rhs: jacs.rhs,
+            rhs_wo_bias: jacs.rhs_wo_bias,
+            im: body.im,
+        }
+    }
+
+    fn apply_impulse(&self, impulse: Real, mj_lambdas: &mut [DeltaVel<Real>]) {
+        let mj_lambda = &mut mj_lambdas[self.mj_lambda];
+        mj_lambda.linear -= self.lin_jac.component_mul(&self.im) * impulse;
+        mj_lambda.angular -= self.ang_jac * impulse;
+    }
+
+    pub fn warmstart(&self, mj_lambdas: &mut [DeltaVel<Real>]) {
+        self.apply_impulse(self.impulse, mj_lambdas);
+    }
+
+    pub fn solve(&mut self, mj_lambdas: &mut [DeltaVel<Real>]) {
+        let mj_lambda = mj_lambdas[self.mj_lambda];
+
+        let dvel =
+            self.lin_jac.dot(&mj_lambda.linear) + self.ang_jac.gdot(mj_lambda.angular) + self.rhs;
+        let total_impulse = (self.impulse + self.inv_lhs * dvel)
+            .max(self.impulse_bounds[0])
+            .min(self.impulse_bounds[1]);
+        let delta_impulse = total_impulse - self.impulse;
+        self.impulse = total_impulse;
+
+        self.apply_impulse(delta_impulse, mj_lambdas);
+    }
+
+    pub fn remove_bias_from_rhs(&mut self) {
+        self.rhs = self.rhs_wo_bias;
+    }
+
+    pub fn writeback_impulses(&self, joints_all: &mut [JointGraphEdge]) {
+        if let Some(coupling) = &mut joints_all[self.joint_id].weight.data.coupling {
+            coupling.impulse = self.impulse;
+        }
+    }
+}
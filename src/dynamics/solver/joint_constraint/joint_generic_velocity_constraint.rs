@@ -1,7 +1,11 @@
 use crate::dynamics::solver::joint_constraint::joint_velocity_constraint::WritebackId;
 use crate::dynamics::solver::joint_constraint::{JointVelocityConstraintBuilder, SolverBody};
 use crate::dynamics::solver::DeltaVel;
-use crate::dynamics::{GenericJoint, IntegrationParameters, JointGraphEdge, JointIndex, Multibody};
+use crate::dynamics::solver::MotorParameters;
+use crate::dynamics::{
+    GenericJoint, IntegrationParameters, JointAxesMask, JointGraphEdge, JointIndex, Multibody,
+    MultibodyJointSet, RigidBodyHandle,
+};
 use crate::math::{Isometry, Real, DIM};
 use crate::prelude::SPATIAL_DIM;
 use na::{DVector, DVectorSlice, DVectorSliceMut};
@@ -78,13 +82,17 @@ impl JointGenericVelocityConstraint {
         let locked_axes = joint.locked_axes.bits();
         let motor_axes = joint.motor_axes.bits();
         let limit_axes = joint.limit_axes.bits();
+        let coupled_axes = joint.coupled_axes.bits();
 
+        let (erp_inv_dt, cfm_coeff) = joint.erp_inv_dt_and_cfm_coeff(params);
         let builder = JointVelocityConstraintBuilder::new(
             frame1,
             frame2,
             &body1.world_com,
             &body2.world_com,
             locked_axes,
+            erp_inv_dt,
+            cfm_coeff,
         );
 
         let start = len;
@@ -107,7 +115,7 @@ impl JointGenericVelocityConstraint {
             }
         }
         for i in 0..DIM {
-            if motor_axes & (1 << i) != 0 {
+            if (motor_axes & !coupled_axes) & (1 << i) != 0 {
                 out[len] = builder.motor_linear_generic(
                     params,
                     jacobians,
@@ -125,6 +133,78 @@ impl JointGenericVelocityConstraint {
                 len += 1;
             }
         }
+
+        if (motor_axes & coupled_axes) & JointAxesMask::LIN_AXES.bits() != 0 {
+            // See `JointVelocityConstraint::lock_axes` for the choice of motor and limits.
+            let i = ((motor_axes & coupled_axes) & JointAxesMask::LIN_AXES.bits()).trailing_zeros()
+                as usize;
+            let limited_axes = (limit_axes & coupled_axes) & JointAxesMask::LIN_AXES.bits();
+            let limits = if limited_axes != 0 {
+                let j = limited_axes.trailing_zeros() as usize;
+                Some([joint.limits[j].min, joint.limits[j].max])
+            } else {
+                None
+            };
+
+            out[len] = builder.motor_linear_coupled_generic(
+                params,
+                jacobians,
+                j_id,
+                joint_id,
+                body1,
+                body2,
+                mb1,
+                mb2,
+                coupled_axes,
+                &joint.motors[i].motor_params(params.dt),
+                limits,
+                WritebackId::Motor(i),
+            );
+            len += 1;
+        }
+
+        let friction_axes = joint.friction_axes();
+        for i in DIM..SPATIAL_DIM {
+            if friction_axes & (1 << i) != 0 {
+                let mut c = builder.motor_angular_generic(
+                    params,
+                    jacobians,
+                    j_id,
+                    joint_id,
+                    body1,
+                    body2,
+                    mb1,
+                    mb2,
+                    i - DIM,
+                    &MotorParameters::default(),
+                    WritebackId::Friction(i),
+                );
+                c.impulse_bounds = joint.frictions[i].impulse_bounds(params.dt, c.rhs_wo_bias);
+                out[len] = c;
+                len += 1;
+            }
+        }
+        for i in 0..DIM {
+            if friction_axes & (1 << i) != 0 {
+                let mut c = builder.motor_linear_generic(
+                    params,
+                    jacobians,
+                    j_id,
+                    joint_id,
+                    body1,
+                    body2,
+                    mb1,
+                    mb2,
+                    i,
+                    &MotorParameters::default(),
+                    WritebackId::Friction(i),
+                );
+                c.impulse_bounds = joint.frictions[i].impulse_bounds(params.dt, c.rhs_wo_bias);
+                out[len] = c;
+                len += 1;
+            }
+        }
+
         JointVelocityConstraintBuilder::finalize_generic_constraints(
             jacobians,
             &mut out[start..len],
@@ -167,7 +247,7 @@ impl JointGenericVelocityConstraint {
         }
 
         for i in DIM..SPATIAL_DIM {
-            if limit_axes & (1 << i) != 0 {
+            if (limit_axes & !coupled_axes) & (1 << i) != 0 {
                 out[len] = builder.limit_angular_generic(
                     params,
                     jacobians,
@@ -185,7 +265,7 @@ impl JointGenericVelocityConstraint {
             }
         }
         for i in 0..DIM {
-            if limit_axes & (1 << i) != 0 {
+            if (limit_axes & !coupled_axes) & (1 << i) != 0 {
                 out[len] = builder.limit_linear_generic(
                     params,
                     jacobians,
@@ -203,6 +283,45 @@ impl JointGenericVelocityConstraint {
             }
         }
 
+        #[cfg(feature = "dim3")]
+        if (limit_axes & coupled_axes) & JointAxesMask::ANG_AXES.bits() != 0 {
+            // The impulse of the swing limit is written back to the first swing axis.
+            let i = ((limit_axes & coupled_axes) & JointAxesMask::ANG_AXES.bits()).trailing_zeros()
+                as usize;
+            out[len] = builder.limit_angular_coupled_generic(
+                params,
+                jacobians,
+                j_id,
+                joint_id,
+                body1,
+                body2,
+                mb1,
+                mb2,
+                joint,
+                WritebackId::Limit(i),
+            );
+            len += 1;
+        }
+
+        if (limit_axes & coupled_axes) & JointAxesMask::LIN_AXES.bits() != 0 {
+            let i = ((limit_axes & coupled_axes) & JointAxesMask::LIN_AXES.bits()).trailing_zeros()
+                as usize;
+            out[len] = builder.limit_linear_coupled_generic(
+                params,
+                jacobians,
+                j_id,
+                joint_id,
+                body1,
+                body2,
+                mb1,
+                mb2,
+                coupled_axes,
+                [joint.limits[i].min, joint.limits[i].max],
+                WritebackId::Limit(i),
+            );
+            len += 1;
+        }
+
         JointVelocityConstraintBuilder::finalize_generic_constraints(
             jacobians,
             &mut out[start..len],
@@ -306,6 +425,7 @@ impl JointGenericVelocityConstraint {
             WritebackId::Dof(i) => joint.impulses[i] = self.impulse,
             WritebackId::Limit(i) => joint.data.limits[i].impulse = self.impulse,
             WritebackId::Motor(i) => joint.data.motors[i].impulse = self.impulse,
+            WritebackId::Friction(i) => joint.data.frictions[i].impulse = self.impulse,
         }
     }
 
@@ -321,6 +441,8 @@ pub struct JointGenericVelocityGroundConstraint {
     pub j_id2: usize,
 
     pub joint_id: JointIndex,
+    // The rigid-body of the multibody link this constraint is internal to, if any.
+    pub multibody_link: Option<RigidBodyHandle>,
 
     pub impulse: Real,
     pub impulse_bounds: [Real; 2],
@@ -346,6 +468,7 @@ impl JointGenericVelocityGroundConstraint {
             ndofs2: 0,
             j_id2: crate::INVALID_USIZE,
             joint_id: 0,
+            multibody_link: None,
             impulse: 0.0,
             impulse_bounds: [-Real::MAX, Real::MAX],
             inv_lhs: 0.0,
@@ -374,13 +497,17 @@ impl JointGenericVelocityGroundConstraint {
         let locked_axes = joint.locked_axes.bits();
         let motor_axes = joint.motor_axes.bits();
         let limit_axes = joint.limit_axes.bits();
+        let coupled_axes = joint.coupled_axes.bits();
 
+        let (erp_inv_dt, cfm_coeff) = joint.erp_inv_dt_and_cfm_coeff(params);
         let builder = JointVelocityConstraintBuilder::new(
             frame1,
             frame2,
             &body1.world_com,
             &body2.world_com,
             locked_axes,
+            erp_inv_dt,
+            cfm_coeff,
         );
 
         let start = len;
@@ -403,7 +530,7 @@ impl JointGenericVelocityGroundConstraint {
         }
 
         for i in 0..DIM {
-            if motor_axes & (1 << i) != 0 {
+            if (motor_axes & !coupled_axes) & (1 << i) != 0 {
                 out[len] = builder.motor_linear_generic_ground(
                     params,
                     jacobians,
@@ -421,6 +548,73 @@ impl JointGenericVelocityGroundConstraint {
             }
         }
 
+        if (motor_axes & coupled_axes) & JointAxesMask::LIN_AXES.bits() != 0 {
+            // See `JointVelocityConstraint::lock_axes` for the choice of motor and limits.
+            let i = ((motor_axes & coupled_axes) & JointAxesMask::LIN_AXES.bits()).trailing_zeros()
+                as usize;
+            let limited_axes = (limit_axes & coupled_axes) & JointAxesMask::LIN_AXES.bits();
+            let limits = if limited_axes != 0 {
+                let j = limited_axes.trailing_zeros() as usize;
+                Some([joint.limits[j].min, joint.limits[j].max])
+            } else {
+                None
+            };
+
+            out[len] = builder.motor_linear_coupled_generic_ground(
+                params,
+                jacobians,
+                j_id,
+                joint_id,
+                body1,
+                mb2,
+                coupled_axes,
+                &joint.motors[i].motor_params(params.dt),
+                limits,
+                WritebackId::Motor(i),
+            );
+            len += 1;
+        }
+
+        let friction_axes = joint.friction_axes();
+        for i in DIM..SPATIAL_DIM {
+            if friction_axes & (1 << i) != 0 {
+                let mut c = builder.motor_angular_generic_ground(
+                    params,
+                    jacobians,
+                    j_id,
+                    joint_id,
+                    body1,
+                    body2,
+                    mb2,
+                    i - DIM,
+                    &MotorParameters::default(),
+                    WritebackId::Friction(i),
+                );
+                c.impulse_bounds = joint.frictions[i].impulse_bounds(params.dt, c.rhs_wo_bias);
+                out[len] = c;
+                len += 1;
+            }
+        }
+        for i in 0..DIM {
+            if friction_axes & (1 << i) != 0 {
+                let mut c = builder.motor_linear_generic_ground(
+                    params,
+                    jacobians,
+                    j_id,
+                    joint_id,
+                    body1,
+                    body2,
+                    mb2,
+                    i,
+                    &MotorParameters::default(),
+                    WritebackId::Friction(i),
+                );
+                c.impulse_bounds = joint.frictions[i].impulse_bounds(params.dt, c.rhs_wo_bias);
+                out[len] = c;
+                len += 1;
+            }
+        }
+
         JointVelocityConstraintBuilder::finalize_generic_constraints_ground(
             jacobians,
             &mut out[start..len],
@@ -459,7 +653,7 @@ impl JointGenericVelocityGroundConstraint {
         }
 
         for i in DIM..SPATIAL_DIM {
-            if limit_axes & (1 << i) != 0 {
+            if (limit_axes & !coupled_axes) & (1 << i) != 0 {
                 out[len] = builder.limit_angular_generic_ground(
                     params,
                     jacobians,
@@ -475,7 +669,7 @@ impl JointGenericVelocityGroundConstraint {
             }
         }
         for i in 0..DIM {
-            if limit_axes & (1 << i) != 0 {
+            if (limit_axes & !coupled_axes) & (1 << i) != 0 {
                 out[len] = builder.limit_linear_generic_ground(
                     params,
                     jacobians,
@@ -491,6 +685,41 @@ impl JointGenericVelocityGroundConstraint {
             }
         }
 
+        #[cfg(feature = "dim3")]
+        if (limit_axes & coupled_axes) & JointAxesMask::ANG_AXES.bits() != 0 {
+            // The impulse of the swing limit is written back to the first swing axis.
+            let i = ((limit_axes & coupled_axes) & JointAxesMask::ANG_AXES.bits()).trailing_zeros()
+                as usize;
+            out[len] = builder.limit_angular_coupled_generic_ground(
+                params,
+                jacobians,
+                j_id,
+                joint_id,
+                body1,
+                mb2,
+                joint,
+                WritebackId::Limit(i),
+            );
+            len += 1;
+        }
+
+        if (limit_axes & coupled_axes) & JointAxesMask::LIN_AXES.bits() != 0 {
+            let i = ((limit_axes & coupled_axes) & JointAxesMask::LIN_AXES.bits()).trailing_zeros()
+                as usize;
+            out[len] = builder.limit_linear_coupled_generic_ground(
+                params,
+                jacobians,
+                j_id,
+                joint_id,
+                body1,
+                mb2,
+                coupled_axes,
+                [joint.limits[i].min, joint.limits[i].max],
+                WritebackId::Limit(i),
+            );
+            len += 1;
+        }
+
         JointVelocityConstraintBuilder::finalize_generic_constraints_ground(
             jacobians,
             &mut out[start..len],
@@ -544,14 +773,35 @@ impl JointGenericVelocityGroundConstraint {
         mj_lambda2.axpy(-delta_impulse, &wj2, 1.0);
     }
 
-    pub fn writeback_impulses(&self, joints_all: &mut [JointGraphEdge]) {
-        // FIXME: impulse writeback isn’t supported yet for internal multibody_joint constraints.
-        if self.joint_id != usize::MAX {
+    pub fn writeback_impulses(
+        &self,
+        joints_all: &mut [JointGraphEdge],
+        multibodies: &mut MultibodyJointSet,
+    ) {
+        if let Some(rb) = self.multibody_link {
+            // This is an internal multibody_joint constraint.
+            if let Some(link_id) = multibodies.rigid_body_link(rb).copied() {
+                let link = multibodies
+                    .get_multibody_mut_internal(link_id.multibody)
+                    .and_then(|mb| mb.link_mut(link_id.id));
+
+                if let Some(link) = link {
+                    let joint = &mut link.joint.data;
+                    match self.writeback_id {
+                        WritebackId::Dof(_) => {}
+                        WritebackId::Limit(i) => joint.limits[i].impulse = self.impulse,
+                        WritebackId::Motor(i) => joint.motors[i].impulse = self.impulse,
+                        WritebackId::Friction(i) => joint.frictions[i].impulse = self.impulse,
+                    }
+                }
+            }
+        } else {
             let joint = &mut joints_all[self.joint_id].weight;
             match self.writeback_id {
                 WritebackId::Dof(i) => joint.impulses[i] = self.impulse,
                 WritebackId::Limit(i) => joint.data.limits[i].impulse = self.impulse,
                 WritebackId::Motor(i) => joint.data.motors[i].impulse = self.impulse,
+                WritebackId::Friction(i) => joint.data.frictions[i].impulse = self.impulse,
             }
         }
     }
@@ -11,7 +11,7 @@ use crate::utils::WDot;
 use na::{DVector, SVector};
 
 #[cfg(feature = "dim3")]
-use crate::utils::WAngularInertia;
+use crate::{dynamics::GenericJoint, utils::WAngularInertia};
 
 impl SolverBody<Real, 1> {
     pub fn fill_jacobians(
@@ -171,7 +171,7 @@ impl JointVelocityConstraintBuilder<Real> {
             ang_jac2,
         );
 
-        let erp_inv_dt = params.joint_erp_inv_dt();
+        let erp_inv_dt = self.erp_inv_dt;
         let rhs_bias = lin_jac.dot(&self.lin_err) * erp_inv_dt;
         c.rhs += rhs_bias;
         c
@@ -214,7 +214,7 @@ impl JointVelocityConstraintBuilder<Real> {
         let min_enabled = dist < limits[0];
         let max_enabled = limits[1] < dist;
 
-        let erp_inv_dt = params.joint_erp_inv_dt();
+        let erp_inv_dt = self.erp_inv_dt;
         let rhs_bias = ((dist - limits[1]).max(0.0) - (limits[0] - dist).max(0.0)) * erp_inv_dt;
         constraint.rhs += rhs_bias;
         constraint.impulse_bounds = [
@@ -276,7 +276,131 @@ impl JointVelocityConstraintBuilder<Real> {
             + (ang_jac2.gdot(body2.angvel) - ang_jac1.gdot(body1.angvel));
         rhs_wo_bias += dvel - motor_params.target_vel;
 
-        constraint.impulse_bounds = [-motor_params.max_impulse, motor_params.max_impulse];
+        constraint.impulse_bounds = motor_params.impulse_bounds(dvel);
+        constraint.rhs = rhs_wo_bias;
+        constraint.rhs_wo_bias = rhs_wo_bias;
+        constraint.cfm_coeff = motor_params.cfm_coeff;
+        constraint.cfm_gain = motor_params.cfm_gain;
+        constraint
+    }
+
+    /// The jacobians of the distance between the two joint anchors, projected on the
+    /// coupled linear axes. See [`Self::linear_coupled_jacobians`].
+    fn linear_coupled_jacobians_generic(
+        &self,
+        coupled_axes: u8,
+    ) -> (
+        Vector<Real>,
+        SVector<Real, ANG_DIM>,
+        SVector<Real, ANG_DIM>,
+        Real,
+    ) {
+        let (lin_jac, ang_jac1, ang_jac2, dist) = self.linear_coupled_jacobians(coupled_axes);
+        #[cfg(feature = "dim2")]
+        return (
+            lin_jac,
+            SVector::<Real, 1>::new(ang_jac1),
+            SVector::<Real, 1>::new(ang_jac2),
+            dist,
+        );
+        #[cfg(feature = "dim3")]
+        return (lin_jac, ang_jac1, ang_jac2, dist);
+    }
+
+    pub fn limit_linear_coupled_generic(
+        &self,
+        params: &IntegrationParameters,
+        jacobians: &mut DVector<Real>,
+        j_id: &mut usize,
+        joint_id: JointIndex,
+        body1: &SolverBody<Real, 1>,
+        body2: &SolverBody<Real, 1>,
+        mb1: Option<(&Multibody, usize)>,
+        mb2: Option<(&Multibody, usize)>,
+        coupled_axes: u8,
+        limits: [Real; 2],
+        writeback_id: WritebackId,
+    ) -> JointGenericVelocityConstraint {
+        let (lin_jac, ang_jac1, ang_jac2, dist) =
+            self.linear_coupled_jacobians_generic(coupled_axes);
+
+        let mut constraint = self.lock_jacobians_generic(
+            params,
+            jacobians,
+            j_id,
+            joint_id,
+            body1,
+            body2,
+            mb1,
+            mb2,
+            writeback_id,
+            lin_jac,
+            ang_jac1,
+            ang_jac2,
+        );
+
+        let min_enabled = dist < limits[0];
+        let max_enabled = limits[1] < dist;
+
+        let erp_inv_dt = self.erp_inv_dt;
+        let rhs_bias = ((dist - limits[1]).max(0.0) - (limits[0] - dist).max(0.0)) * erp_inv_dt;
+        constraint.rhs += rhs_bias;
+        constraint.impulse_bounds = [
+            min_enabled as u32 as Real * -Real::MAX,
+            max_enabled as u32 as Real * Real::MAX,
+        ];
+
+        constraint
+    }
+
+    pub fn motor_linear_coupled_generic(
+        &self,
+        params: &IntegrationParameters,
+        jacobians: &mut DVector<Real>,
+        j_id: &mut usize,
+        joint_id: JointIndex,
+        body1: &SolverBody<Real, 1>,
+        body2: &SolverBody<Real, 1>,
+        mb1: Option<(&Multibody, usize)>,
+        mb2: Option<(&Multibody, usize)>,
+        coupled_axes: u8,
+        motor_params: &MotorParameters<Real>,
+        limits: Option<[Real; 2]>,
+        writeback_id: WritebackId,
+    ) -> JointGenericVelocityConstraint {
+        let (lin_jac, ang_jac1, ang_jac2, dist) =
+            self.linear_coupled_jacobians_generic(coupled_axes);
+
+        let mut constraint = self.lock_jacobians_generic(
+            params,
+            jacobians,
+            j_id,
+            joint_id,
+            body1,
+            body2,
+            mb1,
+            mb2,
+            writeback_id,
+            lin_jac,
+            ang_jac1,
+            ang_jac2,
+        );
+
+        let mut rhs_wo_bias = 0.0;
+        if motor_params.erp_inv_dt != 0.0 {
+            rhs_wo_bias += (dist - motor_params.target_pos) * motor_params.erp_inv_dt;
+        }
+
+        let mut target_vel = motor_params.target_vel;
+        if let Some(limits) = limits {
+            let inv_dt = params.inv_dt();
+            target_vel = target_vel.clamp((limits[0] - dist) * inv_dt, (limits[1] - dist) * inv_dt);
+        };
+
+        // The relative velocity along the jacobian was computed by `lock_jacobians_generic`.
+        rhs_wo_bias += constraint.rhs_wo_bias - target_vel;
+
+        constraint.impulse_bounds = motor_params.impulse_bounds(constraint.rhs_wo_bias);
         constraint.rhs = rhs_wo_bias;
         constraint.rhs_wo_bias = rhs_wo_bias;
         constraint.cfm_coeff = motor_params.cfm_coeff;
@@ -314,7 +438,7 @@ impl JointVelocityConstraintBuilder<Real> {
             ang_jac,
         );
 
-        let erp_inv_dt = params.joint_erp_inv_dt();
+        let erp_inv_dt = self.erp_inv_dt;
         #[cfg(feature = "dim2")]
         let rhs_bias = self.ang_err.im * erp_inv_dt;
         #[cfg(feature = "dim3")]
@@ -366,7 +490,7 @@ impl JointVelocityConstraintBuilder<Real> {
             max_enabled as u32 as Real * Real::MAX,
         ];
 
-        let erp_inv_dt = params.joint_erp_inv_dt();
+        let erp_inv_dt = self.erp_inv_dt;
         let rhs_bias =
             ((s_ang - s_limits[1]).max(0.0) - (s_limits[0] - s_ang).max(0.0)) * erp_inv_dt;
 
@@ -375,6 +499,49 @@ impl JointVelocityConstraintBuilder<Real> {
         constraint
     }
 
+    #[cfg(feature = "dim3")]
+    pub fn limit_angular_coupled_generic(
+        &self,
+        params: &IntegrationParameters,
+        jacobians: &mut DVector<Real>,
+        j_id: &mut usize,
+        joint_id: JointIndex,
+        body1: &SolverBody<Real, 1>,
+        body2: &SolverBody<Real, 1>,
+        mb1: Option<(&Multibody, usize)>,
+        mb2: Option<(&Multibody, usize)>,
+        joint: &GenericJoint,
+        writeback_id: WritebackId,
+    ) -> JointGenericVelocityConstraint {
+        let (ang_jac, angle, max_angle) = self.swing_limit(joint);
+
+        let mut constraint = self.lock_jacobians_generic(
+            params,
+            jacobians,
+            j_id,
+            joint_id,
+            body1,
+            body2,
+            mb1,
+            mb2,
+            writeback_id,
+            na::zero(),
+            ang_jac,
+            ang_jac,
+        );
+
+        // The swing angle is never negative so only the maximum limit can be reached.
+        let max_enabled = max_angle < angle;
+        let impulse_bounds = [0.0, max_enabled as u32 as Real * Real::MAX];
+
+        let erp_inv_dt = self.erp_inv_dt;
+        let rhs_bias = (angle - max_angle).max(0.0) * erp_inv_dt;
+
+        constraint.rhs += rhs_bias;
+        constraint.impulse_bounds = impulse_bounds;
+        constraint
+    }
+
     pub fn motor_angular_generic(
         &self,
         params: &IntegrationParameters,
@@ -427,7 +594,7 @@ impl JointVelocityConstraintBuilder<Real> {
         constraint.rhs = rhs_wo_bias;
         constraint.cfm_coeff = motor_params.cfm_coeff;
         constraint.cfm_gain = motor_params.cfm_gain;
-        constraint.impulse_bounds = [-motor_params.max_impulse, motor_params.max_impulse];
+        constraint.impulse_bounds = motor_params.impulse_bounds(dvel);
         constraint
     }
 
@@ -528,6 +695,7 @@ impl JointVelocityConstraintBuilder<Real> {
             ndofs2,
             j_id2,
             joint_id,
+            multibody_link: None,
             impulse: 0.0,
             impulse_bounds: [-Real::MAX, Real::MAX],
             inv_lhs: 0.0,
@@ -567,7 +735,7 @@ impl JointVelocityConstraintBuilder<Real> {
             ang_jac2,
         );
 
-        let erp_inv_dt = params.joint_erp_inv_dt();
+        let erp_inv_dt = self.erp_inv_dt;
         let rhs_bias = lin_jac.dot(&self.lin_err) * erp_inv_dt;
         c.rhs += rhs_bias;
         c
@@ -606,7 +774,7 @@ impl JointVelocityConstraintBuilder<Real> {
         let min_enabled = dist < limits[0];
         let max_enabled = limits[1] < dist;
 
-        let erp_inv_dt = params.joint_erp_inv_dt();
+        let erp_inv_dt = self.erp_inv_dt;
         let rhs_bias = ((dist - limits[1]).max(0.0) - (limits[0] - dist).max(0.0)) * erp_inv_dt;
         constraint.rhs += rhs_bias;
         constraint.impulse_bounds = [
@@ -665,7 +833,100 @@ impl JointVelocityConstraintBuilder<Real> {
             + (ang_jac2.gdot(body2.angvel) - ang_jac1.gdot(body1.angvel));
         rhs_wo_bias += dvel - motor_params.target_vel;
 
-        constraint.impulse_bounds = [-motor_params.max_impulse, motor_params.max_impulse];
+        constraint.impulse_bounds = motor_params.impulse_bounds(dvel);
+        constraint.rhs = rhs_wo_bias;
+        constraint.rhs_wo_bias = rhs_wo_bias;
+        constraint.cfm_coeff = motor_params.cfm_coeff;
+        constraint.cfm_gain = motor_params.cfm_gain;
+        constraint
+    }
+
+    pub fn limit_linear_coupled_generic_ground(
+        &self,
+        params: &IntegrationParameters,
+        jacobians: &mut DVector<Real>,
+        j_id: &mut usize,
+        joint_id: JointIndex,
+        body1: &SolverBody<Real, 1>,
+        mb2: (&Multibody, usize),
+        coupled_axes: u8,
+        limits: [Real; 2],
+        writeback_id: WritebackId,
+    ) -> JointGenericVelocityGroundConstraint {
+        let (lin_jac, ang_jac1, ang_jac2, dist) =
+            self.linear_coupled_jacobians_generic(coupled_axes);
+
+        let mut constraint = self.lock_jacobians_generic_ground(
+            params,
+            jacobians,
+            j_id,
+            joint_id,
+            body1,
+            mb2,
+            writeback_id,
+            lin_jac,
+            ang_jac1,
+            ang_jac2,
+        );
+
+        let min_enabled = dist < limits[0];
+        let max_enabled = limits[1] < dist;
+
+        let erp_inv_dt = self.erp_inv_dt;
+        let rhs_bias = ((dist - limits[1]).max(0.0) - (limits[0] - dist).max(0.0)) * erp_inv_dt;
+        constraint.rhs += rhs_bias;
+        constraint.impulse_bounds = [
+            min_enabled as u32 as Real * -Real::MAX,
+            max_enabled as u32 as Real * Real::MAX,
+        ];
+
+        constraint
+    }
+
+    pub fn motor_linear_coupled_generic_ground(
+        &self,
+        params: &IntegrationParameters,
+        jacobians: &mut DVector<Real>,
+        j_id: &mut usize,
+        joint_id: JointIndex,
+        body1: &SolverBody<Real, 1>,
+        mb2: (&Multibody, usize),
+        coupled_axes: u8,
+        motor_params: &MotorParameters<Real>,
+        limits: Option<[Real; 2]>,
+        writeback_id: WritebackId,
+    ) -> JointGenericVelocityGroundConstraint {
+        let (lin_jac, ang_jac1, ang_jac2, dist) =
+            self.linear_coupled_jacobians_generic(coupled_axes);
+
+        let mut constraint = self.lock_jacobians_generic_ground(
+            params,
+            jacobians,
+            j_id,
+            joint_id,
+            body1,
+            mb2,
+            writeback_id,
+            lin_jac,
+            ang_jac1,
+            ang_jac2,
+        );
+
+        let mut rhs_wo_bias = 0.0;
+        if motor_params.erp_inv_dt != 0.0 {
+            rhs_wo_bias += (dist - motor_params.target_pos) * motor_params.erp_inv_dt;
+        }
+
+        let mut target_vel = motor_params.target_vel;
+        if let Some(limits) = limits {
+            let inv_dt = params.inv_dt();
+            target_vel = target_vel.clamp((limits[0] - dist) * inv_dt, (limits[1] - dist) * inv_dt);
+        };
+
+        // The relative velocity along the jacobian was computed by `lock_jacobians_generic_ground`.
+        rhs_wo_bias += constraint.rhs_wo_bias - target_vel;
+
+        constraint.impulse_bounds = motor_params.impulse_bounds(constraint.rhs_wo_bias);
         constraint.rhs = rhs_wo_bias;
         constraint.rhs_wo_bias = rhs_wo_bias;
         constraint.cfm_coeff = motor_params.cfm_coeff;
@@ -699,7 +960,7 @@ impl JointVelocityConstraintBuilder<Real> {
             ang_jac,
         );
 
-        let erp_inv_dt = params.joint_erp_inv_dt();
+        let erp_inv_dt = self.erp_inv_dt;
         #[cfg(feature = "dim2")]
         let rhs_bias = self.ang_err.im * erp_inv_dt;
         #[cfg(feature = "dim3")]
@@ -747,7 +1008,7 @@ impl JointVelocityConstraintBuilder<Real> {
             max_enabled as u32 as Real * Real::MAX,
         ];
 
-        let erp_inv_dt = params.joint_erp_inv_dt();
+        let erp_inv_dt = self.erp_inv_dt;
         let rhs_bias =
             ((s_ang - s_limits[1]).max(0.0) - (s_limits[0] - s_ang).max(0.0)) * erp_inv_dt;
 
@@ -756,6 +1017,45 @@ impl JointVelocityConstraintBuilder<Real> {
         constraint
     }
 
+    #[cfg(feature = "dim3")]
+    pub fn limit_angular_coupled_generic_ground(
+        &self,
+        params: &IntegrationParameters,
+        jacobians: &mut DVector<Real>,
+        j_id: &mut usize,
+        joint_id: JointIndex,
+        body1: &SolverBody<Real, 1>,
+        mb2: (&Multibody, usize),
+        joint: &GenericJoint,
+        writeback_id: WritebackId,
+    ) -> JointGenericVelocityGroundConstraint {
+        let (ang_jac, angle, max_angle) = self.swing_limit(joint);
+
+        let mut constraint = self.lock_jacobians_generic_ground(
+            params,
+            jacobians,
+            j_id,
+            joint_id,
+            body1,
+            mb2,
+            writeback_id,
+            na::zero(),
+            ang_jac,
+            ang_jac,
+        );
+
+        // The swing angle is never negative so only the maximum limit can be reached.
+        let max_enabled = max_angle < angle;
+        let impulse_bounds = [0.0, max_enabled as u32 as Real * Real::MAX];
+
+        let erp_inv_dt = self.erp_inv_dt;
+        let rhs_bias = (angle - max_angle).max(0.0) * erp_inv_dt;
+
+        constraint.rhs += rhs_bias;
+        constraint.impulse_bounds = impulse_bounds;
+        constraint
+    }
+
     pub fn motor_angular_generic_ground(
         &self,
         params: &IntegrationParameters,
@@ -805,7 +1105,7 @@ impl JointVelocityConstraintBuilder<Real> {
         constraint.rhs = rhs;
         constraint.cfm_coeff = motor_params.cfm_coeff;
         constraint.cfm_gain = motor_params.cfm_gain;
-        constraint.impulse_bounds = [-motor_params.max_impulse, motor_params.max_impulse];
+        constraint.impulse_bounds = motor_params.impulse_bounds(dvel);
         constraint
     }
 
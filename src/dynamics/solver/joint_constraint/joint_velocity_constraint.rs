@@ -1,7 +1,7 @@
 use crate::dynamics::solver::joint_constraint::JointVelocityConstraintBuilder;
 use crate::dynamics::solver::DeltaVel;
 use crate::dynamics::{
-    GenericJoint, IntegrationParameters, JointAxesMask, JointGraphEdge, JointIndex,
+    ActuatorModel, GenericJoint, IntegrationParameters, JointAxesMask, JointGraphEdge, JointIndex,
 };
 use crate::math::{AngVector, AngularInertia, Isometry, Point, Real, Vector, DIM, SPATIAL_DIM};
 use crate::utils::{WDot, WReal};
@@ -19,7 +19,9 @@ pub struct MotorParameters<N: WReal> {
     pub cfm_gain: N,
     pub target_pos: N,
     pub target_vel: N,
-    pub max_impulse: N,
+    pub max_force: N,
+    pub dt: N,
+    pub actuator: ActuatorModel,
 }
 
 impl<N: WReal> Default for MotorParameters<N> {
@@ -30,16 +32,30 @@ impl<N: WReal> Default for MotorParameters<N> {
             cfm_gain: N::zero(),
             target_pos: N::zero(),
             target_vel: N::zero(),
-            max_impulse: N::zero(),
+            max_force: N::zero(),
+            dt: N::zero(),
+            actuator: ActuatorModel::Ideal,
         }
     }
 }
 
+impl<N: WReal> MotorParameters<N> {
+    /// The bounds of the motor’s impulse, given the current velocity along the motorized axis.
+    ///
+    /// A positive impulse slows the motorized degree of freedom down, so the bounds are the
+    /// opposite of the actuator’s force bounds.
+    pub fn impulse_bounds(&self, vel: N) -> [N; 2] {
+        let [min_force, max_force] = self.actuator.force_bounds(self.max_force, vel);
+        [-max_force * self.dt, -min_force * self.dt]
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum WritebackId {
     Dof(usize),
     Limit(usize),
     Motor(usize),
+    Friction(usize),
 }
 
 // TODO: right now we only use this for impulse_joints.
@@ -146,12 +162,15 @@ impl JointVelocityConstraint<Real, 1> {
         let limit_axes = joint.limit_axes.bits() & !locked_axes;
         let coupled_axes = joint.coupled_axes.bits();
 
+        let (erp_inv_dt, cfm_coeff) = joint.erp_inv_dt_and_cfm_coeff(params);
         let builder = JointVelocityConstraintBuilder::new(
             frame1,
             frame2,
             &body1.world_com,
             &body2.world_com,
             locked_axes,
+            erp_inv_dt,
+            cfm_coeff,
         );
 
         let start = len;
@@ -195,39 +214,79 @@ impl JointVelocityConstraint<Real, 1> {
         }
 
         if (motor_axes & coupled_axes) & JointAxesMask::LIN_AXES.bits() != 0 {
-            // TODO: coupled linear motor constraint.
-            // out[len] = builder.motor_linear_coupled(
-            //     params,
-            //     [joint_id],
-            //     body1,
-            //     body2,
-            //     limit_axes & coupled_axes,
-            //     &joint.limits,
-            //     WritebackId::Limit(0), // TODO: writeback
-            // );
-            // len += 1;
+            // The motor and limits of the coupled linear axes are the ones of the first
+            // coupled axis they are enabled on. They apply to the distance between the two
+            // anchors, projected on the coupled axes.
+            let i = ((motor_axes & coupled_axes) & JointAxesMask::LIN_AXES.bits()).trailing_zeros()
+                as usize;
+            let limited_axes = (limit_axes & coupled_axes) & JointAxesMask::LIN_AXES.bits();
+            let limits = if limited_axes != 0 {
+                let j = limited_axes.trailing_zeros() as usize;
+                Some([joint.limits[j].min, joint.limits[j].max])
+            } else {
+                None
+            };
+
+            out[len] = builder.motor_linear_coupled(
+                params,
+                [joint_id],
+                body1,
+                body2,
+                coupled_axes,
+                &joint.motors[i].motor_params(params.dt),
+                limits,
+                WritebackId::Motor(i),
+            );
+            len += 1;
         }
 
-        JointVelocityConstraintBuilder::finalize_constraints(&mut out[start..len]);
-
-        let start = len;
+        let friction_axes = joint.friction_axes();
         for i in DIM..SPATIAL_DIM {
-            if locked_axes & (1 << i) != 0 {
-                out[len] = builder.lock_angular(
-                    params,
+            if friction_axes & (1 << i) != 0 {
+                let mut c = builder.motor_angular(
                     [joint_id],
                     body1,
                     body2,
                     i - DIM,
-                    WritebackId::Dof(i),
+                    &MotorParameters::default(),
+                    WritebackId::Friction(i),
                 );
+                c.impulse_bounds = joint.frictions[i].impulse_bounds(params.dt, c.rhs_wo_bias);
+                out[len] = c;
                 len += 1;
             }
         }
         for i in 0..DIM {
+            if friction_axes & (1 << i) != 0 {
+                let mut c = builder.motor_linear(
+                    params,
+                    [joint_id],
+                    body1,
+                    body2,
+                    i,
+                    &MotorParameters::default(),
+                    None,
+                    WritebackId::Friction(i),
+                );
+                c.impulse_bounds = joint.frictions[i].impulse_bounds(params.dt, c.rhs_wo_bias);
+                out[len] = c;
+                len += 1;
+            }
+        }
+
+        JointVelocityConstraintBuilder::finalize_constraints(&mut out[start..len]);
+
+        let start = len;
+        for i in DIM..SPATIAL_DIM {
             if locked_axes & (1 << i) != 0 {
                 out[len] =
-                    builder.lock_linear(params, [joint_id], body1, body2, i, WritebackId::Dof(i));
+                    builder.lock_angular([joint_id], body1, body2, i - DIM, WritebackId::Dof(i));
+                len += 1;
+            }
+        }
+        for i in 0..DIM {
+            if locked_axes & (1 << i) != 0 {
+                out[len] = builder.lock_linear([joint_id], body1, body2, i, WritebackId::Dof(i));
                 len += 1;
             }
         }
@@ -235,7 +294,6 @@ impl JointVelocityConstraint<Real, 1> {
         for i in DIM..SPATIAL_DIM {
             if (limit_axes & !coupled_axes) & (1 << i) != 0 {
                 out[len] = builder.limit_angular(
-                    params,
                     [joint_id],
                     body1,
                     body2,
@@ -249,7 +307,6 @@ impl JointVelocityConstraint<Real, 1> {
         for i in 0..DIM {
             if (limit_axes & !coupled_axes) & (1 << i) != 0 {
                 out[len] = builder.limit_linear(
-                    params,
                     [joint_id],
                     body1,
                     body2,
@@ -263,27 +320,29 @@ impl JointVelocityConstraint<Real, 1> {
 
         #[cfg(feature = "dim3")]
         if (limit_axes & coupled_axes) & JointAxesMask::ANG_AXES.bits() != 0 {
+            // The impulse of the swing limit is written back to the first swing axis.
+            let i = ((limit_axes & coupled_axes) & JointAxesMask::ANG_AXES.bits()).trailing_zeros()
+                as usize;
             out[len] = builder.limit_angular_coupled(
-                params,
                 [joint_id],
                 body1,
                 body2,
-                limit_axes & coupled_axes,
-                &joint.limits,
-                WritebackId::Limit(0), // TODO: writeback
+                joint,
+                WritebackId::Limit(i),
             );
             len += 1;
         }
 
         if (limit_axes & coupled_axes) & JointAxesMask::LIN_AXES.bits() != 0 {
+            let i = ((limit_axes & coupled_axes) & JointAxesMask::LIN_AXES.bits()).trailing_zeros()
+                as usize;
             out[len] = builder.limit_linear_coupled(
-                params,
                 [joint_id],
                 body1,
                 body2,
-                limit_axes & coupled_axes,
-                &joint.limits,
-                WritebackId::Limit(0), // TODO: writeback
+                coupled_axes,
+                [joint.limits[i].min, joint.limits[i].max],
+                WritebackId::Limit(i),
             );
             len += 1;
         }
@@ -308,6 +367,7 @@ impl JointVelocityConstraint<Real, 1> {
             WritebackId::Dof(i) => joint.impulses[i] = self.impulse,
             WritebackId::Limit(i) => joint.data.limits[i].impulse = self.impulse,
             WritebackId::Motor(i) => joint.data.motors[i].impulse = self.impulse,
+            WritebackId::Friction(i) => joint.data.frictions[i].impulse = self.impulse,
         }
     }
 }
@@ -329,27 +389,22 @@ impl JointVelocityConstraint<SimdReal, SIMD_WIDTH> {
             &body1.world_com,
             &body2.world_com,
             locked_axes,
+            SimdReal::splat(params.joint_erp_inv_dt()),
+            SimdReal::splat(params.joint_cfm_coeff()),
         );
 
         let mut len = 0;
         for i in 0..DIM {
             if locked_axes & (1 << i) != 0 {
-                out[len] =
-                    builder.lock_linear(params, joint_id, body1, body2, i, WritebackId::Dof(i));
+                out[len] = builder.lock_linear(joint_id, body1, body2, i, WritebackId::Dof(i));
                 len += 1;
             }
         }
 
         for i in DIM..SPATIAL_DIM {
             if locked_axes & (1 << i) != 0 {
-                out[len] = builder.lock_angular(
-                    params,
-                    joint_id,
-                    body1,
-                    body2,
-                    i - DIM,
-                    WritebackId::Dof(i),
-                );
+                out[len] =
+                    builder.lock_angular(joint_id, body1, body2, i - DIM, WritebackId::Dof(i));
                 len += 1;
             }
         }
@@ -392,6 +447,7 @@ impl JointVelocityConstraint<SimdReal, SIMD_WIDTH> {
                 WritebackId::Dof(i) => joint.impulses[i] = impulses[ii],
                 WritebackId::Limit(i) => joint.data.limits[i].impulse = impulses[ii],
                 WritebackId::Motor(i) => joint.data.motors[i].impulse = impulses[ii],
+                WritebackId::Friction(i) => joint.data.frictions[i].impulse = impulses[ii],
             }
         }
     }
@@ -477,12 +533,15 @@ impl JointVelocityGroundConstraint<Real, 1> {
         let limit_axes = joint.limit_axes.bits() & !locked_axes;
         let coupled_axes = joint.coupled_axes.bits();
 
+        let (erp_inv_dt, cfm_coeff) = joint.erp_inv_dt_and_cfm_coeff(params);
         let builder = JointVelocityConstraintBuilder::new(
             frame1,
             frame2,
             &body1.world_com,
             &body2.world_com,
             locked_axes,
+            erp_inv_dt,
+            cfm_coeff,
         );
 
         let start = len;
@@ -526,58 +585,92 @@ impl JointVelocityGroundConstraint<Real, 1> {
         }
 
         if (motor_axes & coupled_axes) & JointAxesMask::LIN_AXES.bits() != 0 {
-            /*
-            // TODO: coupled linear motor constraint.
+            // The motor and limits of the coupled linear axes are the ones of the first
+            // coupled axis they are enabled on. They apply to the distance between the two
+            // anchors, projected on the coupled axes.
+            let i = ((motor_axes & coupled_axes) & JointAxesMask::LIN_AXES.bits()).trailing_zeros()
+                as usize;
+            let limited_axes = (limit_axes & coupled_axes) & JointAxesMask::LIN_AXES.bits();
+            let limits = if limited_axes != 0 {
+                let j = limited_axes.trailing_zeros() as usize;
+                Some([joint.limits[j].min, joint.limits[j].max])
+            } else {
+                None
+            };
+
             out[len] = builder.motor_linear_coupled_ground(
                 params,
                 [joint_id],
                 body1,
                 body2,
-                motor_axes & coupled_axes,
-                &joint.motors,
-                limit_axes & coupled_axes,
-                &joint.limits,
-                WritebackId::Limit(0), // TODO: writeback
+                coupled_axes,
+                &joint.motors[i].motor_params(params.dt),
+                limits,
+                WritebackId::Motor(i),
             );
             len += 1;
-            */
-            todo!()
         }
 
-        JointVelocityConstraintBuilder::finalize_ground_constraints(&mut out[start..len]);
-
-        let start = len;
+        let friction_axes = joint.friction_axes();
         for i in DIM..SPATIAL_DIM {
-            if locked_axes & (1 << i) != 0 {
-                out[len] = builder.lock_angular_ground(
-                    params,
+            if friction_axes & (1 << i) != 0 {
+                let mut c = builder.motor_angular_ground(
                     [joint_id],
                     body1,
                     body2,
                     i - DIM,
-                    WritebackId::Dof(i),
+                    &MotorParameters::default(),
+                    WritebackId::Friction(i),
                 );
+                c.impulse_bounds = joint.frictions[i].impulse_bounds(params.dt, c.rhs_wo_bias);
+                out[len] = c;
                 len += 1;
             }
         }
         for i in 0..DIM {
-            if locked_axes & (1 << i) != 0 {
-                out[len] = builder.lock_linear_ground(
+            if friction_axes & (1 << i) != 0 {
+                let mut c = builder.motor_linear_ground(
                     params,
                     [joint_id],
                     body1,
                     body2,
                     i,
+                    &MotorParameters::default(),
+                    None,
+                    WritebackId::Friction(i),
+                );
+                c.impulse_bounds = joint.frictions[i].impulse_bounds(params.dt, c.rhs_wo_bias);
+                out[len] = c;
+                len += 1;
+            }
+        }
+
+        JointVelocityConstraintBuilder::finalize_ground_constraints(&mut out[start..len]);
+
+        let start = len;
+        for i in DIM..SPATIAL_DIM {
+            if locked_axes & (1 << i) != 0 {
+                out[len] = builder.lock_angular_ground(
+                    [joint_id],
+                    body1,
+                    body2,
+                    i - DIM,
                     WritebackId::Dof(i),
                 );
                 len += 1;
             }
         }
+        for i in 0..DIM {
+            if locked_axes & (1 << i) != 0 {
+                out[len] =
+                    builder.lock_linear_ground([joint_id], body1, body2, i, WritebackId::Dof(i));
+                len += 1;
+            }
+        }
 
         for i in DIM..SPATIAL_DIM {
             if (limit_axes & !coupled_axes) & (1 << i) != 0 {
                 out[len] = builder.limit_angular_ground(
-                    params,
                     [joint_id],
                     body1,
                     body2,
@@ -591,7 +684,6 @@ impl JointVelocityGroundConstraint<Real, 1> {
         for i in 0..DIM {
             if (limit_axes & !coupled_axes) & (1 << i) != 0 {
                 out[len] = builder.limit_linear_ground(
-                    params,
                     [joint_id],
                     body1,
                     body2,
@@ -605,27 +697,29 @@ impl JointVelocityGroundConstraint<Real, 1> {
 
         #[cfg(feature = "dim3")]
         if (limit_axes & coupled_axes) & JointAxesMask::ANG_AXES.bits() != 0 {
+            // The impulse of the swing limit is written back to the first swing axis.
+            let i = ((limit_axes & coupled_axes) & JointAxesMask::ANG_AXES.bits()).trailing_zeros()
+                as usize;
             out[len] = builder.limit_angular_coupled_ground(
-                params,
                 [joint_id],
                 body1,
                 body2,
-                limit_axes & coupled_axes,
-                &joint.limits,
-                WritebackId::Limit(0), // TODO: writeback
+                joint,
+                WritebackId::Limit(i),
             );
             len += 1;
         }
 
         if (limit_axes & coupled_axes) & JointAxesMask::LIN_AXES.bits() != 0 {
+            let i = ((limit_axes & coupled_axes) & JointAxesMask::LIN_AXES.bits()).trailing_zeros()
+                as usize;
             out[len] = builder.limit_linear_coupled_ground(
-                params,
                 [joint_id],
                 body1,
                 body2,
-                limit_axes & coupled_axes,
-                &joint.limits,
-                WritebackId::Limit(0), // TODO: writeback
+                coupled_axes,
+                [joint.limits[i].min, joint.limits[i].max],
+                WritebackId::Limit(i),
             );
             len += 1;
         }
@@ -646,6 +740,7 @@ impl JointVelocityGroundConstraint<Real, 1> {
             WritebackId::Dof(i) => joint.impulses[i] = self.impulse,
             WritebackId::Limit(i) => joint.data.limits[i].impulse = self.impulse,
             WritebackId::Motor(i) => joint.data.motors[i].impulse = self.impulse,
+            WritebackId::Friction(i) => joint.data.frictions[i].impulse = self.impulse,
         }
     }
 }
@@ -669,25 +764,20 @@ impl JointVelocityGroundConstraint<SimdReal, SIMD_WIDTH> {
             &body1.world_com,
             &body2.world_com,
             locked_axes,
+            SimdReal::splat(params.joint_erp_inv_dt()),
+            SimdReal::splat(params.joint_cfm_coeff()),
         );
 
         for i in 0..DIM {
             if locked_axes & (1 << i) != 0 {
-                out[len] = builder.lock_linear_ground(
-                    params,
-                    joint_id,
-                    body1,
-                    body2,
-                    i,
-                    WritebackId::Dof(i),
-                );
+                out[len] =
+                    builder.lock_linear_ground(joint_id, body1, body2, i, WritebackId::Dof(i));
                 len += 1;
             }
         }
         for i in DIM..SPATIAL_DIM {
             if locked_axes & (1 << i) != 0 {
                 out[len] = builder.lock_angular_ground(
-                    params,
                     joint_id,
                     body1,
                     body2,
@@ -728,6 +818,7 @@ impl JointVelocityGroundConstraint<SimdReal, SIMD_WIDTH> {
                 WritebackId::Dof(i) => joint.impulses[i] = impulses[ii],
                 WritebackId::Limit(i) => joint.data.limits[i].impulse = impulses[ii],
                 WritebackId::Motor(i) => joint.data.motors[i].impulse = impulses[ii],
+                WritebackId::Friction(i) => joint.data.frictions[i].impulse = impulses[ii],
             }
         }
     }
@@ -3,13 +3,15 @@ use crate::dynamics::solver::joint_constraint::joint_velocity_constraint::{
 };
 use crate::dynamics::solver::joint_constraint::SolverBody;
 use crate::dynamics::solver::MotorParameters;
-use crate::dynamics::{IntegrationParameters, JointIndex, JointLimits};
-use crate::math::{AngVector, Isometry, Matrix, Point, Real, Rotation, Vector, ANG_DIM, DIM};
+use crate::dynamics::{IntegrationParameters, JointIndex, RigidBodyVelocity};
+use crate::math::{
+    AngVector, Isometry, Matrix, Point, Real, Rotation, SpacialVector, Vector, ANG_DIM, DIM,
+};
 use crate::utils::{IndexMut2, WCrossMatrix, WDot, WQuat, WReal};
 use na::SMatrix;
 
 #[cfg(feature = "dim3")]
-use crate::utils::WBasis;
+use crate::{dynamics::GenericJoint, utils::WBasis};
 
 #[derive(Debug, Copy, Clone)]
 pub struct JointVelocityConstraintBuilder<N: WReal> {
@@ -20,6 +22,8 @@ pub struct JointVelocityConstraintBuilder<N: WReal> {
     pub ang_basis: SMatrix<N, ANG_DIM, ANG_DIM>,
     pub lin_err: Vector<N>,
     pub ang_err: Rotation<N>,
+    pub erp_inv_dt: N,
+    pub cfm_coeff: N,
 }
 
 impl<N: WReal> JointVelocityConstraintBuilder<N> {
@@ -29,6 +33,8 @@ impl<N: WReal> JointVelocityConstraintBuilder<N> {
         world_com1: &Point<N>,
         world_com2: &Point<N>,
         locked_lin_axes: u8,
+        erp_inv_dt: N,
+        cfm_coeff: N,
     ) -> Self {
         let mut frame1 = *frame1;
         let basis = frame1.rotation.to_rotation_matrix().into_inner();
@@ -76,12 +82,13 @@ impl<N: WReal> JointVelocityConstraintBuilder<N> {
             ang_basis,
             lin_err,
             ang_err,
+            erp_inv_dt,
+            cfm_coeff,
         }
     }
 
     pub fn limit_linear<const LANES: usize>(
         &self,
-        params: &IntegrationParameters,
         joint_id: [JointIndex; LANES],
         body1: &SolverBody<N, LANES>,
         body2: &SolverBody<N, LANES>,
@@ -90,15 +97,14 @@ impl<N: WReal> JointVelocityConstraintBuilder<N> {
         writeback_id: WritebackId,
     ) -> JointVelocityConstraint<N, LANES> {
         let zero = N::zero();
-        let mut constraint =
-            self.lock_linear(params, joint_id, body1, body2, limited_axis, writeback_id);
+        let mut constraint = self.lock_linear(joint_id, body1, body2, limited_axis, writeback_id);
 
         let dist = self.lin_err.dot(&constraint.lin_jac);
         let min_enabled = dist.simd_lt(limits[0]);
         let max_enabled = limits[1].simd_lt(dist);
 
-        let erp_inv_dt = N::splat(params.joint_erp_inv_dt());
-        let cfm_coeff = N::splat(params.joint_cfm_coeff());
+        let erp_inv_dt = self.erp_inv_dt;
+        let cfm_coeff = self.cfm_coeff;
         let rhs_bias =
             ((dist - limits[1]).simd_max(zero) - (limits[0] - dist).simd_max(zero)) * erp_inv_dt;
         constraint.rhs = constraint.rhs_wo_bias + rhs_bias;
@@ -111,24 +117,21 @@ impl<N: WReal> JointVelocityConstraintBuilder<N> {
         constraint
     }
 
-    pub fn limit_linear_coupled<const LANES: usize>(
+    /// The jacobians of the distance between the two joint anchors, projected on the
+    /// coupled linear axes.
+    ///
+    /// Returns the unit linear jacobian, the angular jacobians of both bodies (not yet
+    /// multiplied by the square root of their inverse angular inertia), and the distance.
+    pub fn linear_coupled_jacobians(
         &self,
-        params: &IntegrationParameters,
-        joint_id: [JointIndex; LANES],
-        body1: &SolverBody<N, LANES>,
-        body2: &SolverBody<N, LANES>,
-        limited_coupled_axes: u8,
-        limits: &[JointLimits<N>],
-        writeback_id: WritebackId,
-    ) -> JointVelocityConstraint<N, LANES> {
-        let zero = N::zero();
+        coupled_axes: u8,
+    ) -> (Vector<N>, AngVector<N>, AngVector<N>, N) {
         let mut lin_jac = Vector::zeros();
         let mut ang_jac1: AngVector<N> = na::zero();
         let mut ang_jac2: AngVector<N> = na::zero();
-        let mut limit = N::zero();
 
         for i in 0..DIM {
-            if limited_coupled_axes & (1 << i) != 0 {
+            if coupled_axes & (1 << i) != 0 {
                 let coeff = self.basis.column(i).dot(&self.lin_err);
                 lin_jac += self.basis.column(i) * coeff;
                 #[cfg(feature = "dim2")]
@@ -141,29 +144,50 @@ impl<N: WReal> JointVelocityConstraintBuilder<N> {
                     ang_jac1 += self.cmat1_basis.column(i) * coeff;
                     ang_jac2 += self.cmat2_basis.column(i) * coeff;
                 }
-                limit += limits[i].max * limits[i].max;
             }
         }
 
-        limit = limit.simd_sqrt();
         let dist = lin_jac.norm();
         let inv_dist = crate::utils::simd_inv(dist);
         lin_jac *= inv_dist;
         ang_jac1 *= inv_dist;
         ang_jac2 *= inv_dist;
 
+        (lin_jac, ang_jac1, ang_jac2, dist)
+    }
+
+    pub fn limit_linear_coupled<const LANES: usize>(
+        &self,
+        joint_id: [JointIndex; LANES],
+        body1: &SolverBody<N, LANES>,
+        body2: &SolverBody<N, LANES>,
+        coupled_axes: u8,
+        limits: [N; 2],
+        writeback_id: WritebackId,
+    ) -> JointVelocityConstraint<N, LANES> {
+        let zero = N::zero();
+        let (lin_jac, mut ang_jac1, mut ang_jac2, dist) =
+            self.linear_coupled_jacobians(coupled_axes);
+
         let dvel = lin_jac.dot(&(body2.linvel - body1.linvel))
             + (ang_jac2.gdot(body2.angvel) - ang_jac1.gdot(body1.angvel));
-        let rhs_wo_bias = dvel + (dist - limit).simd_min(zero) * N::splat(params.inv_dt());
+        let rhs_wo_bias = dvel;
 
         ang_jac1 = body1.sqrt_ii * ang_jac1;
         ang_jac2 = body2.sqrt_ii * ang_jac2;
 
-        let erp_inv_dt = N::splat(params.joint_erp_inv_dt());
-        let cfm_coeff = N::splat(params.joint_cfm_coeff());
-        let rhs_bias = (dist - limit).simd_max(zero) * erp_inv_dt;
+        let min_enabled = dist.simd_lt(limits[0]);
+        let max_enabled = limits[1].simd_lt(dist);
+
+        let erp_inv_dt = self.erp_inv_dt;
+        let cfm_coeff = self.cfm_coeff;
+        let rhs_bias =
+            ((dist - limits[1]).simd_max(zero) - (limits[0] - dist).simd_max(zero)) * erp_inv_dt;
         let rhs = rhs_wo_bias + rhs_bias;
-        let impulse_bounds = [N::zero(), N::splat(Real::INFINITY)];
+        let impulse_bounds = [
+            N::splat(-Real::INFINITY).select(min_enabled, zero),
+            N::splat(Real::INFINITY).select(max_enabled, zero),
+        ];
 
         JointVelocityConstraint {
             joint_id,
@@ -185,6 +209,59 @@ impl<N: WReal> JointVelocityConstraintBuilder<N> {
         }
     }
 
+    pub fn motor_linear_coupled<const LANES: usize>(
+        &self,
+        params: &IntegrationParameters,
+        joint_id: [JointIndex; LANES],
+        body1: &SolverBody<N, LANES>,
+        body2: &SolverBody<N, LANES>,
+        coupled_axes: u8,
+        motor_params: &MotorParameters<N>,
+        limits: Option<[N; 2]>,
+        writeback_id: WritebackId,
+    ) -> JointVelocityConstraint<N, LANES> {
+        let inv_dt = N::splat(params.inv_dt());
+        let (lin_jac, mut ang_jac1, mut ang_jac2, dist) =
+            self.linear_coupled_jacobians(coupled_axes);
+
+        let mut rhs_wo_bias = N::zero();
+        if motor_params.erp_inv_dt != N::zero() {
+            rhs_wo_bias += (dist - motor_params.target_pos) * motor_params.erp_inv_dt;
+        }
+
+        let mut target_vel = motor_params.target_vel;
+        if let Some(limits) = limits {
+            target_vel =
+                target_vel.simd_clamp((limits[0] - dist) * inv_dt, (limits[1] - dist) * inv_dt);
+        };
+
+        let dvel = lin_jac.dot(&(body2.linvel - body1.linvel))
+            + (ang_jac2.gdot(body2.angvel) - ang_jac1.gdot(body1.angvel));
+        rhs_wo_bias += dvel - target_vel;
+
+        ang_jac1 = body1.sqrt_ii * ang_jac1;
+        ang_jac2 = body2.sqrt_ii * ang_jac2;
+
+        JointVelocityConstraint {
+            joint_id,
+            mj_lambda1: body1.mj_lambda,
+            mj_lambda2: body2.mj_lambda,
+            im1: body1.im,
+            im2: body2.im,
+            impulse: N::zero(),
+            impulse_bounds: motor_params.impulse_bounds(dvel),
+            lin_jac,
+            ang_jac1,
+            ang_jac2,
+            inv_lhs: N::zero(), // Will be set during ortogonalization.
+            cfm_coeff: motor_params.cfm_coeff,
+            cfm_gain: motor_params.cfm_gain,
+            rhs: rhs_wo_bias,
+            rhs_wo_bias,
+            writeback_id,
+        }
+    }
+
     pub fn motor_linear<const LANES: usize>(
         &self,
         params: &IntegrationParameters,
@@ -197,8 +274,7 @@ impl<N: WReal> JointVelocityConstraintBuilder<N> {
         writeback_id: WritebackId,
     ) -> JointVelocityConstraint<N, LANES> {
         let inv_dt = N::splat(params.inv_dt());
-        let mut constraint =
-            self.lock_linear(params, joint_id, body1, body2, motor_axis, writeback_id);
+        let mut constraint = self.lock_linear(joint_id, body1, body2, motor_axis, writeback_id);
 
         let mut rhs_wo_bias = N::zero();
         if motor_params.erp_inv_dt != N::zero() {
@@ -219,7 +295,7 @@ impl<N: WReal> JointVelocityConstraintBuilder<N> {
 
         constraint.cfm_coeff = motor_params.cfm_coeff;
         constraint.cfm_gain = motor_params.cfm_gain;
-        constraint.impulse_bounds = [-motor_params.max_impulse, motor_params.max_impulse];
+        constraint.impulse_bounds = motor_params.impulse_bounds(dvel);
         constraint.rhs = rhs_wo_bias;
         constraint.rhs_wo_bias = rhs_wo_bias;
         constraint
@@ -227,7 +303,6 @@ impl<N: WReal> JointVelocityConstraintBuilder<N> {
 
     pub fn lock_linear<const LANES: usize>(
         &self,
-        params: &IntegrationParameters,
         joint_id: [JointIndex; LANES],
         body1: &SolverBody<N, LANES>,
         body2: &SolverBody<N, LANES>,
@@ -248,8 +323,8 @@ impl<N: WReal> JointVelocityConstraintBuilder<N> {
             + (ang_jac2.gdot(body2.angvel) - ang_jac1.gdot(body1.angvel));
         let rhs_wo_bias = dvel;
 
-        let erp_inv_dt = N::splat(params.joint_erp_inv_dt());
-        let cfm_coeff = N::splat(params.joint_cfm_coeff());
+        let erp_inv_dt = self.erp_inv_dt;
+        let cfm_coeff = self.cfm_coeff;
         let rhs_bias = lin_jac.dot(&self.lin_err) * erp_inv_dt;
 
         ang_jac1 = body1.sqrt_ii * ang_jac1;
@@ -277,7 +352,6 @@ impl<N: WReal> JointVelocityConstraintBuilder<N> {
 
     pub fn limit_angular<const LANES: usize>(
         &self,
-        params: &IntegrationParameters,
         joint_id: [JointIndex; LANES],
         body1: &SolverBody<N, LANES>,
         body2: &SolverBody<N, LANES>,
@@ -307,8 +381,8 @@ impl<N: WReal> JointVelocityConstraintBuilder<N> {
         let dvel = ang_jac.gdot(body2.angvel) - ang_jac.gdot(body1.angvel);
         let rhs_wo_bias = dvel;
 
-        let erp_inv_dt = N::splat(params.joint_erp_inv_dt());
-        let cfm_coeff = N::splat(params.joint_cfm_coeff());
+        let erp_inv_dt = self.erp_inv_dt;
+        let cfm_coeff = self.cfm_coeff;
         let rhs_bias = ((s_ang - s_limits[1]).simd_max(zero)
             - (s_limits[0] - s_ang).simd_max(zero))
             * erp_inv_dt;
@@ -374,7 +448,7 @@ impl<N: WReal> JointVelocityConstraintBuilder<N> {
             im1: body1.im,
             im2: body2.im,
             impulse: N::zero(),
-            impulse_bounds: [-motor_params.max_impulse, motor_params.max_impulse],
+            impulse_bounds: motor_params.impulse_bounds(dvel),
             lin_jac: na::zero(),
             ang_jac1,
             ang_jac2,
@@ -389,7 +463,6 @@ impl<N: WReal> JointVelocityConstraintBuilder<N> {
 
     pub fn lock_angular<const LANES: usize>(
         &self,
-        params: &IntegrationParameters,
         joint_id: [JointIndex; LANES],
         body1: &SolverBody<N, LANES>,
         body2: &SolverBody<N, LANES>,
@@ -404,8 +477,8 @@ impl<N: WReal> JointVelocityConstraintBuilder<N> {
         let dvel = ang_jac.gdot(body2.angvel) - ang_jac.gdot(body1.angvel);
         let rhs_wo_bias = dvel;
 
-        let erp_inv_dt = N::splat(params.joint_erp_inv_dt());
-        let cfm_coeff = N::splat(params.joint_cfm_coeff());
+        let erp_inv_dt = self.erp_inv_dt;
+        let cfm_coeff = self.cfm_coeff;
         #[cfg(feature = "dim2")]
         let rhs_bias = self.ang_err.im * erp_inv_dt;
         #[cfg(feature = "dim3")]
@@ -483,7 +556,6 @@ impl<N: WReal> JointVelocityConstraintBuilder<N> {
 
     pub fn limit_linear_ground<const LANES: usize>(
         &self,
-        params: &IntegrationParameters,
         joint_id: [JointIndex; LANES],
         body1: &SolverBody<N, LANES>,
         body2: &SolverBody<N, LANES>,
@@ -513,8 +585,8 @@ impl<N: WReal> JointVelocityConstraintBuilder<N> {
             + (ang_jac2.gdot(body2.angvel) - ang_jac1.gdot(body1.angvel));
         let rhs_wo_bias = dvel;
 
-        let erp_inv_dt = N::splat(params.joint_erp_inv_dt());
-        let cfm_coeff = N::splat(params.joint_cfm_coeff());
+        let erp_inv_dt = self.erp_inv_dt;
+        let cfm_coeff = self.cfm_coeff;
         let rhs_bias =
             ((dist - limits[1]).simd_max(zero) - (limits[0] - dist).simd_max(zero)) * erp_inv_dt;
 
@@ -539,56 +611,34 @@ impl<N: WReal> JointVelocityConstraintBuilder<N> {
 
     pub fn limit_linear_coupled_ground<const LANES: usize>(
         &self,
-        params: &IntegrationParameters,
         joint_id: [JointIndex; LANES],
         body1: &SolverBody<N, LANES>,
         body2: &SolverBody<N, LANES>,
-        limited_coupled_axes: u8,
-        limits: &[JointLimits<N>],
+        coupled_axes: u8,
+        limits: [N; 2],
         writeback_id: WritebackId,
     ) -> JointVelocityGroundConstraint<N, LANES> {
         let zero = N::zero();
-        let mut lin_jac = Vector::zeros();
-        let mut ang_jac1: AngVector<N> = na::zero();
-        let mut ang_jac2: AngVector<N> = na::zero();
-        let mut limit = N::zero();
-
-        for i in 0..DIM {
-            if limited_coupled_axes & (1 << i) != 0 {
-                let coeff = self.basis.column(i).dot(&self.lin_err);
-                lin_jac += self.basis.column(i) * coeff;
-                #[cfg(feature = "dim2")]
-                {
-                    ang_jac1 += self.cmat1_basis[i] * coeff;
-                    ang_jac2 += self.cmat2_basis[i] * coeff;
-                }
-                #[cfg(feature = "dim3")]
-                {
-                    ang_jac1 += self.cmat1_basis.column(i) * coeff;
-                    ang_jac2 += self.cmat2_basis.column(i) * coeff;
-                }
-                limit += limits[i].max * limits[i].max;
-            }
-        }
-
-        limit = limit.simd_sqrt();
-        let dist = lin_jac.norm();
-        let inv_dist = crate::utils::simd_inv(dist);
-        lin_jac *= inv_dist;
-        ang_jac1 *= inv_dist;
-        ang_jac2 *= inv_dist;
+        let (lin_jac, ang_jac1, mut ang_jac2, dist) = self.linear_coupled_jacobians(coupled_axes);
 
         let dvel = lin_jac.dot(&(body2.linvel - body1.linvel))
             + (ang_jac2.gdot(body2.angvel) - ang_jac1.gdot(body1.angvel));
-        let rhs_wo_bias = dvel + (dist - limit).simd_min(zero) * N::splat(params.inv_dt());
+        let rhs_wo_bias = dvel;
 
         ang_jac2 = body2.sqrt_ii * ang_jac2;
 
-        let erp_inv_dt = N::splat(params.joint_erp_inv_dt());
-        let cfm_coeff = N::splat(params.joint_cfm_coeff());
-        let rhs_bias = (dist - limit).simd_max(zero) * erp_inv_dt;
+        let min_enabled = dist.simd_lt(limits[0]);
+        let max_enabled = limits[1].simd_lt(dist);
+
+        let erp_inv_dt = self.erp_inv_dt;
+        let cfm_coeff = self.cfm_coeff;
+        let rhs_bias =
+            ((dist - limits[1]).simd_max(zero) - (limits[0] - dist).simd_max(zero)) * erp_inv_dt;
         let rhs = rhs_wo_bias + rhs_bias;
-        let impulse_bounds = [N::zero(), N::splat(Real::INFINITY)];
+        let impulse_bounds = [
+            N::splat(-Real::INFINITY).select(min_enabled, zero),
+            N::splat(Real::INFINITY).select(max_enabled, zero),
+        ];
 
         JointVelocityGroundConstraint {
             joint_id,
@@ -651,7 +701,7 @@ impl<N: WReal> JointVelocityConstraintBuilder<N> {
             mj_lambda2: body2.mj_lambda,
             im2: body2.im,
             impulse: N::zero(),
-            impulse_bounds: [-motor_params.max_impulse, motor_params.max_impulse],
+            impulse_bounds: motor_params.impulse_bounds(dvel),
             lin_jac,
             ang_jac2,
             inv_lhs: N::zero(), // Will be set during ortogonalization.
@@ -663,80 +713,56 @@ impl<N: WReal> JointVelocityConstraintBuilder<N> {
         }
     }
 
-    // pub fn motor_linear_coupled_ground<const LANES: usize>(
-    //     &self,
-    //     _joint_id: [JointIndex; LANES],
-    //     _body1: &SolverBody<N, LANES>,
-    //     _body2: &SolverBody<N, LANES>,
-    //     _motor_coupled_axes: u8,
-    //     _motors: &[MotorParameters<N>],
-    //     _limited_coupled_axes: u8,
-    //     _limits: &[JointLimits<N>],
-    //     _writeback_id: WritebackId,
-    // ) -> JointVelocityGroundConstraint<N, LANES> {
-    //     let zero = N::zero();
-    //     let mut lin_jac = Vector::zeros();
-    //     let mut ang_jac1: AngVector<N> = na::zero();
-    //     let mut ang_jac2: AngVector<N> = na::zero();
-    //     let mut limit = N::zero();
-
-    //     for i in 0..DIM {
-    //         if limited_coupled_axes & (1 << i) != 0 {
-    //             let coeff = self.basis.column(i).dot(&self.lin_err);
-    //             lin_jac += self.basis.column(i) * coeff;
-    //             #[cfg(feature = "dim2")]
-    //             {
-    //                 ang_jac1 += self.cmat1_basis[i] * coeff;
-    //                 ang_jac2 += self.cmat2_basis[i] * coeff;
-    //             }
-    //             #[cfg(feature = "dim3")]
-    //             {
-    //                 ang_jac1 += self.cmat1_basis.column(i) * coeff;
-    //                 ang_jac2 += self.cmat2_basis.column(i) * coeff;
-    //             }
-    //             limit += limits[i].max * limits[i].max;
-    //         }
-    //     }
-
-    //     limit = limit.simd_sqrt();
-    //     let dist = lin_jac.norm();
-    //     let inv_dist = crate::utils::simd_inv(dist);
-    //     lin_jac *= inv_dist;
-    //     ang_jac1 *= inv_dist;
-    //     ang_jac2 *= inv_dist;
-
-    //     let dvel = lin_jac.dot(&(body2.linvel - body1.linvel))
-    //         + (ang_jac2.gdot(body2.angvel) - ang_jac1.gdot(body1.angvel));
-    //     let rhs_wo_bias = dvel + (dist - limit).simd_min(zero) * N::splat(params.inv_dt());
-
-    //     ang_jac2 = body2.sqrt_ii * ang_jac2;
-
-    //     let erp_inv_dt = N::splat(params.joint_erp_inv_dt());
-    //     let cfm_coeff = N::splat(params.joint_cfm_coeff());
-    //     let rhs_bias = (dist - limit).simd_max(zero) * erp_inv_dt;
-    //     let rhs = rhs_wo_bias + rhs_bias;
-    //     let impulse_bounds = [N::zero(), N::splat(Real::INFINITY)];
-
-    //     JointVelocityGroundConstraint {
-    //         joint_id,
-    //         mj_lambda2: body2.mj_lambda,
-    //         im2: body2.im,
-    //         impulse: N::zero(),
-    //         impulse_bounds,
-    //         lin_jac,
-    //         ang_jac2,
-    //         inv_lhs: N::zero(), // Will be set during ortogonalization.
-    //         cfm_coeff,
-    //         cfm_gain: N::zero(),
-    //         rhs,
-    //         rhs_wo_bias,
-    //         writeback_id,
-    //     }
-    // }
+    pub fn motor_linear_coupled_ground<const LANES: usize>(
+        &self,
+        params: &IntegrationParameters,
+        joint_id: [JointIndex; LANES],
+        body1: &SolverBody<N, LANES>,
+        body2: &SolverBody<N, LANES>,
+        coupled_axes: u8,
+        motor_params: &MotorParameters<N>,
+        limits: Option<[N; 2]>,
+        writeback_id: WritebackId,
+    ) -> JointVelocityGroundConstraint<N, LANES> {
+        let inv_dt = N::splat(params.inv_dt());
+        let (lin_jac, ang_jac1, mut ang_jac2, dist) = self.linear_coupled_jacobians(coupled_axes);
+
+        let mut rhs_wo_bias = N::zero();
+        if motor_params.erp_inv_dt != N::zero() {
+            rhs_wo_bias += (dist - motor_params.target_pos) * motor_params.erp_inv_dt;
+        }
+
+        let mut target_vel = motor_params.target_vel;
+        if let Some(limits) = limits {
+            target_vel =
+                target_vel.simd_clamp((limits[0] - dist) * inv_dt, (limits[1] - dist) * inv_dt);
+        };
+
+        let dvel = lin_jac.dot(&(body2.linvel - body1.linvel))
+            + (ang_jac2.gdot(body2.angvel) - ang_jac1.gdot(body1.angvel));
+        rhs_wo_bias += dvel - target_vel;
+
+        ang_jac2 = body2.sqrt_ii * ang_jac2;
+
+        JointVelocityGroundConstraint {
+            joint_id,
+            mj_lambda2: body2.mj_lambda,
+            im2: body2.im,
+            impulse: N::zero(),
+            impulse_bounds: motor_params.impulse_bounds(dvel),
+            lin_jac,
+            ang_jac2,
+            inv_lhs: N::zero(), // Will be set during ortogonalization.
+            cfm_coeff: motor_params.cfm_coeff,
+            cfm_gain: motor_params.cfm_gain,
+            rhs: rhs_wo_bias,
+            rhs_wo_bias,
+            writeback_id,
+        }
+    }
 
     pub fn lock_linear_ground<const LANES: usize>(
         &self,
-        params: &IntegrationParameters,
         joint_id: [JointIndex; LANES],
         body1: &SolverBody<N, LANES>,
         body2: &SolverBody<N, LANES>,
@@ -754,8 +780,8 @@ impl<N: WReal> JointVelocityConstraintBuilder<N> {
             + (ang_jac2.gdot(body2.angvel) - ang_jac1.gdot(body1.angvel));
         let rhs_wo_bias = dvel;
 
-        let erp_inv_dt = N::splat(params.joint_erp_inv_dt());
-        let cfm_coeff = N::splat(params.joint_cfm_coeff());
+        let erp_inv_dt = self.erp_inv_dt;
+        let cfm_coeff = self.cfm_coeff;
         let rhs_bias = lin_jac.dot(&self.lin_err) * erp_inv_dt;
 
         ang_jac2 = body2.sqrt_ii * ang_jac2;
@@ -812,7 +838,7 @@ impl<N: WReal> JointVelocityConstraintBuilder<N> {
             mj_lambda2: body2.mj_lambda,
             im2: body2.im,
             impulse: N::zero(),
-            impulse_bounds: [-motor_params.max_impulse, motor_params.max_impulse],
+            impulse_bounds: motor_params.impulse_bounds(dvel),
             lin_jac: na::zero(),
             ang_jac2,
             inv_lhs: N::zero(), // Will be set during ortogonalization.
@@ -826,7 +852,6 @@ impl<N: WReal> JointVelocityConstraintBuilder<N> {
 
     pub fn limit_angular_ground<const LANES: usize>(
         &self,
-        params: &IntegrationParameters,
         joint_id: [JointIndex; LANES],
         body1: &SolverBody<N, LANES>,
         body2: &SolverBody<N, LANES>,
@@ -856,8 +881,8 @@ impl<N: WReal> JointVelocityConstraintBuilder<N> {
         let dvel = ang_jac.gdot(body2.angvel) - ang_jac.gdot(body1.angvel);
         let rhs_wo_bias = dvel;
 
-        let erp_inv_dt = N::splat(params.joint_erp_inv_dt());
-        let cfm_coeff = N::splat(params.joint_cfm_coeff());
+        let erp_inv_dt = self.erp_inv_dt;
+        let cfm_coeff = self.cfm_coeff;
         let rhs_bias = ((s_ang - s_limits[1]).simd_max(zero)
             - (s_limits[0] - s_ang).simd_max(zero))
             * erp_inv_dt;
@@ -883,7 +908,6 @@ impl<N: WReal> JointVelocityConstraintBuilder<N> {
 
     pub fn lock_angular_ground<const LANES: usize>(
         &self,
-        params: &IntegrationParameters,
         joint_id: [JointIndex; LANES],
         body1: &SolverBody<N, LANES>,
         body2: &SolverBody<N, LANES>,
@@ -897,8 +921,8 @@ impl<N: WReal> JointVelocityConstraintBuilder<N> {
         let dvel = ang_jac.gdot(body2.angvel) - ang_jac.gdot(body1.angvel);
         let rhs_wo_bias = dvel;
 
-        let erp_inv_dt = N::splat(params.joint_erp_inv_dt());
-        let cfm_coeff = N::splat(params.joint_cfm_coeff());
+        let erp_inv_dt = self.erp_inv_dt;
+        let cfm_coeff = self.cfm_coeff;
         #[cfg(feature = "dim2")]
         let rhs_bias = self.ang_err.im * erp_inv_dt;
         #[cfg(feature = "dim3")]
@@ -971,59 +995,112 @@ impl<N: WReal> JointVelocityConstraintBuilder<N> {
 }
 
 impl JointVelocityConstraintBuilder<Real> {
+    /// The current coordinates of the joint along each of its degrees of freedom.
+    ///
+    /// The linear coordinates are the components of the translation from the first frame
+    /// to the second frame, expressed in the first frame. The angular coordinates are the
+    /// angles extracted from the relative rotation between both frames.
+    pub fn coordinates(&self) -> SpacialVector<Real> {
+        let mut result = SpacialVector::zeros();
+
+        for i in 0..DIM {
+            result[i] = self.lin_err.dot(&self.basis.column(i));
+        }
+
+        #[cfg(feature = "dim2")]
+        {
+            result[DIM] = self.ang_err.angle();
+        }
+
+        #[cfg(feature = "dim3")]
+        for i in 0..ANG_DIM {
+            result[DIM + i] = self.ang_err.imag()[i].clamp(-1.0, 1.0).asin() * 2.0;
+        }
+
+        result
+    }
+
+    /// The current velocities of the joint along each of its degrees of freedom.
+    ///
+    /// These are the relative velocities seen by the limits and motors of the joint.
+    pub fn velocities(
+        &self,
+        vels1: &RigidBodyVelocity,
+        vels2: &RigidBodyVelocity,
+    ) -> SpacialVector<Real> {
+        let mut result = SpacialVector::zeros();
+
+        for i in 0..DIM {
+            #[cfg(feature = "dim2")]
+            let (ang_jac1, ang_jac2) = (self.cmat1_basis[i], self.cmat2_basis[i]);
+            #[cfg(feature = "dim3")]
+            let (ang_jac1, ang_jac2) = (
+                self.cmat1_basis.column(i).into_owned(),
+                self.cmat2_basis.column(i).into_owned(),
+            );
+
+            result[i] = self.basis.column(i).dot(&(vels2.linvel - vels1.linvel))
+                + ang_jac2.gdot(vels2.angvel)
+                - ang_jac1.gdot(vels1.angvel);
+        }
+
+        #[cfg(feature = "dim2")]
+        {
+            result[DIM] = vels2.angvel - vels1.angvel;
+        }
+
+        #[cfg(feature = "dim3")]
+        for i in 0..ANG_DIM {
+            result[DIM + i] = self.basis.column(i).dot(&(vels2.angvel - vels1.angvel));
+        }
+
+        result
+    }
+
+    /// The swing axis, swing angle, and maximum swing angle of the cone-twist limit
+    /// of `joint`.
+    ///
+    /// The swing is the rotation bringing the twist axis of the first frame to the twist
+    /// axis of the second frame.
+    #[cfg(feature = "dim3")]
+    pub fn swing_limit(&self, joint: &GenericJoint) -> (Vector<Real>, Real, Real) {
+        let twist_axis = joint.cone_twist_axis();
+        let axis1 = self.basis.column(twist_axis).into_owned();
+        let axis2 = self.basis2.column(twist_axis).into_owned();
+
+        let rot = Rotation::rotation_between(&axis1, &axis2).unwrap_or_else(Rotation::identity);
+        let (swing_axis, angle) = rot
+            .axis_angle()
+            .map(|(axis, angle)| (axis.into_inner(), angle))
+            .unwrap_or_else(|| (axis1.orthonormal_basis()[0], 0.0));
+        let max_angle = joint.max_swing_angle(&self.basis.tr_mul(&swing_axis));
+
+        (swing_axis, angle, max_angle)
+    }
+
     // TODO: this method is almost identical to the ground version, except for the
     //       return type. Could they share their implementation somehow?
     #[cfg(feature = "dim3")]
     pub fn limit_angular_coupled(
         &self,
-        params: &IntegrationParameters,
         joint_id: [JointIndex; 1],
         body1: &SolverBody<Real, 1>,
         body2: &SolverBody<Real, 1>,
-        limited_coupled_axes: u8,
-        limits: &[JointLimits<Real>],
+        joint: &GenericJoint,
         writeback_id: WritebackId,
     ) -> JointVelocityConstraint<Real, 1> {
-        // NOTE: right now, this only supports exactly 2 coupled axes.
-        let ang_coupled_axes = limited_coupled_axes >> DIM;
-        assert_eq!(ang_coupled_axes.count_ones(), 2);
-        let not_coupled_index = ang_coupled_axes.trailing_ones() as usize;
-        let axis1 = self.basis.column(not_coupled_index).into_owned();
-        let axis2 = self.basis2.column(not_coupled_index).into_owned();
+        let (ang_jac, angle, max_angle) = self.swing_limit(joint);
 
-        let rot = Rotation::rotation_between(&axis1, &axis2).unwrap_or_else(Rotation::identity);
-        let (ang_jac, angle) = rot
-            .axis_angle()
-            .map(|(axis, angle)| (axis.into_inner(), angle))
-            .unwrap_or_else(|| (axis1.orthonormal_basis()[0], 0.0));
-        let mut ang_limits = [0.0, 0.0];
-
-        for k in 0..3 {
-            if (ang_coupled_axes & (1 << k)) != 0 {
-                let limit = &limits[DIM + k];
-                ang_limits[0] += limit.min * limit.min;
-                ang_limits[1] += limit.max * limit.max;
-            }
-        }
-
-        ang_limits[0] = ang_limits[0].sqrt();
-        ang_limits[1] = ang_limits[1].sqrt();
-
-        let min_enabled = angle <= ang_limits[0];
-        let max_enabled = ang_limits[1] <= angle;
-
-        let impulse_bounds = [
-            if min_enabled { -Real::INFINITY } else { 0.0 },
-            if max_enabled { Real::INFINITY } else { 0.0 },
-        ];
+        // The swing angle is never negative so only the maximum limit can be reached.
+        let max_enabled = max_angle < angle;
+        let impulse_bounds = [0.0, if max_enabled { Real::INFINITY } else { 0.0 }];
 
         let dvel = ang_jac.gdot(body2.angvel) - ang_jac.gdot(body1.angvel);
         let rhs_wo_bias = dvel;
 
-        let erp_inv_dt = params.joint_erp_inv_dt();
-        let cfm_coeff = params.joint_cfm_coeff();
-        let rhs_bias =
-            ((angle - ang_limits[1]).max(0.0) - (ang_limits[0] - angle).max(0.0)) * erp_inv_dt;
+        let erp_inv_dt = self.erp_inv_dt;
+        let cfm_coeff = self.cfm_coeff;
+        let rhs_bias = (angle - max_angle).max(0.0) * erp_inv_dt;
 
         let ang_jac1 = body1.sqrt_ii * ang_jac;
         let ang_jac2 = body2.sqrt_ii * ang_jac;
@@ -1051,54 +1128,24 @@ impl JointVelocityConstraintBuilder<Real> {
     #[cfg(feature = "dim3")]
     pub fn limit_angular_coupled_ground(
         &self,
-        params: &IntegrationParameters,
         joint_id: [JointIndex; 1],
         body1: &SolverBody<Real, 1>,
         body2: &SolverBody<Real, 1>,
-        limited_coupled_axes: u8,
-        limits: &[JointLimits<Real>],
+        joint: &GenericJoint,
         writeback_id: WritebackId,
     ) -> JointVelocityGroundConstraint<Real, 1> {
-        // NOTE: right now, this only supports exactly 2 coupled axes.
-        let ang_coupled_axes = limited_coupled_axes >> DIM;
-        assert_eq!(ang_coupled_axes.count_ones(), 2);
-        let not_coupled_index = ang_coupled_axes.trailing_ones() as usize;
-        let axis1 = self.basis.column(not_coupled_index).into_owned();
-        let axis2 = self.basis2.column(not_coupled_index).into_owned();
-
-        let rot = Rotation::rotation_between(&axis1, &axis2).unwrap_or_else(Rotation::identity);
-        let (ang_jac, angle) = rot
-            .axis_angle()
-            .map(|(axis, angle)| (axis.into_inner(), angle))
-            .unwrap_or_else(|| (axis1.orthonormal_basis()[0], 0.0));
-        let mut ang_limits = [0.0, 0.0];
-
-        for k in 0..3 {
-            if (ang_coupled_axes & (1 << k)) != 0 {
-                let limit = &limits[DIM + k];
-                ang_limits[0] += limit.min * limit.min;
-                ang_limits[1] += limit.max * limit.max;
-            }
-        }
+        let (ang_jac, angle, max_angle) = self.swing_limit(joint);
 
-        ang_limits[0] = ang_limits[0].sqrt();
-        ang_limits[1] = ang_limits[1].sqrt();
-
-        let min_enabled = angle <= ang_limits[0];
-        let max_enabled = ang_limits[1] <= angle;
-
-        let impulse_bounds = [
-            if min_enabled { -Real::INFINITY } else { 0.0 },
-            if max_enabled { Real::INFINITY } else { 0.0 },
-        ];
+        // The swing angle is never negative so only the maximum limit can be reached.
+        let max_enabled = max_angle < angle;
+        let impulse_bounds = [0.0, if max_enabled { Real::INFINITY } else { 0.0 }];
 
         let dvel = ang_jac.gdot(body2.angvel) - ang_jac.gdot(body1.angvel);
         let rhs_wo_bias = dvel;
 
-        let erp_inv_dt = params.joint_erp_inv_dt();
-        let cfm_coeff = params.joint_cfm_coeff();
-        let rhs_bias =
-            ((angle - ang_limits[1]).max(0.0) - (ang_limits[0] - angle).max(0.0)) * erp_inv_dt;
+        let erp_inv_dt = self.erp_inv_dt;
+        let cfm_coeff = self.cfm_coeff;
+        let rhs_bias = (angle - max_angle).max(0.0) * erp_inv_dt;
 
         let ang_jac2 = body2.sqrt_ii * ang_jac;
 
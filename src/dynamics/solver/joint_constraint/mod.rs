@@ -7,6 +7,7 @@ pub use joint_generic_velocity_constraint::{
 pub use joint_velocity_constraint_builder::JointVelocityConstraintBuilder;
 
 mod joint_constraint;
+mod joint_coupling_velocity_constraint;
 mod joint_generic_velocity_constraint;
 mod joint_generic_velocity_constraint_builder;
 mod joint_velocity_constraint;
@@ -62,7 +62,7 @@ impl ParallelVelocitySolver {
         // a parallel_desc_group, we have to wait util the current group is finished
         // before starting the next one.
         macro_rules! solve {
-            ($part: expr, $($solve_args: expr),*) => {
+            (@call $method: ident, $part: expr, $($solve_args: expr),*) => {
                 for group in $part.parallel_desc_groups.windows(2) {
                     let num_descs_in_group = group[1] - group[0];
                     target_num_desc += num_descs_in_group;
@@ -81,7 +81,7 @@ impl ParallelVelocitySolver {
                         };
 
                         for constraint in constraints {
-                            constraint.solve(
+                            constraint.$method(
                                 $($solve_args),*
                             );
                         }
@@ -109,8 +109,16 @@ impl ParallelVelocitySolver {
                     );
                 }
             };
+            ($part: expr, $($solve_args: expr),*) => {
+                solve!(@call solve, $part, $($solve_args),*)
+            };
         }
 
+        // Warm-start the joint constraints that carry their impulses across timesteps.
+        solve!(@call warmstart, joint_constraints, &mut self.mj_lambdas);
+        shift += joint_descs.len();
+        start_index -= joint_descs.len();
+
         /*
          * Solve constraints.
          */
@@ -361,7 +369,7 @@ impl ParallelVelocitySolver {
         crate::concurrent_loop! {
              let batch_size = thread.batch_size;
              for constraint in joint_constraints[thread.joint_writeback_index] {
-                 constraint.writeback_impulses(joints_all);
+                 constraint.writeback_impulses(joints_all, multibodies);
              }
         }
         crate::concurrent_loop! {
@@ -80,6 +80,10 @@ impl VelocitySolver {
             }
         }
 
+        for constraint in &*joint_constraints {
+            constraint.warmstart(&mut self.mj_lambdas[..]);
+        }
+
         /*
          * Solve constraints.
          */
@@ -265,7 +269,7 @@ impl VelocitySolver {
 
         // Write impulses back into the manifold structures.
         for constraint in &*joint_constraints {
-            constraint.writeback_impulses(joints_all);
+            constraint.writeback_impulses(joints_all, multibodies);
         }
 
         for constraint in &*contact_constraints {
@@ -124,6 +124,19 @@ impl Collider {
         self.co_material.restitution_combine_rule = rule;
     }
 
+    /// The total force magnitude beyond which a contact force event can be emitted.
+    pub fn contact_force_event_threshold(&self) -> Real {
+        self.co_material.contact_force_event_threshold
+    }
+
+    /// Sets the total force magnitude beyond which a contact force event can be emitted.
+    ///
+    /// Contact force events are only emitted if `ActiveEvents::CONTACT_FORCE_EVENTS` is
+    /// enabled for this collider.
+    pub fn set_contact_force_event_threshold(&mut self, threshold: Real) {
+        self.co_material.contact_force_event_threshold = threshold;
+    }
+
     /// Sets whether or not this is a sensor collider.
     pub fn set_sensor(&mut self, is_sensor: bool) {
         if is_sensor != self.is_sensor() {
@@ -316,6 +329,10 @@ pub struct ColliderBuilder {
     pub active_hooks: ActiveHooks,
     /// Events enabled for this collider.
     pub active_events: ActiveEvents,
+    /// The total force magnitude beyond which a contact force event can be emitted.
+    ///
+    /// Defaults to `Real::MAX`, i.e., no contact force event is emitted.
+    pub contact_force_event_threshold: Real,
     /// The user-data of the collider being built.
     pub user_data: u128,
     /// The collision groups for the collider being built.
@@ -343,6 +360,7 @@ impl ColliderBuilder {
             active_collision_types: ActiveCollisionTypes::default(),
             active_hooks: ActiveHooks::empty(),
             active_events: ActiveEvents::empty(),
+            contact_force_event_threshold: Real::MAX,
         }
     }
 
@@ -637,6 +655,14 @@ impl ColliderBuilder {
         self
     }
 
+    /// Sets the total force magnitude beyond which a contact force event can be emitted.
+    ///
+    /// Defaults to `Real::MAX`, i.e., no contact force event is emitted.
+    pub fn contact_force_event_threshold(mut self, threshold: Real) -> Self {
+        self.contact_force_event_threshold = threshold;
+        self
+    }
+
     /// The set of active collision types for this collider.
     pub fn active_collision_types(mut self, active_collision_types: ActiveCollisionTypes) -> Self {
         self.active_collision_types = active_collision_types;
@@ -773,6 +799,7 @@ impl ColliderBuilder {
             restitution: self.restitution,
             friction_combine_rule: self.friction_combine_rule,
             restitution_combine_rule: self.restitution_combine_rule,
+            contact_force_event_threshold: self.contact_force_event_threshold,
         };
         let co_flags = ColliderFlags {
             collision_groups: self.collision_groups,
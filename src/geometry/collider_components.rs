@@ -249,6 +249,12 @@ pub struct ColliderMaterial {
     pub friction_combine_rule: CoefficientCombineRule,
     /// The rule applied to combine the restitution coefficients of two colliders.
     pub restitution_combine_rule: CoefficientCombineRule,
+    /// The total force magnitude beyond which a contact force event can be emitted
+    /// for contacts involving this collider.
+    ///
+    /// Only taken into account if `ActiveEvents::CONTACT_FORCE_EVENTS` is enabled. Defaults
+    /// to `Real::MAX`, i.e., no contact force event is emitted until a threshold is set.
+    pub contact_force_event_threshold: Real,
 }
 
 impl ColliderMaterial {
@@ -269,6 +275,7 @@ impl Default for ColliderMaterial {
             restitution: 0.0,
             friction_combine_rule: CoefficientCombineRule::default(),
             restitution_combine_rule: CoefficientCombineRule::default(),
+            contact_force_event_threshold: Real::MAX,
         }
     }
 }
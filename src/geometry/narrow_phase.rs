@@ -4,7 +4,8 @@ use rayon::prelude::*;
 use crate::data::{BundleSet, Coarena, ComponentSet, ComponentSetMut, ComponentSetOption};
 use crate::dynamics::CoefficientCombineRule;
 use crate::dynamics::{
-    IslandManager, RigidBodyActivation, RigidBodyDominance, RigidBodyIds, RigidBodyType,
+    ImpulseJointSet, IslandManager, MultibodyJointSet, RigidBodyActivation, RigidBodyDominance,
+    RigidBodyHandle, RigidBodyIds, RigidBodyType,
 };
 use crate::geometry::{
     BroadPhasePairEvent, ColliderChanges, ColliderGraphIndex, ColliderHandle, ColliderMaterial,
@@ -199,6 +200,19 @@ impl NarrowPhase {
             .map(|c| c.2)
     }
 
+    /// The mutable contact pair involving two specific colliders.
+    pub(crate) fn contact_pair_mut(
+        &mut self,
+        collider1: ColliderHandle,
+        collider2: ColliderHandle,
+    ) -> Option<&mut ContactPair> {
+        let id1 = self.graph_indices.get(collider1.0)?;
+        let id2 = self.graph_indices.get(collider2.0)?;
+        self.contact_graph
+            .interaction_pair_mut(id1.contact_graph_index, id2.contact_graph_index)
+            .map(|c| c.2)
+    }
+
     /// The intersection pair involving two specific colliders.
     ///
     /// It is strongly recommended to use the [`NarrowPhase::intersection_pair`] method instead. This
@@ -235,6 +249,15 @@ impl NarrowPhase {
         self.contact_graph.interactions()
     }
 
+    /// All the contact pairs maintained by this narrow-phase, mutably.
+    pub(crate) fn contact_pairs_mut(&mut self) -> impl Iterator<Item = &mut ContactPair> {
+        self.contact_graph
+            .graph
+            .edges
+            .iter_mut()
+            .map(|e| &mut e.weight)
+    }
+
     /// All the intersection pairs maintained by this narrow-phase.
     pub fn intersection_pairs(
         &self,
@@ -824,11 +847,56 @@ impl NarrowPhase {
         });
     }
 
+    /// Checks that no joint linking the two given rigid-bodies has its contacts disabled.
+    fn joints_allow_contacts(
+        impulse_joints: Option<&ImpulseJointSet>,
+        multibody_joints: Option<&MultibodyJointSet>,
+        rb1: RigidBodyHandle,
+        rb2: RigidBodyHandle,
+    ) -> bool {
+        if let Some(impulse_joints) = impulse_joints {
+            if impulse_joints
+                .joints_between(rb1, rb2)
+                .any(|joint| !joint.data.contacts_enabled)
+            {
+                return false;
+            }
+        }
+
+        if let Some(multibody_joints) = multibody_joints {
+            if let (Some(link1), Some(link2)) = (
+                multibody_joints.rigid_body_link(rb1),
+                multibody_joints.rigid_body_link(rb2),
+            ) {
+                if link1.multibody == link2.multibody {
+                    if let Some(multibody) = multibody_joints.get_multibody(link1.multibody) {
+                        // Only the link directly attached to its parent by a joint is affected.
+                        let child = [(link1.id, link2.id), (link2.id, link1.id)]
+                            .iter()
+                            .find_map(|(child, parent)| {
+                                multibody
+                                    .link(*child)
+                                    .filter(|link| link.parent_id() == Some(*parent))
+                            });
+
+                        if let Some(child) = child {
+                            return child.joint.data.contacts_enabled;
+                        }
+                    }
+                }
+            }
+        }
+
+        true
+    }
+
     pub(crate) fn compute_contacts<Bodies, Colliders>(
         &mut self,
         prediction_distance: Real,
         bodies: &Bodies,
         colliders: &Colliders,
+        impulse_joints: Option<&ImpulseJointSet>,
+        multibody_joints: Option<&MultibodyJointSet>,
         modified_colliders: &[ColliderHandle],
         hooks: &dyn PhysicsHooks<Bodies, Colliders>,
         events: &dyn EventHandler,
@@ -907,6 +975,19 @@ impl NarrowPhase {
                     break 'emit_events;
                 }
 
+                // Filter based on the joints linking both rigid-bodies.
+                if let (Some(co_parent1), Some(co_parent2)) = (co_parent1, co_parent2) {
+                    if !Self::joints_allow_contacts(
+                        impulse_joints,
+                        multibody_joints,
+                        co_parent1.handle,
+                        co_parent2.handle,
+                    ) {
+                        pair.clear();
+                        break 'emit_events;
+                    }
+                }
+
                 let active_hooks = co_flags1.active_hooks | co_flags2.active_hooks;
 
                 let mut solver_flags = if active_hooks.contains(ActiveHooks::FILTER_CONTACT_PAIRS) {
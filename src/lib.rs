@@ -130,6 +130,7 @@ pub(crate) const INVALID_USIZE: usize = INVALID_U32 as usize;
 /// The string version of Rapier.
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
+pub mod control;
 pub mod counters;
 pub mod data;
 pub mod dynamics;
@@ -137,6 +138,12 @@ pub mod geometry;
 pub mod pipeline;
 pub mod utils;
 
+#[cfg(test)]
+mod test_utils;
+
+#[cfg(all(feature = "dim3", feature = "urdf", feature = "default-sets"))]
+pub mod urdf;
+
 /// Elementary mathematical entities (vectors, matrices, isometries, etc).
 pub mod math {
     pub use parry::math::*;
@@ -202,6 +209,7 @@ pub mod math {
 
 /// Prelude containing the common types defined by Rapier.
 pub mod prelude {
+    pub use crate::control::*;
     pub use crate::dynamics::*;
     pub use crate::geometry::*;
     pub use crate::math::*;
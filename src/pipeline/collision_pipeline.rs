@@ -103,6 +103,8 @@ impl CollisionPipeline {
             prediction_distance,
             bodies,
             colliders,
+            None,
+            None,
             modified_colliders,
             hooks,
             events,
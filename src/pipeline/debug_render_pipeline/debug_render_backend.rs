@@ -0,0 +1,96 @@
+use crate::dynamics::{
+    ImpulseJoint, ImpulseJointHandle, Multibody, MultibodyJointHandle, MultibodyLink, RigidBody,
+    RigidBodyHandle,
+};
+use crate::geometry::{Collider, ColliderHandle, ContactPair};
+use crate::math::{Isometry, Point, Real};
+
+/// A color, given as its red, green, blue, and alpha components in `[0, 1]`.
+pub type DebugColor = [f32; 4];
+
+/// The physics object being rendered by the `DebugRenderPipeline`.
+#[derive(Copy, Clone)]
+pub enum DebugRenderObject<'a> {
+    /// A rigid-body is being rendered.
+    RigidBody(RigidBodyHandle, &'a RigidBody),
+    /// A collider is being rendered.
+    Collider(ColliderHandle, &'a Collider),
+    /// An impulse-joint is being rendered.
+    ImpulseJoint(ImpulseJointHandle, &'a ImpulseJoint),
+    /// A multibody joint is being rendered.
+    MultibodyJoint(MultibodyJointHandle, &'a Multibody, &'a MultibodyLink),
+    /// The contacts of a contact-pair are being rendered.
+    ContactPair(&'a ContactPair, &'a Collider, &'a Collider),
+}
+
+/// Trait implemented by graphics backends responsible for rendering the physics scene.
+///
+/// The only required method is `draw_line`. The other methods can be overridden to filter
+/// out objects, to change the color of specific objects, or to draw polylines more efficiently.
+pub trait DebugRenderBackend {
+    /// Should the given object be rendered at all?
+    ///
+    /// Returns `true` by default.
+    fn filter_object(&self, _object: DebugRenderObject) -> bool {
+        true
+    }
+
+    /// The color used for rendering a part of the given object.
+    ///
+    /// The `default` color is the one selected by the `DebugRenderStyle`. Override this
+    /// method to assign a custom color to specific objects.
+    fn object_color(&self, _object: DebugRenderObject, default: DebugColor) -> DebugColor {
+        default
+    }
+
+    /// Draws a colored line between the points `a` and `b`, both given in world-space.
+    fn draw_line(
+        &mut self,
+        object: DebugRenderObject,
+        a: Point<Real>,
+        b: Point<Real>,
+        color: DebugColor,
+    );
+
+    /// Draws a set of lines.
+    ///
+    /// Each element of `indices` identifies the two endpoints of one line. The `vertices`
+    /// are given in the local-space of the object and must be transformed by `transform`.
+    fn draw_polyline(
+        &mut self,
+        object: DebugRenderObject,
+        vertices: &[Point<Real>],
+        indices: &[[u32; 2]],
+        transform: &Isometry<Real>,
+        color: DebugColor,
+    ) {
+        for idx in indices {
+            let a = transform * vertices[idx[0] as usize];
+            let b = transform * vertices[idx[1] as usize];
+            self.draw_line(object, a, b, color);
+        }
+    }
+
+    /// Draws a chain of lines joining consecutive vertices.
+    ///
+    /// If `closed` is `true`, the last vertex is joined to the first one. The `vertices`
+    /// are given in the local-space of the object and must be transformed by `transform`.
+    fn draw_line_strip(
+        &mut self,
+        object: DebugRenderObject,
+        vertices: &[Point<Real>],
+        transform: &Isometry<Real>,
+        closed: bool,
+        color: DebugColor,
+    ) {
+        for pts in vertices.windows(2) {
+            self.draw_line(object, transform * pts[0], transform * pts[1], color);
+        }
+
+        if closed && vertices.len() > 2 {
+            let a = transform * vertices[vertices.len() - 1];
+            let b = transform * vertices[0];
+            self.draw_line(object, a, b, color);
+        }
+    }
+}
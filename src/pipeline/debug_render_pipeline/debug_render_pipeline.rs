@@ -0,0 +1,671 @@
+use super::{DebugColor, DebugRenderBackend, DebugRenderObject, DebugRenderStyle};
+use crate::dynamics::{ImpulseJointSet, MultibodyJointSet, RigidBody, RigidBodySet, RigidBodyType};
+use crate::geometry::{ColliderSet, NarrowPhase, Shape, ShapeType};
+use crate::math::{Isometry, Point, Real, Vector, DIM};
+use crate::utils::WBasis;
+#[cfg(feature = "dim3")]
+use na::RealField;
+
+// The half-size of the (finite) part of a half-space that gets rendered.
+const HALFSPACE_EXTENT: Real = 1000.0;
+
+bitflags::bitflags! {
+    /// Flags indicating what part of the physics scene should be rendered by the `DebugRenderPipeline`.
+    #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+    pub struct DebugRenderMode: u32 {
+        /// If this flag is set, the outline of the collider shapes will be rendered.
+        const COLLIDER_SHAPES = 1 << 0;
+        /// If this flag is set, the local coordinate axes of each rigid-body will be rendered.
+        const RIGID_BODY_AXES = 1 << 1;
+        /// If this flag is set, the multibody joints will be rendered.
+        const MULTIBODY_JOINTS = 1 << 2;
+        /// If this flag is set, the impulse joints will be rendered.
+        const IMPULSE_JOINTS = 1 << 3;
+        /// If this flag is set, both the impulse joints and multibody joints will be rendered.
+        const JOINTS = Self::MULTIBODY_JOINTS.bits | Self::IMPULSE_JOINTS.bits;
+        /// If this flag is set, the contact points and contact normals will be rendered.
+        const CONTACTS = 1 << 4;
+        /// If this flag is set, the AABB of each collider will be rendered.
+        const COLLIDER_AABBS = 1 << 5;
+        /// If this flag is set, the center-of-mass of each rigid-body will be rendered.
+        const CENTERS_OF_MASS = 1 << 6;
+    }
+}
+
+impl Default for DebugRenderMode {
+    fn default() -> Self {
+        Self::COLLIDER_SHAPES | Self::JOINTS | Self::RIGID_BODY_AXES
+    }
+}
+
+/// Pipeline responsible for rendering the state of the physics engine for debugging purpose.
+///
+/// The actual drawing is delegated to a [`DebugRenderBackend`] which only has to be able to
+/// render colored line segments.
+#[derive(Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+pub struct DebugRenderPipeline {
+    /// The style used to compute the line colors for each element to render.
+    pub style: DebugRenderStyle,
+    /// Flags controlling what part of the physics state needs to be rendered.
+    pub mode: DebugRenderMode,
+}
+
+impl DebugRenderPipeline {
+    /// Creates a new debug-render pipeline from a given style and flags.
+    pub fn new(style: DebugRenderStyle, mode: DebugRenderMode) -> Self {
+        Self { style, mode }
+    }
+
+    /// Renders all the physics elements selected by `self.mode`.
+    pub fn render(
+        &self,
+        backend: &mut impl DebugRenderBackend,
+        bodies: &RigidBodySet,
+        colliders: &ColliderSet,
+        impulse_joints: &ImpulseJointSet,
+        multibody_joints: &MultibodyJointSet,
+        narrow_phase: &NarrowPhase,
+    ) {
+        self.render_joints(backend, bodies, impulse_joints, multibody_joints);
+        self.render_bodies(backend, bodies);
+        self.render_colliders(backend, bodies, colliders);
+        self.render_contacts(backend, colliders, narrow_phase);
+    }
+
+    /// Renders the contact points and contact normals of all the active contacts.
+    ///
+    /// Does nothing unless `self.mode` contains `DebugRenderMode::CONTACTS`.
+    pub fn render_contacts(
+        &self,
+        backend: &mut impl DebugRenderBackend,
+        colliders: &ColliderSet,
+        narrow_phase: &NarrowPhase,
+    ) {
+        if !self.mode.contains(DebugRenderMode::CONTACTS) {
+            return;
+        }
+
+        for pair in narrow_phase.contact_pairs() {
+            if !pair.has_any_active_contact {
+                continue;
+            }
+
+            let (co1, co2) = match (colliders.get(pair.collider1), colliders.get(pair.collider2)) {
+                (Some(co1), Some(co2)) => (co1, co2),
+                _ => continue,
+            };
+
+            let object = DebugRenderObject::ContactPair(pair, co1, co2);
+
+            if !backend.filter_object(object) {
+                continue;
+            }
+
+            let depth_color = backend.object_color(object, self.style.contact_depth_color);
+            let normal_color = backend.object_color(object, self.style.contact_normal_color);
+
+            for manifold in &pair.manifolds {
+                let normal = manifold.data.normal;
+
+                for contact in &manifold.data.solver_contacts {
+                    // The solver contact point lies halfway between the two original contact points.
+                    let half_depth = normal * (contact.dist / 2.0);
+                    backend.draw_line(
+                        object,
+                        contact.point - half_depth,
+                        contact.point + half_depth,
+                        depth_color,
+                    );
+                    backend.draw_line(
+                        object,
+                        contact.point,
+                        contact.point + normal * self.style.contact_normal_length,
+                        normal_color,
+                    );
+                }
+            }
+        }
+    }
+
+    /// Renders the impulse joints and multibody joints.
+    ///
+    /// Does nothing unless `self.mode` contains `DebugRenderMode::IMPULSE_JOINTS` or
+    /// `DebugRenderMode::MULTIBODY_JOINTS`.
+    pub fn render_joints(
+        &self,
+        backend: &mut impl DebugRenderBackend,
+        bodies: &RigidBodySet,
+        impulse_joints: &ImpulseJointSet,
+        multibody_joints: &MultibodyJointSet,
+    ) {
+        if self.mode.contains(DebugRenderMode::IMPULSE_JOINTS) {
+            for (handle, joint) in impulse_joints.iter() {
+                let object = DebugRenderObject::ImpulseJoint(handle, joint);
+
+                if !backend.filter_object(object) {
+                    continue;
+                }
+
+                if let (Some(body1), Some(body2)) =
+                    (bodies.get(joint.body1), bodies.get(joint.body2))
+                {
+                    self.render_joint(
+                        backend,
+                        object,
+                        body1,
+                        body2,
+                        &joint.data.local_frame1,
+                        &joint.data.local_frame2,
+                        self.style.impulse_joint_anchor_color,
+                        self.style.impulse_joint_separation_color,
+                    );
+                }
+            }
+        }
+
+        if self.mode.contains(DebugRenderMode::MULTIBODY_JOINTS) {
+            for (handle, multibody, link) in multibody_joints.iter() {
+                let object = DebugRenderObject::MultibodyJoint(handle, multibody, link);
+
+                if !backend.filter_object(object) {
+                    continue;
+                }
+
+                let parent = match link.parent_id().and_then(|id| multibody.link(id)) {
+                    Some(parent) => parent,
+                    None => continue,
+                };
+
+                if let (Some(body1), Some(body2)) = (
+                    bodies.get(parent.rigid_body_handle()),
+                    bodies.get(link.rigid_body_handle()),
+                ) {
+                    let data = &link.joint().data;
+                    self.render_joint(
+                        backend,
+                        object,
+                        body1,
+                        body2,
+                        &data.local_frame1,
+                        &data.local_frame2,
+                        self.style.multibody_joint_anchor_color,
+                        self.style.multibody_joint_separation_color,
+                    );
+                }
+            }
+        }
+    }
+
+    fn render_joint(
+        &self,
+        backend: &mut impl DebugRenderBackend,
+        object: DebugRenderObject,
+        body1: &RigidBody,
+        body2: &RigidBody,
+        frame1: &Isometry<Real>,
+        frame2: &Isometry<Real>,
+        anchor_color: DebugColor,
+        separation_color: DebugColor,
+    ) {
+        let frame1 = body1.position() * frame1;
+        let frame2 = body2.position() * frame2;
+        let anchor_color = backend.object_color(object, anchor_color);
+        let separation_color = backend.object_color(object, separation_color);
+
+        let com1 = body1.rb_mprops.world_com;
+        let com2 = body2.rb_mprops.world_com;
+        let anchor1 = Point::from(frame1.translation.vector);
+        let anchor2 = Point::from(frame2.translation.vector);
+
+        backend.draw_line(object, com1, anchor1, anchor_color);
+        backend.draw_line(object, com2, anchor2, anchor_color);
+        backend.draw_line(object, anchor1, anchor2, separation_color);
+    }
+
+    /// Renders the local coordinate axes and the center-of-mass of the rigid-bodies.
+    ///
+    /// Does nothing unless `self.mode` contains `DebugRenderMode::RIGID_BODY_AXES` or
+    /// `DebugRenderMode::CENTERS_OF_MASS`.
+    pub fn render_bodies(&self, backend: &mut impl DebugRenderBackend, bodies: &RigidBodySet) {
+        let render_axes = self.mode.contains(DebugRenderMode::RIGID_BODY_AXES);
+        let render_com = self.mode.contains(DebugRenderMode::CENTERS_OF_MASS);
+
+        if !render_axes && !render_com {
+            return;
+        }
+
+        for (handle, rb) in bodies.iter() {
+            let object = DebugRenderObject::RigidBody(handle, rb);
+
+            if !backend.filter_object(object) {
+                continue;
+            }
+
+            if render_axes && self.style.rigid_body_axes_length != 0.0 {
+                let pos = rb.position();
+                let origin = Point::from(pos.translation.vector);
+
+                for i in 0..DIM {
+                    let mut default_color = [0.0, 0.0, 0.0, 1.0];
+                    default_color[i] = 1.0;
+                    let color = backend.object_color(object, default_color);
+                    let axis = pos.rotation * Vector::ith(i, self.style.rigid_body_axes_length);
+                    backend.draw_line(object, origin, origin + axis, color);
+                }
+            }
+
+            if render_com && rb.is_dynamic() {
+                let color = backend.object_color(object, self.style.center_of_mass_color);
+                let com = rb.rb_mprops.world_com;
+
+                for i in 0..DIM {
+                    let dir = Vector::ith(i, self.style.center_of_mass_size);
+                    backend.draw_line(object, com - dir, com + dir, color);
+                }
+            }
+        }
+    }
+
+    /// Renders the shapes and the AABBs of the colliders.
+    ///
+    /// Does nothing unless `self.mode` contains `DebugRenderMode::COLLIDER_SHAPES` or
+    /// `DebugRenderMode::COLLIDER_AABBS`.
+    pub fn render_colliders(
+        &self,
+        backend: &mut impl DebugRenderBackend,
+        bodies: &RigidBodySet,
+        colliders: &ColliderSet,
+    ) {
+        let render_shapes = self.mode.contains(DebugRenderMode::COLLIDER_SHAPES);
+        let render_aabbs = self.mode.contains(DebugRenderMode::COLLIDER_AABBS);
+
+        if !render_shapes && !render_aabbs {
+            return;
+        }
+
+        for (handle, co) in colliders.iter() {
+            let object = DebugRenderObject::Collider(handle, co);
+
+            if !backend.filter_object(object) {
+                continue;
+            }
+
+            let parent = co.parent().and_then(|h| bodies.get(h));
+            let is_sleeping = parent.map(|rb| rb.is_sleeping()).unwrap_or(false);
+
+            if render_shapes {
+                let default_color = match parent.map(|rb| rb.body_type()) {
+                    Some(RigidBodyType::Dynamic) => self.style.collider_dynamic_color,
+                    Some(RigidBodyType::Fixed) => self.style.collider_fixed_color,
+                    Some(RigidBodyType::KinematicPositionBased)
+                    | Some(RigidBodyType::KinematicVelocityBased) => {
+                        self.style.collider_kinematic_color
+                    }
+                    None => self.style.collider_parentless_color,
+                };
+                let color =
+                    self.sleep_color(backend.object_color(object, default_color), is_sleeping);
+                self.render_shape(object, backend, co.shape(), co.position(), color);
+            }
+
+            if render_aabbs {
+                let aabb = co.compute_aabb();
+                let color = self.sleep_color(
+                    backend.object_color(object, self.style.collider_aabb_color),
+                    is_sleeping,
+                );
+                let (vertices, indices) = box_outline(&aabb.half_extents());
+                let transform = Isometry::new(aabb.center().coords, na::zero());
+                backend.draw_polyline(object, &vertices, &indices, &transform, color);
+            }
+        }
+    }
+
+    fn sleep_color(&self, mut color: DebugColor, is_sleeping: bool) -> DebugColor {
+        if is_sleeping {
+            for (c, m) in color
+                .iter_mut()
+                .zip(self.style.sleep_color_multiplier.iter())
+            {
+                *c *= m;
+            }
+        }
+
+        color
+    }
+
+    #[cfg(feature = "dim2")]
+    fn render_shape(
+        &self,
+        object: DebugRenderObject,
+        backend: &mut impl DebugRenderBackend,
+        shape: &dyn Shape,
+        pos: &Isometry<Real>,
+        color: DebugColor,
+    ) {
+        let nsubdivs = self.style.subdivisions;
+
+        match shape.shape_type() {
+            ShapeType::Ball => {
+                let vertices = shape.as_ball().unwrap().to_polyline(nsubdivs);
+                backend.draw_line_strip(object, &vertices, pos, true, color);
+            }
+            ShapeType::Cuboid => {
+                let (vertices, indices) = box_outline(&shape.as_cuboid().unwrap().half_extents);
+                backend.draw_polyline(object, &vertices, &indices, pos, color);
+            }
+            ShapeType::RoundCuboid => {
+                let cuboid = &shape.as_round_cuboid().unwrap().base_shape;
+                let (vertices, indices) = box_outline(&cuboid.half_extents);
+                backend.draw_polyline(object, &vertices, &indices, pos, color);
+            }
+            ShapeType::Capsule => {
+                let vertices = shape.as_capsule().unwrap().to_polyline(nsubdivs);
+                backend.draw_line_strip(object, &vertices, pos, true, color);
+            }
+            ShapeType::Segment => {
+                let s = shape.as_segment().unwrap();
+                backend.draw_line(object, pos * s.a, pos * s.b, color);
+            }
+            ShapeType::Triangle => {
+                let tri = shape.as_triangle().unwrap();
+                backend.draw_line_strip(object, &[tri.a, tri.b, tri.c], pos, true, color);
+            }
+            ShapeType::RoundTriangle => {
+                let tri = &shape.as_round_triangle().unwrap().base_shape;
+                backend.draw_line_strip(object, &[tri.a, tri.b, tri.c], pos, true, color);
+            }
+            ShapeType::TriMesh => {
+                let trimesh = shape.as_trimesh().unwrap();
+                let indices = triangle_edges(trimesh.indices());
+                backend.draw_polyline(object, trimesh.vertices(), &indices, pos, color);
+            }
+            ShapeType::Polyline => {
+                let polyline = shape.as_polyline().unwrap();
+                backend.draw_polyline(object, polyline.vertices(), polyline.indices(), pos, color);
+            }
+            ShapeType::HeightField => {
+                for s in shape.as_heightfield().unwrap().segments() {
+                    backend.draw_line(object, pos * s.a, pos * s.b, color);
+                }
+            }
+            ShapeType::ConvexPolygon => {
+                let poly = shape.as_convex_polygon().unwrap();
+                backend.draw_line_strip(object, poly.points(), pos, true, color);
+            }
+            ShapeType::RoundConvexPolygon => {
+                let poly = &shape.as_round_convex_polygon().unwrap().base_shape;
+                backend.draw_line_strip(object, poly.points(), pos, true, color);
+            }
+            ShapeType::HalfSpace => {
+                let normal = shape.as_halfspace().unwrap().normal;
+                let tangent = normal.orthonormal_basis()[0] * HALFSPACE_EXTENT;
+                let origin = Point::origin();
+                backend.draw_line(
+                    object,
+                    pos * (origin - tangent),
+                    pos * (origin + tangent),
+                    color,
+                );
+            }
+            ShapeType::Compound => {
+                for (sub_pos, sub_shape) in shape.as_compound().unwrap().shapes() {
+                    self.render_shape(object, backend, &**sub_shape, &(pos * sub_pos), color);
+                }
+            }
+            _ => self.render_shape_aabb(object, backend, shape, pos, color),
+        }
+    }
+
+    #[cfg(feature = "dim3")]
+    fn render_shape(
+        &self,
+        object: DebugRenderObject,
+        backend: &mut impl DebugRenderBackend,
+        shape: &dyn Shape,
+        pos: &Isometry<Real>,
+        color: DebugColor,
+    ) {
+        let nsubdivs = self.style.subdivisions;
+
+        match shape.shape_type() {
+            ShapeType::Ball => {
+                let radius = shape.as_ball().unwrap().radius;
+
+                for (i, j) in [(0, 1), (1, 2), (2, 0)] {
+                    let vertices = circle(radius, nsubdivs, i, j);
+                    backend.draw_line_strip(object, &vertices, pos, true, color);
+                }
+            }
+            ShapeType::Cuboid => {
+                let (vertices, indices) = box_outline(&shape.as_cuboid().unwrap().half_extents);
+                backend.draw_polyline(object, &vertices, &indices, pos, color);
+            }
+            ShapeType::RoundCuboid => {
+                let cuboid = &shape.as_round_cuboid().unwrap().base_shape;
+                let (vertices, indices) = box_outline(&cuboid.half_extents);
+                backend.draw_polyline(object, &vertices, &indices, pos, color);
+            }
+            ShapeType::Capsule => {
+                let (vertices, indices) = shape
+                    .as_capsule()
+                    .unwrap()
+                    .to_trimesh(nsubdivs, nsubdivs / 2);
+                backend.draw_polyline(object, &vertices, &triangle_edges(&indices), pos, color);
+            }
+            ShapeType::Cylinder => {
+                let (vertices, indices) = shape.as_cylinder().unwrap().to_trimesh(nsubdivs);
+                backend.draw_polyline(object, &vertices, &triangle_edges(&indices), pos, color);
+            }
+            ShapeType::RoundCylinder => {
+                let cylinder = &shape.as_round_cylinder().unwrap().base_shape;
+                let (vertices, indices) = cylinder.to_trimesh(nsubdivs);
+                backend.draw_polyline(object, &vertices, &triangle_edges(&indices), pos, color);
+            }
+            ShapeType::Cone => {
+                let (vertices, indices) = shape.as_cone().unwrap().to_trimesh(nsubdivs);
+                backend.draw_polyline(object, &vertices, &triangle_edges(&indices), pos, color);
+            }
+            ShapeType::RoundCone => {
+                let cone = &shape.as_round_cone().unwrap().base_shape;
+                let (vertices, indices) = cone.to_trimesh(nsubdivs);
+                backend.draw_polyline(object, &vertices, &triangle_edges(&indices), pos, color);
+            }
+            ShapeType::Segment => {
+                let s = shape.as_segment().unwrap();
+                backend.draw_line(object, pos * s.a, pos * s.b, color);
+            }
+            ShapeType::Triangle => {
+                let tri = shape.as_triangle().unwrap();
+                backend.draw_line_strip(object, &[tri.a, tri.b, tri.c], pos, true, color);
+            }
+            ShapeType::RoundTriangle => {
+                let tri = &shape.as_round_triangle().unwrap().base_shape;
+                backend.draw_line_strip(object, &[tri.a, tri.b, tri.c], pos, true, color);
+            }
+            ShapeType::TriMesh => {
+                let trimesh = shape.as_trimesh().unwrap();
+                let indices = triangle_edges(trimesh.indices());
+                backend.draw_polyline(object, trimesh.vertices(), &indices, pos, color);
+            }
+            ShapeType::Polyline => {
+                let polyline = shape.as_polyline().unwrap();
+                backend.draw_polyline(object, polyline.vertices(), polyline.indices(), pos, color);
+            }
+            ShapeType::HeightField => {
+                let (vertices, indices) = shape.as_heightfield().unwrap().to_trimesh();
+                backend.draw_polyline(object, &vertices, &triangle_edges(&indices), pos, color);
+            }
+            ShapeType::ConvexPolyhedron => {
+                let (vertices, indices) = shape.as_convex_polyhedron().unwrap().to_trimesh();
+                backend.draw_polyline(object, &vertices, &triangle_edges(&indices), pos, color);
+            }
+            ShapeType::RoundConvexPolyhedron => {
+                let poly = &shape.as_round_convex_polyhedron().unwrap().base_shape;
+                let (vertices, indices) = poly.to_trimesh();
+                backend.draw_polyline(object, &vertices, &triangle_edges(&indices), pos, color);
+            }
+            ShapeType::HalfSpace => {
+                let normal = shape.as_halfspace().unwrap().normal;
+                let basis = normal.orthonormal_basis();
+                let (t1, t2) = (basis[0] * HALFSPACE_EXTENT, basis[1] * HALFSPACE_EXTENT);
+                let origin = Point::origin();
+                let vertices = [
+                    origin - t1 - t2,
+                    origin + t1 - t2,
+                    origin + t1 + t2,
+                    origin - t1 + t2,
+                ];
+                backend.draw_line_strip(object, &vertices, pos, true, color);
+            }
+            ShapeType::Compound => {
+                for (sub_pos, sub_shape) in shape.as_compound().unwrap().shapes() {
+                    self.render_shape(object, backend, &**sub_shape, &(pos * sub_pos), color);
+                }
+            }
+            _ => self.render_shape_aabb(object, backend, shape, pos, color),
+        }
+    }
+
+    // Fallback for the shapes we don’t know how to outline (e.g., user-defined shapes).
+    fn render_shape_aabb(
+        &self,
+        object: DebugRenderObject,
+        backend: &mut impl DebugRenderBackend,
+        shape: &dyn Shape,
+        pos: &Isometry<Real>,
+        color: DebugColor,
+    ) {
+        let aabb = shape.compute_local_aabb();
+        let (vertices, indices) = box_outline(&aabb.half_extents());
+        let transform = pos * Isometry::new(aabb.center().coords, na::zero());
+        backend.draw_polyline(object, &vertices, &indices, &transform, color);
+    }
+}
+
+// The vertices and edges of a box centered at the origin.
+fn box_outline(half_extents: &Vector<Real>) -> (Vec<Point<Real>>, Vec<[u32; 2]>) {
+    let num_vertices = 1 << DIM;
+    let vertices = (0..num_vertices)
+        .map(|i| {
+            let mut pt = Point::origin();
+            for k in 0..DIM {
+                pt[k] = if i & (1 << k) == 0 {
+                    -half_extents[k]
+                } else {
+                    half_extents[k]
+                };
+            }
+            pt
+        })
+        .collect();
+
+    // Two vertices are linked by an edge if their index differ by exactly one bit.
+    let mut indices = vec![];
+    for i in 0..num_vertices {
+        for k in 0..DIM {
+            if i & (1 << k) == 0 {
+                indices.push([i as u32, (i | (1 << k)) as u32]);
+            }
+        }
+    }
+
+    (vertices, indices)
+}
+
+fn triangle_edges(indices: &[[u32; 3]]) -> Vec<[u32; 2]> {
+    indices
+        .iter()
+        .flat_map(|t| [[t[0], t[1]], [t[1], t[2]], [t[2], t[0]]])
+        .collect()
+}
+
+// A circle in the plane spanned by the coordinate axes `i` and `j`.
+#[cfg(feature = "dim3")]
+fn circle(radius: Real, nsubdivs: u32, i: usize, j: usize) -> Vec<Point<Real>> {
+    (0..nsubdivs)
+        .map(|k| {
+            let angle = k as Real * Real::two_pi() / nsubdivs as Real;
+            let mut pt = Point::origin();
+            pt[i] = radius * angle.cos();
+            pt[j] = radius * angle.sin();
+            pt
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{DebugRenderMode, DebugRenderPipeline};
+    use crate::dynamics::FixedJointBuilder;
+    use crate::geometry::ColliderHandle;
+    use crate::math::{Point, Real, Vector};
+    use crate::pipeline::{DebugColor, DebugRenderBackend, DebugRenderObject};
+    use crate::test_utils::TestWorld;
+
+    #[derive(Default)]
+    struct RecordingBackend {
+        collider_lines: Vec<ColliderHandle>,
+        joint_lines: Vec<(Point<Real>, Point<Real>)>,
+    }
+
+    impl DebugRenderBackend for RecordingBackend {
+        fn draw_line(
+            &mut self,
+            object: DebugRenderObject,
+            a: Point<Real>,
+            b: Point<Real>,
+            _color: DebugColor,
+        ) {
+            match object {
+                DebugRenderObject::Collider(handle, _) => self.collider_lines.push(handle),
+                DebugRenderObject::ImpulseJoint(..) => self.joint_lines.push((a, b)),
+                _ => {}
+            }
+        }
+    }
+
+    #[test]
+    fn render_collider_and_joint_lines() {
+        let mut world = TestWorld::without_gravity();
+        let ball1 = world.add_ball(Vector::zeros(), 0.5);
+        let ball2 = world.add_ball(Vector::x() * 2.0, 0.5);
+        let joint = FixedJointBuilder::new()
+            .local_anchor1(Point::from(Vector::x()))
+            .local_anchor2(Point::from(-Vector::x()));
+        world.impulse_joints.insert(ball1, ball2, joint);
+        // Compute the world-space centers of mass.
+        world.step();
+
+        let pipeline = DebugRenderPipeline::new(
+            Default::default(),
+            DebugRenderMode::COLLIDER_SHAPES | DebugRenderMode::JOINTS,
+        );
+        let mut backend = RecordingBackend::default();
+        pipeline.render(
+            &mut backend,
+            &world.bodies,
+            &world.colliders,
+            &world.impulse_joints,
+            &world.multibody_joints,
+            &world.narrow_phase,
+        );
+
+        for ball in [ball1, ball2] {
+            let collider = world.bodies[ball].colliders()[0];
+            assert!(backend.collider_lines.contains(&collider));
+        }
+
+        // Each body’s center-of-mass is linked to its anchor, and both anchors coincide.
+        let anchor = Point::from(Vector::x());
+        assert_eq!(
+            backend.joint_lines,
+            vec![
+                (Point::origin(), anchor),
+                (Point::from(Vector::x() * 2.0), anchor),
+                (anchor, anchor)
+            ]
+        );
+    }
+}
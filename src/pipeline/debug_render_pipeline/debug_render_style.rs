@@ -0,0 +1,68 @@
+use crate::math::Real;
+use crate::pipeline::DebugColor;
+
+/// Style used for computing colors when rendering the scene.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+pub struct DebugRenderStyle {
+    /// The number of subdivisions used to approximate the curved
+    /// parts of a shape with lines.
+    pub subdivisions: u32,
+    /// The color of colliders attached to dynamic rigid-bodies.
+    pub collider_dynamic_color: DebugColor,
+    /// The color of colliders attached to fixed rigid-bodies.
+    pub collider_fixed_color: DebugColor,
+    /// The color of colliders attached to kinematic rigid-bodies.
+    pub collider_kinematic_color: DebugColor,
+    /// The color of colliders not attached to any rigid-body.
+    pub collider_parentless_color: DebugColor,
+    /// The color of the AABB of colliders.
+    pub collider_aabb_color: DebugColor,
+    /// The color of the line between a rigid-body’s center-of-mass and an impulse joint anchor.
+    pub impulse_joint_anchor_color: DebugColor,
+    /// The color of the line between the two anchors of an impulse joint.
+    pub impulse_joint_separation_color: DebugColor,
+    /// The color of the line between a rigid-body’s center-of-mass and a multibody joint anchor.
+    pub multibody_joint_anchor_color: DebugColor,
+    /// The color of the line between the two anchors of a multibody joint.
+    pub multibody_joint_separation_color: DebugColor,
+    /// Each component of the color of a sleeping object is multiplied by the
+    /// corresponding component of this array.
+    pub sleep_color_multiplier: [f32; 4],
+    /// The length of the local coordinate axes rendered for a rigid-body.
+    pub rigid_body_axes_length: Real,
+    /// The color of the cross marking the center-of-mass of a rigid-body.
+    pub center_of_mass_color: DebugColor,
+    /// The half-length of the cross marking the center-of-mass of a rigid-body.
+    pub center_of_mass_size: Real,
+    /// The color of the line joining the two points of a contact.
+    pub contact_depth_color: DebugColor,
+    /// The color of the contact normal.
+    pub contact_normal_color: DebugColor,
+    /// The length of the contact normal.
+    pub contact_normal_length: Real,
+}
+
+impl Default for DebugRenderStyle {
+    fn default() -> Self {
+        Self {
+            subdivisions: 20,
+            collider_dynamic_color: [0.55, 0.35, 0.85, 1.0],
+            collider_fixed_color: [0.55, 0.55, 0.55, 1.0],
+            collider_kinematic_color: [0.2, 0.55, 0.9, 1.0],
+            collider_parentless_color: [0.9, 0.6, 0.2, 1.0],
+            collider_aabb_color: [0.9, 0.9, 0.2, 1.0],
+            impulse_joint_anchor_color: [0.1, 0.6, 0.1, 1.0],
+            impulse_joint_separation_color: [1.0, 0.0, 0.0, 1.0],
+            multibody_joint_anchor_color: [0.1, 0.1, 0.8, 1.0],
+            multibody_joint_separation_color: [1.0, 0.0, 0.0, 1.0],
+            sleep_color_multiplier: [0.5, 0.5, 0.5, 1.0],
+            rigid_body_axes_length: 0.5,
+            center_of_mass_color: [1.0, 1.0, 1.0, 1.0],
+            center_of_mass_size: 0.1,
+            contact_depth_color: [1.0, 0.2, 0.2, 1.0],
+            contact_normal_color: [0.2, 1.0, 0.2, 1.0],
+            contact_normal_length: 0.3,
+        }
+    }
+}
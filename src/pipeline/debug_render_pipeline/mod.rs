@@ -0,0 +1,9 @@
+//! Backend-agnostic rendering of the physics scene for debugging purpose.
+
+pub use self::debug_render_backend::{DebugColor, DebugRenderBackend, DebugRenderObject};
+pub use self::debug_render_pipeline::{DebugRenderMode, DebugRenderPipeline};
+pub use self::debug_render_style::DebugRenderStyle;
+
+mod debug_render_backend;
+mod debug_render_pipeline;
+mod debug_render_style;
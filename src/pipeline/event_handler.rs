@@ -1,4 +1,6 @@
-use crate::geometry::{CollisionEvent, ContactPair};
+use crate::dynamics::{ImpulseJoint, ImpulseJointHandle, RigidBodyHandle};
+use crate::geometry::{ColliderHandle, CollisionEvent, ContactPair};
+use crate::math::{Real, Vector};
 use crossbeam::channel::Sender;
 
 bitflags::bitflags! {
@@ -8,6 +10,9 @@ bitflags::bitflags! {
         /// If set, Rapier will call `EventHandler::handle_intersection_event` and
         /// `EventHandler::handle_contact_event` whenever relevant for this collider.
         const COLLISION_EVENTS = 0b0001;
+        /// If set, Rapier will call `EventHandler::handle_contact_force_event`
+        /// whenever relevant for this collider.
+        const CONTACT_FORCE_EVENTS = 0b0010;
     }
 }
 
@@ -17,6 +22,104 @@ impl Default for ActiveEvents {
     }
 }
 
+/// Event occurring when the sum of the magnitudes of the contact forces
+/// between two colliders exceed a threshold.
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+pub struct ContactForceEvent {
+    /// The first collider involved in the contact.
+    pub collider1: ColliderHandle,
+    /// The second collider involved in the contact.
+    pub collider2: ColliderHandle,
+    /// The sum of all the normal forces applied by the contacts to the first collider’s rigid-body.
+    ///
+    /// The force applied to the second collider’s rigid-body is `-total_force`. Friction forces
+    /// are not included because their directions are not preserved after the solver runs.
+    pub total_force: Vector<Real>,
+    /// The sum of the magnitudes of all the contact forces (normal and friction) between
+    /// the two colliders.
+    pub total_force_magnitude: Real,
+    /// The direction (i.e. the contact normal) of the contact with the largest force
+    /// magnitude.
+    pub max_force_direction: Vector<Real>,
+    /// The magnitude of the largest contact force (normal and friction) between the two colliders.
+    pub max_force_magnitude: Real,
+}
+
+impl ContactForceEvent {
+    /// Computes the contact force event from the impulses stored in the contact pair after
+    /// the constraints resolution of a timestep of length `dt`.
+    pub fn from_contact_pair(dt: Real, pair: &ContactPair) -> Self {
+        let mut result = ContactForceEvent {
+            collider1: pair.collider1,
+            collider2: pair.collider2,
+            ..ContactForceEvent::default()
+        };
+
+        for manifold in &pair.manifolds {
+            let mut total_manifold_impulse = 0.0;
+
+            // Only the contacts that were given to the solver have up-to-date impulses.
+            for solver_contact in &manifold.data.solver_contacts {
+                let data = &manifold.points[solver_contact.contact_id as usize].data;
+                let impulse = data.impulse;
+                #[cfg(feature = "dim2")]
+                let tangent_impulse_sq = data.tangent_impulse * data.tangent_impulse;
+                #[cfg(feature = "dim3")]
+                let tangent_impulse_sq = data.tangent_impulse.norm_squared();
+                let impulse_magnitude = (impulse * impulse + tangent_impulse_sq).sqrt();
+
+                total_manifold_impulse += impulse;
+                result.total_force_magnitude += impulse_magnitude;
+
+                if impulse_magnitude > result.max_force_magnitude {
+                    result.max_force_magnitude = impulse_magnitude;
+                    result.max_force_direction = manifold.data.normal;
+                }
+            }
+
+            result.total_force += manifold.data.normal * total_manifold_impulse;
+        }
+
+        let inv_dt = crate::utils::inv(dt);
+        result.total_force *= inv_dt;
+        result.total_force_magnitude *= inv_dt;
+        result.max_force_magnitude *= inv_dt;
+        result
+    }
+}
+
+/// Event occurring when an impulse joint breaks because the force or torque it
+/// applies exceeds its `GenericJoint::max_force` or `GenericJoint::max_torque`.
+///
+/// The broken joint is removed from the `ImpulseJointSet`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+pub struct JointBrokenEvent {
+    /// The (now invalid) handle of the broken joint.
+    pub joint: ImpulseJointHandle,
+    /// The first rigid-body that was attached to the broken joint.
+    pub body1: RigidBodyHandle,
+    /// The second rigid-body that was attached to the broken joint.
+    pub body2: RigidBodyHandle,
+    /// The norm of the linear force applied by the joint before it broke.
+    pub force: Real,
+    /// The norm of the torque applied by the joint before it broke.
+    pub torque: Real,
+}
+
+/// Event occurring when a rigid-body falls asleep or wakes up.
+///
+/// This is only emitted for rigid-bodies with `RigidBodyActivation::events_enabled` set to `true`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+pub struct BodyActivationEvent {
+    /// The rigid-body that fell asleep or woke up.
+    pub body: RigidBodyHandle,
+    /// `true` if the rigid-body fell asleep, `false` if it woke up.
+    pub sleeping: bool,
+}
+
 /// Trait implemented by structures responsible for handling events generated by the physics engine.
 ///
 /// Implementors of this trait will typically collect these events for future processing.
@@ -31,26 +134,235 @@ pub trait EventHandler: Send + Sync {
     ///                    if at least one of the collider is a sensor (in which case no contact information
     ///                    is ever computed).
     fn handle_collision_event(&self, event: CollisionEvent, contact_pair: Option<&ContactPair>);
+
+    /// Handle a contact force event.
+    ///
+    /// A contact force event is emitted after the constraints resolution when at least one
+    /// of the two colliders has the `ActiveEvents::CONTACT_FORCE_EVENTS` flag set, and the
+    /// total force magnitude of their contacts exceeds the smallest contact force event
+    /// threshold of these colliders.
+    ///
+    /// # Parameters
+    /// * `event` - The contact force event, containing the forces computed from the contact impulses.
+    /// * `contact_pair` - The current state of contacts between the two colliders.
+    ///
+    /// Does nothing by default.
+    fn handle_contact_force_event(&self, _event: ContactForceEvent, _contact_pair: &ContactPair) {}
+
+    /// Handle a joint broken event.
+    ///
+    /// A joint broken event is emitted after the constraints resolution when an impulse joint
+    /// applied a force or torque exceeding its limits. The joint has already been removed from
+    /// its `ImpulseJointSet`.
+    ///
+    /// # Parameters
+    /// * `event` - The joint broken event.
+    /// * `joint` - The joint that broke. It can be inserted back into the joint set if needed.
+    ///
+    /// Does nothing by default.
+    fn handle_joint_broken_event(&self, _event: JointBrokenEvent, _joint: &ImpulseJoint) {}
+
+    /// Handle a body activation event.
+    ///
+    /// A body activation event is emitted while building the islands of the timestep, when
+    /// a rigid-body with `RigidBodyActivation::events_enabled` set falls asleep or wakes up. A
+    /// wake-up event is also emitted the first time an awake rigid-body is simulated.
+    ///
+    /// # Parameters
+    /// * `event` - The body activation event.
+    /// * `island` - The handles of all the rigid-bodies of the island the body is part of. For a
+    ///              rigid-body falling asleep, these are all the sleeping rigid-bodies touching or
+    ///              jointed to it, directly or indirectly. For a rigid-body waking up, this is the
+    ///              active island it is solved with.
+    ///
+    /// Does nothing by default.
+    fn handle_body_activation_event(
+        &self,
+        _event: BodyActivationEvent,
+        _island: &[RigidBodyHandle],
+    ) {
+    }
 }
 
 impl EventHandler for () {
     fn handle_collision_event(&self, _event: CollisionEvent, _contact_pair: Option<&ContactPair>) {}
 }
 
-/// A collision event handler that collects events into a crossbeam channel.
+/// A collision event handler that collects events into crossbeam channels.
+///
+/// Only the collision events are collected by default. The other events are collected
+/// once a channel sender is set for them with the `with_*_sender` methods.
 pub struct ChannelEventCollector {
-    event_sender: Sender<CollisionEvent>,
+    collision_event_sender: Sender<CollisionEvent>,
+    contact_force_event_sender: Option<Sender<ContactForceEvent>>,
+    joint_broken_event_sender: Option<Sender<JointBrokenEvent>>,
+    body_activation_event_sender: Option<Sender<BodyActivationEvent>>,
 }
 
 impl ChannelEventCollector {
-    /// Initialize a new collision event handler from crossbeam channel senders.
-    pub fn new(event_sender: Sender<CollisionEvent>) -> Self {
-        Self { event_sender }
+    /// Initialize a new collision event handler from a crossbeam channel sender.
+    pub fn new(collision_event_sender: Sender<CollisionEvent>) -> Self {
+        Self {
+            collision_event_sender,
+            contact_force_event_sender: None,
+            joint_broken_event_sender: None,
+            body_activation_event_sender: None,
+        }
+    }
+
+    /// Sets the channel sender the contact force events are sent to.
+    pub fn with_contact_force_event_sender(mut self, sender: Sender<ContactForceEvent>) -> Self {
+        self.contact_force_event_sender = Some(sender);
+        self
+    }
+
+    /// Sets the channel sender the joint broken events are sent to.
+    pub fn with_joint_broken_event_sender(mut self, sender: Sender<JointBrokenEvent>) -> Self {
+        self.joint_broken_event_sender = Some(sender);
+        self
+    }
+
+    /// Sets the channel sender the body activation events are sent to.
+    pub fn with_body_activation_event_sender(
+        mut self,
+        sender: Sender<BodyActivationEvent>,
+    ) -> Self {
+        self.body_activation_event_sender = Some(sender);
+        self
     }
 }
 
 impl EventHandler for ChannelEventCollector {
     fn handle_collision_event(&self, event: CollisionEvent, _: Option<&ContactPair>) {
-        let _ = self.event_sender.send(event);
+        let _ = self.collision_event_sender.send(event);
+    }
+
+    fn handle_contact_force_event(&self, event: ContactForceEvent, _: &ContactPair) {
+        if let Some(sender) = &self.contact_force_event_sender {
+            let _ = sender.send(event);
+        }
+    }
+
+    fn handle_joint_broken_event(&self, event: JointBrokenEvent, _: &ImpulseJoint) {
+        if let Some(sender) = &self.joint_broken_event_sender {
+            let _ = sender.send(event);
+        }
+    }
+
+    fn handle_body_activation_event(&self, event: BodyActivationEvent, _: &[RigidBodyHandle]) {
+        if let Some(sender) = &self.body_activation_event_sender {
+            let _ = sender.send(event);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ActiveEvents, ChannelEventCollector, ContactForceEvent};
+    use crate::dynamics::{RigidBodyBuilder, RigidBodyHandle};
+    use crate::geometry::ColliderBuilder;
+    use crate::math::{Real, Vector};
+    use crate::test_utils::TestWorld;
+    use crossbeam::channel::unbounded;
+
+    // A ball resting on the ground, with contact force events enabled. The threshold is
+    // given as a multiple of the ball’s weight.
+    fn resting_ball(threshold_in_weights: Option<Real>) -> (TestWorld, RigidBodyHandle, Real) {
+        let mut world = TestWorld::new();
+        world.add_ground();
+        let ball = world.bodies.insert(
+            RigidBodyBuilder::dynamic()
+                .translation(Vector::y() * 0.5)
+                .build(),
+        );
+        let collider = ColliderBuilder::ball(0.5)
+            .active_events(ActiveEvents::CONTACT_FORCE_EVENTS)
+            .build();
+        let handle = world
+            .colliders
+            .insert_with_parent(collider, ball, &mut world.bodies);
+        let weight = world.bodies[ball].mass() * 9.81;
+        if let Some(threshold) = threshold_in_weights {
+            world.colliders[handle].set_contact_force_event_threshold(threshold * weight);
+        }
+        (world, ball, weight)
+    }
+
+    // Steps the world `num_steps` times and returns the contact force events of the last step.
+    fn step_and_collect(world: &mut TestWorld, num_steps: usize) -> Vec<ContactForceEvent> {
+        let (contact_force_send, contact_force_recv) = unbounded();
+        let collector = ChannelEventCollector::new(unbounded().0)
+            .with_contact_force_event_sender(contact_force_send);
+
+        world.step_n(num_steps - 1);
+        world.step_with_events(&collector);
+        contact_force_recv.try_iter().collect()
+    }
+
+    #[test]
+    fn contact_force_events_above_threshold() {
+        let (mut world, _, weight) = resting_ball(Some(0.5));
+        let events = step_and_collect(&mut world, 30);
+        assert_eq!(events.len(), 1);
+        assert!((events[0].total_force_magnitude - weight).abs() < weight * 0.1);
+
+        let (mut world, _, _) = resting_ball(Some(2.0));
+        assert!(step_and_collect(&mut world, 30).is_empty());
+
+        // The threshold is `Real::MAX` by default.
+        let (mut world, _, _) = resting_ball(None);
+        assert!(step_and_collect(&mut world, 30).is_empty());
+    }
+
+    #[test]
+    fn no_contact_force_events_for_sleeping_pairs() {
+        let (mut world, ball, _) = resting_ball(Some(0.5));
+        let events = step_and_collect(&mut world, 500);
+        assert!(world.bodies[ball].is_sleeping());
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn body_activation_events_when_falling_asleep_and_waking_up() {
+        let (body_activation_send, body_activation_recv) = unbounded();
+        let collector = ChannelEventCollector::new(unbounded().0)
+            .with_body_activation_event_sender(body_activation_send);
+
+        // A ball resting on the ground.
+        let mut world = TestWorld::new();
+        world.add_ground();
+        let ball = world.bodies.insert(
+            RigidBodyBuilder::dynamic()
+                .translation(Vector::y() * 0.5)
+                .activation_events_enabled(true)
+                .build(),
+        );
+        world.colliders.insert_with_parent(
+            ColliderBuilder::ball(0.5).build(),
+            ball,
+            &mut world.bodies,
+        );
+
+        // The first simulation of the ball is reported as a wake-up.
+        world.step_with_events(&collector);
+        let event = body_activation_recv.try_recv().unwrap();
+        assert_eq!(event.body, ball);
+        assert!(!event.sleeping);
+
+        for _ in 0..300 {
+            world.step_with_events(&collector);
+        }
+
+        assert!(world.bodies[ball].is_sleeping());
+        let event = body_activation_recv.try_recv().unwrap();
+        assert_eq!(event.body, ball);
+        assert!(event.sleeping);
+        assert!(body_activation_recv.try_recv().is_err());
+
+        world.bodies[ball].wake_up(true);
+        world.step_with_events(&collector);
+        let event = body_activation_recv.try_recv().unwrap();
+        assert_eq!(event.body, ball);
+        assert!(!event.sleeping);
     }
 }
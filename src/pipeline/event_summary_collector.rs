@@ -0,0 +1,383 @@
+use crate::dynamics::{ImpulseJoint, RigidBodyHandle};
+use crate::geometry::{ColliderHandle, ColliderSet, CollisionEvent, ContactPair};
+use crate::math::{Point, Real, Vector};
+use crate::pipeline::{BodyActivationEvent, ContactForceEvent, EventHandler, JointBrokenEvent};
+use crossbeam::channel::{Receiver, Sender, TrySendError};
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+/// What a [`ChannelEventSummaryCollector`] does with a new event when its channel, or the buffer
+/// of events waiting for [`ChannelEventSummaryCollector::send_events`], is full.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ChannelOverflowPolicy {
+    /// The new event is dropped.
+    DropNewest,
+    /// The oldest event still waiting in the channel, or in the buffer, is dropped to make room
+    /// for the new event.
+    DropOldest,
+}
+
+/// A summary of the contacts between two colliders.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+pub struct ContactSummary {
+    /// The world-space location of the deepest contact point.
+    pub point: Point<Real>,
+    /// The world-space contact normal of the deepest contact point, pointing toward the
+    /// exterior of the first collider.
+    pub normal: Vector<Real>,
+    /// The signed distance at the deepest contact point. If negative, this is the penetration
+    /// depth.
+    pub dist: Real,
+    /// The sum of the normal impulses of all the contacts, as computed by the last constraints
+    /// resolution.
+    pub total_impulse: Real,
+}
+
+impl ContactSummary {
+    /// Summarizes the contacts given to the constraints solver for this contact pair.
+    ///
+    /// Returns `None` if the contact pair doesn’t have any active contact.
+    pub fn from_contact_pair(pair: &ContactPair) -> Option<Self> {
+        let mut result: Option<Self> = None;
+        let mut total_impulse = 0.0;
+
+        for manifold in &pair.manifolds {
+            for solver_contact in &manifold.data.solver_contacts {
+                total_impulse += manifold.points[solver_contact.contact_id as usize]
+                    .data
+                    .impulse;
+
+                let is_deepest = match result {
+                    Some(deepest) => solver_contact.dist < deepest.dist,
+                    None => true,
+                };
+
+                if is_deepest {
+                    result = Some(ContactSummary {
+                        point: solver_contact.point,
+                        normal: manifold.data.normal,
+                        dist: solver_contact.dist,
+                        total_impulse: 0.0,
+                    });
+                }
+            }
+        }
+
+        result.map(|mut summary| {
+            summary.total_impulse = total_impulse;
+            summary
+        })
+    }
+}
+
+/// An owned summary of an event involving two colliders.
+#[derive(Copy, Clone, Debug)]
+pub struct EventSummary<E> {
+    /// The event being summarized.
+    pub event: E,
+    /// The first collider involved in the event.
+    pub collider1: ColliderHandle,
+    /// The second collider involved in the event.
+    pub collider2: ColliderHandle,
+    /// The rigid-body the first collider is attached to, if any.
+    pub body1: Option<RigidBodyHandle>,
+    /// The rigid-body the second collider is attached to, if any.
+    pub body2: Option<RigidBodyHandle>,
+    /// The user-data of the first collider.
+    ///
+    /// This is `None` if the collider was removed from the `ColliderSet` before the event was sent.
+    pub user_data1: Option<u128>,
+    /// The user-data of the second collider.
+    ///
+    /// This is `None` if the collider was removed from the `ColliderSet` before the event was sent.
+    pub user_data2: Option<u128>,
+    /// The contacts between both colliders when the event occurred.
+    ///
+    /// This is `None` if at least one of the colliders is a sensor, or if they don’t have any
+    /// active contact.
+    pub contact: Option<ContactSummary>,
+}
+
+/// An owned summary of a collision event.
+pub type CollisionEventSummary = EventSummary<CollisionEvent>;
+/// An owned summary of a contact force event.
+pub type ContactForceEventSummary = EventSummary<ContactForceEvent>;
+
+impl<E> EventSummary<E> {
+    fn new(
+        event: E,
+        collider1: ColliderHandle,
+        collider2: ColliderHandle,
+        contact_pair: Option<&ContactPair>,
+    ) -> Self {
+        let manifold = contact_pair.and_then(|pair| pair.manifolds.first());
+
+        Self {
+            event,
+            collider1,
+            collider2,
+            body1: manifold.and_then(|m| m.data.rigid_body1),
+            body2: manifold.and_then(|m| m.data.rigid_body2),
+            user_data1: None,
+            user_data2: None,
+            contact: contact_pair.and_then(ContactSummary::from_contact_pair),
+        }
+    }
+
+    fn complete(&mut self, colliders: &ColliderSet) {
+        if let Some(co1) = colliders.get(self.collider1) {
+            self.body1 = co1.parent();
+            self.user_data1 = Some(co1.user_data);
+        }
+
+        if let Some(co2) = colliders.get(self.collider2) {
+            self.body2 = co2.parent();
+            self.user_data2 = Some(co2.user_data);
+        }
+    }
+}
+
+/// An event handler that sends owned summaries of the events into crossbeam channels.
+///
+/// Unlike the [`ChannelEventCollector`](crate::pipeline::ChannelEventCollector), the summaries
+/// include the rigid-bodies and user-data of the colliders, as well as their deepest contact.
+/// Because the colliders can’t be read while a timestep is running, the collision and contact
+/// force events are buffered during the timestep, and only sent to their channels by
+/// [`ChannelEventSummaryCollector::send_events`], which must be called after each timestep.
+///
+/// The channels can be bounded, in which case an overflow policy decides which events are
+/// dropped when a consumer doesn’t keep up. The buffers of events waiting for
+/// [`ChannelEventSummaryCollector::send_events`] are then bounded the same way.
+pub struct ChannelEventSummaryCollector {
+    capacity: Option<usize>,
+    overflow_policy: ChannelOverflowPolicy,
+    collision_events: (
+        Sender<CollisionEventSummary>,
+        Receiver<CollisionEventSummary>,
+    ),
+    contact_force_events: (
+        Sender<ContactForceEventSummary>,
+        Receiver<ContactForceEventSummary>,
+    ),
+    joint_broken_events: (Sender<JointBrokenEvent>, Receiver<JointBrokenEvent>),
+    body_activation_events: (Sender<BodyActivationEvent>, Receiver<BodyActivationEvent>),
+    pending_collision_events: Mutex<VecDeque<CollisionEventSummary>>,
+    pending_contact_force_events: Mutex<VecDeque<ContactForceEventSummary>>,
+}
+
+impl Default for ChannelEventSummaryCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ChannelEventSummaryCollector {
+    /// Initialize a new event summary collector with unbounded channels.
+    pub fn new() -> Self {
+        Self {
+            capacity: None,
+            overflow_policy: ChannelOverflowPolicy::DropNewest,
+            collision_events: crossbeam::channel::unbounded(),
+            contact_force_events: crossbeam::channel::unbounded(),
+            joint_broken_events: crossbeam::channel::unbounded(),
+            body_activation_events: crossbeam::channel::unbounded(),
+            pending_collision_events: Mutex::new(VecDeque::new()),
+            pending_contact_force_events: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Initialize a new event summary collector with channels holding at most `capacity` events
+    /// each.
+    ///
+    /// At most `capacity` collision events and `capacity` contact force events are buffered
+    /// until the next call to [`ChannelEventSummaryCollector::send_events`]. When a channel or a
+    /// buffer is full, the `overflow_policy` decides which event is dropped.
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn bounded(capacity: usize, overflow_policy: ChannelOverflowPolicy) -> Self {
+        assert!(
+            capacity > 0,
+            "The event channels capacity must not be zero."
+        );
+        Self {
+            capacity: Some(capacity),
+            overflow_policy,
+            collision_events: crossbeam::channel::bounded(capacity),
+            contact_force_events: crossbeam::channel::bounded(capacity),
+            joint_broken_events: crossbeam::channel::bounded(capacity),
+            body_activation_events: crossbeam::channel::bounded(capacity),
+            pending_collision_events: Mutex::new(VecDeque::new()),
+            pending_contact_force_events: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// The receiving end of the channel of collision event summaries.
+    pub fn collision_event_receiver(&self) -> Receiver<CollisionEventSummary> {
+        self.collision_events.1.clone()
+    }
+
+    /// The receiving end of the channel of contact force event summaries.
+    pub fn contact_force_event_receiver(&self) -> Receiver<ContactForceEventSummary> {
+        self.contact_force_events.1.clone()
+    }
+
+    /// The receiving end of the channel of joint broken events.
+    pub fn joint_broken_event_receiver(&self) -> Receiver<JointBrokenEvent> {
+        self.joint_broken_events.1.clone()
+    }
+
+    /// The receiving end of the channel of body activation events.
+    pub fn body_activation_event_receiver(&self) -> Receiver<BodyActivationEvent> {
+        self.body_activation_events.1.clone()
+    }
+
+    /// Completes the summaries of the events buffered during the last timestep with the
+    /// rigid-bodies and user-data of their colliders, and sends them to their channels.
+    ///
+    /// This must be called after each timestep, before any collider is removed from `colliders`.
+    pub fn send_events(&self, colliders: &ColliderSet) {
+        let collision_events = std::mem::take(&mut *self.pending_collision_events.lock().unwrap());
+        for mut summary in collision_events {
+            summary.complete(colliders);
+            self.push(&self.collision_events, summary);
+        }
+
+        let contact_force_events =
+            std::mem::take(&mut *self.pending_contact_force_events.lock().unwrap());
+        for mut summary in contact_force_events {
+            summary.complete(colliders);
+            self.push(&self.contact_force_events, summary);
+        }
+    }
+
+    fn push<T>(&self, channel: &(Sender<T>, Receiver<T>), mut event: T) {
+        loop {
+            match channel.0.try_send(event) {
+                // NOTE: the channel can’t be disconnected since we keep its receiver.
+                Ok(()) | Err(TrySendError::Disconnected(_)) => return,
+                Err(TrySendError::Full(rejected)) => match self.overflow_policy {
+                    ChannelOverflowPolicy::DropNewest => return,
+                    ChannelOverflowPolicy::DropOldest => {
+                        let _ = channel.1.try_recv();
+                        event = rejected;
+                    }
+                },
+            }
+        }
+    }
+
+    fn push_pending<T>(&self, pending: &Mutex<VecDeque<T>>, event: T) {
+        let mut pending = pending.lock().unwrap();
+
+        if matches!(self.capacity, Some(capacity) if pending.len() >= capacity) {
+            match self.overflow_policy {
+                ChannelOverflowPolicy::DropNewest => return,
+                ChannelOverflowPolicy::DropOldest => {
+                    pending.pop_front();
+                }
+            }
+        }
+
+        pending.push_back(event);
+    }
+}
+
+impl EventHandler for ChannelEventSummaryCollector {
+    fn handle_collision_event(&self, event: CollisionEvent, contact_pair: Option<&ContactPair>) {
+        let summary = EventSummary::new(event, event.collider1(), event.collider2(), contact_pair);
+        self.push_pending(&self.pending_collision_events, summary);
+    }
+
+    fn handle_contact_force_event(&self, event: ContactForceEvent, contact_pair: &ContactPair) {
+        let summary =
+            EventSummary::new(event, event.collider1, event.collider2, Some(contact_pair));
+        self.push_pending(&self.pending_contact_force_events, summary);
+    }
+
+    fn handle_joint_broken_event(&self, event: JointBrokenEvent, _: &ImpulseJoint) {
+        self.push(&self.joint_broken_events, event);
+    }
+
+    fn handle_body_activation_event(&self, event: BodyActivationEvent, _: &[RigidBodyHandle]) {
+        self.push(&self.body_activation_events, event);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ChannelEventSummaryCollector, ChannelOverflowPolicy};
+    use crate::dynamics::RigidBodyBuilder;
+    use crate::geometry::{ColliderBuilder, ColliderHandle, ColliderSet, CollisionEvent};
+    use crate::math::Vector;
+    use crate::pipeline::{ActiveEvents, EventHandler};
+    use crate::test_utils::{cuboid, TestWorld};
+
+    #[test]
+    fn event_summaries_carry_bodies_user_data_and_contacts() {
+        let mut world = TestWorld::new();
+        let events = ChannelEventSummaryCollector::bounded(1, ChannelOverflowPolicy::DropNewest);
+        let receiver = events.collision_event_receiver();
+
+        let ground = world
+            .colliders
+            .insert(cuboid(10.0, 0.1, 10.0).user_data(1).build());
+        let ball = world.bodies.insert(
+            RigidBodyBuilder::dynamic()
+                .translation(Vector::y() * 1.2)
+                .build(),
+        );
+        world.colliders.insert_with_parent(
+            ColliderBuilder::ball(0.5)
+                .user_data(2)
+                .active_events(ActiveEvents::COLLISION_EVENTS)
+                .build(),
+            ball,
+            &mut world.bodies,
+        );
+
+        for _ in 0..60 {
+            world.step_with_events(&events);
+            events.send_events(&world.colliders);
+        }
+
+        let summary = receiver.try_recv().unwrap();
+        assert!(summary.event.started());
+        let (ground_user_data, ball_user_data, ball_handle) = if summary.collider1 == ground {
+            (summary.user_data1, summary.user_data2, summary.body2)
+        } else {
+            (summary.user_data2, summary.user_data1, summary.body1)
+        };
+        assert_eq!(ground_user_data, Some(1));
+        assert_eq!(ball_user_data, Some(2));
+        assert_eq!(ball_handle, Some(ball));
+        assert!(summary.contact.is_some());
+        // The channel only has room for a single event.
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[test]
+    fn pending_events_are_bounded() {
+        let colliders = ColliderSet::new();
+        let received = |policy| {
+            let events = ChannelEventSummaryCollector::bounded(2, policy);
+            let receiver = events.collision_event_receiver();
+
+            // More events than the capacity, without calling `send_events` in-between.
+            for i in 0..5 {
+                let handle = ColliderHandle::from_raw_parts(i, 0);
+                events.handle_collision_event(CollisionEvent::Started(handle, handle), None);
+            }
+
+            assert_eq!(events.pending_collision_events.lock().unwrap().len(), 2);
+            events.send_events(&colliders);
+            receiver
+                .try_iter()
+                .map(|summary| summary.collider1.into_raw_parts().0)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(received(ChannelOverflowPolicy::DropNewest), [0, 1]);
+        assert_eq!(received(ChannelOverflowPolicy::DropOldest), [3, 4]);
+    }
+}
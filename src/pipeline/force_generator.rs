@@ -0,0 +1,250 @@
+use crate::dynamics::{RigidBodyHandle, RigidBodyMassProps, RigidBodyVelocity};
+use crate::geometry::{ColliderHandle, ColliderMassProps, ColliderPosition, ColliderShape};
+use crate::math::{AngVector, Isometry, Point, Real, Vector};
+use crate::utils::{self, WCross};
+
+/// A collider attached to the rigid-body given to a [`ForceGenerator`].
+pub struct ForceGeneratorCollider<'a> {
+    /// The handle of the collider.
+    pub handle: ColliderHandle,
+    /// The shape of the collider.
+    pub shape: &'a ColliderShape,
+    /// The world-space position of the collider.
+    pub position: &'a ColliderPosition,
+    /// The mass-properties of the collider.
+    pub mass_props: &'a ColliderMassProps,
+}
+
+impl<'a> ForceGeneratorCollider<'a> {
+    /// The volume (or area in 2D) of this collider.
+    ///
+    /// This is computed from the collider’s shape, so it may be expensive for complex shapes
+    /// like triangle meshes.
+    pub fn volume(&self) -> Real {
+        match self.mass_props {
+            ColliderMassProps::Density(density) if *density > 0.0 => {
+                utils::inv(self.mass_props.mass_properties(&**self.shape).inv_mass) / *density
+            }
+            _ => utils::inv(self.shape.mass_properties(1.0).inv_mass),
+        }
+    }
+}
+
+/// Context given to a [`ForceGenerator`] to compute the forces applied to one rigid-body.
+pub struct ForceGeneratorContext<'a> {
+    /// The length of the current (sub-)step.
+    pub dt: Real,
+    /// The gravity vector of the simulation.
+    pub gravity: &'a Vector<Real>,
+    /// The handle of the rigid-body.
+    pub body: RigidBodyHandle,
+    /// The world-space position of the rigid-body.
+    pub position: &'a Isometry<Real>,
+    /// The velocity of the rigid-body.
+    pub velocity: &'a RigidBodyVelocity,
+    /// The mass-properties of the rigid-body.
+    pub mass_props: &'a RigidBodyMassProps,
+    /// The scaling factor applied to the gravity affecting the rigid-body.
+    pub gravity_scale: Real,
+    /// The colliders attached to the rigid-body.
+    pub colliders: &'a [ForceGeneratorCollider<'a>],
+    /// The force accumulated by the force generators for this (sub-)step.
+    pub force: Vector<Real>,
+    /// The torque accumulated by the force generators for this (sub-)step.
+    pub torque: AngVector<Real>,
+}
+
+impl<'a> ForceGeneratorContext<'a> {
+    /// Adds a force at the center-of-mass of the rigid-body.
+    pub fn add_force(&mut self, force: Vector<Real>) {
+        self.force += force;
+    }
+
+    /// Adds a torque to the rigid-body.
+    pub fn add_torque(&mut self, torque: AngVector<Real>) {
+        self.torque += torque;
+    }
+
+    /// Adds a force at the given world-space point of the rigid-body.
+    pub fn add_force_at_point(&mut self, force: Vector<Real>, point: Point<Real>) {
+        self.force += force;
+        self.torque += (point - self.mass_props.world_com).gcross(force);
+    }
+}
+
+/// Trait implemented by custom force fields (wind, magnets, buoyancy, etc.) applied by the
+/// `PhysicsPipeline`.
+///
+/// Unlike the forces added with `RigidBody::add_force`, the forces of a force generator are
+/// computed again at each CCD substep, and are not kept from one timestep to the next.
+pub trait ForceGenerator: Send + Sync {
+    /// Computes the forces applied to one rigid-body.
+    ///
+    /// This is called at each substep for each active dynamic rigid-body, right before its
+    /// effective force and torque are computed. The forces must be added to `context`.
+    fn generate_forces(&self, context: &mut ForceGeneratorContext);
+}
+
+/// A gravity field pulling every rigid-body toward a single point.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+pub struct RadialGravity {
+    /// The world-space point the rigid-bodies are attracted to.
+    pub center: Point<Real>,
+    /// The gravitational acceleration at a unit distance from the center.
+    ///
+    /// The acceleration decreases with the squared distance to the center. A negative strength
+    /// pushes the rigid-bodies away from the center.
+    pub strength: Real,
+    /// The distance below which the acceleration stops increasing when approaching the center.
+    pub min_distance: Real,
+}
+
+impl RadialGravity {
+    /// Creates a new radial gravity field.
+    pub fn new(center: Point<Real>, strength: Real) -> Self {
+        Self {
+            center,
+            strength,
+            min_distance: 0.1,
+        }
+    }
+}
+
+impl ForceGenerator for RadialGravity {
+    fn generate_forces(&self, context: &mut ForceGeneratorContext) {
+        let dir = self.center - context.mass_props.world_com;
+        let dist = dir.norm();
+
+        if dist > 0.0 {
+            let clamped_dist = dist.max(self.min_distance);
+            let acceleration = dir * (self.strength / (clamped_dist * clamped_dist * dist));
+            let force = acceleration.component_mul(&context.mass_props.effective_mass());
+            context.add_force(force * context.gravity_scale);
+        }
+    }
+}
+
+/// A uniform wind slowing down, or dragging along, every rigid-body.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+pub struct WindDrag {
+    /// The world-space velocity of the wind.
+    pub wind_velocity: Vector<Real>,
+    /// The drag coefficient.
+    ///
+    /// The force applied to a rigid-body is this coefficient multiplied by the velocity of the
+    /// wind relative to the rigid-body’s center-of-mass.
+    pub drag_coefficient: Real,
+}
+
+impl WindDrag {
+    /// Creates a new uniform wind.
+    pub fn new(wind_velocity: Vector<Real>, drag_coefficient: Real) -> Self {
+        Self {
+            wind_velocity,
+            drag_coefficient,
+        }
+    }
+}
+
+impl ForceGenerator for WindDrag {
+    fn generate_forces(&self, context: &mut ForceGeneratorContext) {
+        let relative_velocity = self.wind_velocity - context.velocity.linvel;
+        context.add_force(relative_velocity * self.drag_coefficient);
+    }
+}
+
+/// A fluid filling all the space below a given height, applying Archimedes’ buoyancy force
+/// to the colliders it submerges.
+///
+/// The submerged part of each collider is approximated from its axis-aligned bounding box.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+pub struct Buoyancy {
+    /// The height, along the world-space `Y` axis, of the fluid’s surface.
+    pub surface_height: Real,
+    /// The density of the fluid.
+    pub fluid_density: Real,
+    /// The linear drag coefficient of the fluid.
+    ///
+    /// This slows down each collider proportionally to its submerged fraction.
+    pub drag_coefficient: Real,
+}
+
+impl Buoyancy {
+    /// Creates a new fluid with the given surface height and density, and without drag.
+    pub fn new(surface_height: Real, fluid_density: Real) -> Self {
+        Self {
+            surface_height,
+            fluid_density,
+            drag_coefficient: 0.0,
+        }
+    }
+}
+
+impl ForceGenerator for Buoyancy {
+    fn generate_forces(&self, context: &mut ForceGeneratorContext) {
+        for collider in context.colliders {
+            let aabb = collider.shape.compute_aabb(collider.position);
+
+            if aabb.mins.y >= self.surface_height {
+                continue;
+            }
+
+            let height = aabb.maxs.y - aabb.mins.y;
+            let submerged_top = aabb.maxs.y.min(self.surface_height);
+            let submerged_fraction = if height > 0.0 {
+                (submerged_top - aabb.mins.y) / height
+            } else {
+                1.0
+            };
+
+            // Apply the force at the center of the submerged part of the bounding box.
+            let mut center = aabb.center();
+            center.y = (aabb.mins.y + submerged_top) / 2.0;
+
+            let submerged_volume = collider.volume() * submerged_fraction;
+            let buoyancy_force = -*context.gravity * (self.fluid_density * submerged_volume);
+            let drag_force =
+                -context.velocity.linvel * (self.drag_coefficient * submerged_fraction);
+            context.add_force_at_point(buoyancy_force + drag_force, center);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Buoyancy;
+    use crate::dynamics::RigidBodyBuilder;
+    use crate::geometry::ColliderBuilder;
+    use crate::math::Vector;
+    use crate::test_utils::TestWorld;
+
+    #[test]
+    fn buoyancy_makes_light_ball_float() {
+        let mut world = TestWorld::new();
+        world.pipeline.force_generators.push(Box::new(Buoyancy {
+            drag_coefficient: 1.0,
+            ..Buoyancy::new(0.0, 2.0)
+        }));
+
+        // A ball half as dense as the fluid, released deep below its surface.
+        let ball = world.bodies.insert(
+            RigidBodyBuilder::dynamic()
+                .translation(Vector::y() * -3.0)
+                .build(),
+        );
+        world.colliders.insert_with_parent(
+            ColliderBuilder::ball(0.5).density(1.0).build(),
+            ball,
+            &mut world.bodies,
+        );
+
+        world.step_n(600);
+
+        // The ball ends up floating, half-submerged.
+        let height = world.bodies[ball].translation().y;
+        assert!(height.abs() < 0.1, "{}", height);
+    }
+}
@@ -1,14 +1,51 @@
 //! Structure for combining the various physics components to perform an actual simulation.
 
 pub use collision_pipeline::CollisionPipeline;
-pub use event_handler::{ActiveEvents, ChannelEventCollector, EventHandler};
+#[cfg(feature = "default-sets")]
+pub use debug_render_pipeline::{
+    DebugColor, DebugRenderBackend, DebugRenderMode, DebugRenderObject, DebugRenderPipeline,
+    DebugRenderStyle,
+};
+pub use event_handler::{
+    ActiveEvents, BodyActivationEvent, ChannelEventCollector, ContactForceEvent, EventHandler,
+    JointBrokenEvent,
+};
+#[cfg(feature = "default-sets")]
+pub use event_summary_collector::{
+    ChannelEventSummaryCollector, ChannelOverflowPolicy, CollisionEventSummary,
+    ContactForceEventSummary, ContactSummary, EventSummary,
+};
+pub use force_generator::{
+    Buoyancy, ForceGenerator, ForceGeneratorCollider, ForceGeneratorContext, RadialGravity,
+    WindDrag,
+};
 pub use physics_hooks::{ActiveHooks, ContactModificationContext, PairFilterContext, PhysicsHooks};
 pub use physics_pipeline::PhysicsPipeline;
+#[cfg(all(feature = "serde-serialize", feature = "default-sets"))]
+pub use physics_world_snapshot::PhysicsWorldSnapshot;
 pub use query_pipeline::{QueryPipeline, QueryPipelineMode};
+#[cfg(feature = "default-sets")]
+pub use rollback_buffer::RollbackBuffer;
+#[cfg(all(feature = "serde-serialize", feature = "default-sets"))]
+pub use scene::{
+    ColliderDesc, ImpulseJointDesc, MultibodyDesc, MultibodyJointDesc, RigidBodyDesc, SceneDesc,
+    SceneError, SceneWorld, ShapeDesc,
+};
 
 mod collision_pipeline;
+#[cfg(feature = "default-sets")]
+mod debug_render_pipeline;
 mod event_handler;
+#[cfg(feature = "default-sets")]
+mod event_summary_collector;
+mod force_generator;
 mod physics_hooks;
 mod physics_pipeline;
+#[cfg(all(feature = "serde-serialize", feature = "default-sets"))]
+mod physics_world_snapshot;
 mod query_pipeline;
+#[cfg(feature = "default-sets")]
+mod rollback_buffer;
+#[cfg(all(feature = "serde-serialize", feature = "default-sets"))]
+mod scene;
 mod user_changes;
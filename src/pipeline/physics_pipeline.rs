@@ -5,42 +5,50 @@ use crate::data::{BundleSet, ComponentSet, ComponentSetMut, ComponentSetOption};
 #[cfg(not(feature = "parallel"))]
 use crate::dynamics::IslandSolver;
 use crate::dynamics::{
-    CCDSolver, ImpulseJointSet, IntegrationParameters, IslandManager, MultibodyJointSet,
-    RigidBodyActivation, RigidBodyCcd, RigidBodyChanges, RigidBodyColliders, RigidBodyDamping,
-    RigidBodyDominance, RigidBodyForces, RigidBodyHandle, RigidBodyIds, RigidBodyMassProps,
-    RigidBodyPosition, RigidBodyType, RigidBodyVelocity,
+    CCDSolver, ImpulseJointHandle, ImpulseJointSet, IntegrationParameters, IslandManager,
+    MultibodyJointSet, RigidBodyActivation, RigidBodyCcd, RigidBodyChanges, RigidBodyColliders,
+    RigidBodyDamping, RigidBodyDominance, RigidBodyForces, RigidBodyHandle, RigidBodyIds,
+    RigidBodyMassProps, RigidBodyPosition, RigidBodyType, RigidBodyVelocity,
 };
 #[cfg(feature = "parallel")]
 use crate::dynamics::{JointGraphEdge, ParallelIslandSolver as IslandSolver};
 use crate::geometry::{
     BroadPhase, BroadPhasePairEvent, ColliderBroadPhaseData, ColliderChanges, ColliderFlags,
-    ColliderHandle, ColliderMaterial, ColliderPair, ColliderParent, ColliderPosition,
-    ColliderShape, ColliderType, ContactManifoldIndex, NarrowPhase,
+    ColliderHandle, ColliderMassProps, ColliderMaterial, ColliderPair, ColliderParent,
+    ColliderPosition, ColliderShape, ColliderType, ContactManifoldIndex, NarrowPhase,
 };
 use crate::math::{Real, Vector};
-use crate::pipeline::{EventHandler, PhysicsHooks};
+use crate::pipeline::{
+    ActiveEvents, ContactForceEvent, EventHandler, ForceGenerator, ForceGeneratorCollider,
+    ForceGeneratorContext, JointBrokenEvent, PhysicsHooks,
+};
 
 #[cfg(feature = "default-sets")]
 use {crate::dynamics::RigidBodySet, crate::geometry::ColliderSet};
 
 /// The physics pipeline, responsible for stepping the whole physics simulation.
 ///
-/// This structure only contains temporary data buffers. It can be dropped and replaced by a fresh
-/// copy at any time. For performance reasons it is recommended to reuse the same physics pipeline
-/// instance to benefit from the cached data.
+/// Besides its [`force_generators`](Self::force_generators), this structure only contains temporary
+/// data buffers. It can be replaced by a fresh copy at any time, as long as the force generators are
+/// moved to the new copy. For performance reasons it is recommended to reuse the same physics
+/// pipeline instance to benefit from the cached data.
 ///
 /// Rapier relies on a time-stepping scheme. Its force computations
 /// uses two solvers:
 /// - A velocity based solver based on PGS which computes forces for contact and joint constraints.
 /// - A position based solver based on non-linear PGS which performs constraint stabilization (i.e. correction of errors like penetrations).
-// NOTE: this contains only workspace data, so there is no point in making this serializable.
+// NOTE: this contains only workspace data and force generators, so there is no point in making
+//       this serializable.
 pub struct PhysicsPipeline {
     /// Counters used for benchmarking only.
     pub counters: Counters,
+    /// The force generators applied to each active dynamic rigid-body at each substep.
+    pub force_generators: Vec<Box<dyn ForceGenerator>>,
     manifold_indices: Vec<Vec<ContactManifoldIndex>>,
     joint_constraint_indices: Vec<Vec<ContactManifoldIndex>>,
     broadphase_collider_pairs: Vec<ColliderPair>,
     broad_phase_events: Vec<BroadPhasePairEvent>,
+    broken_joints: Vec<(ImpulseJointHandle, Real, Real)>,
     solvers: Vec<IslandSolver>,
 }
 
@@ -61,11 +69,13 @@ impl PhysicsPipeline {
     pub fn new() -> PhysicsPipeline {
         PhysicsPipeline {
             counters: Counters::new(false),
+            force_generators: Vec::new(),
             solvers: Vec::new(),
             manifold_indices: Vec::new(),
             joint_constraint_indices: Vec::new(),
             broadphase_collider_pairs: Vec::new(),
             broad_phase_events: Vec::new(),
+            broken_joints: Vec::new(),
         }
     }
 
@@ -87,6 +97,8 @@ impl PhysicsPipeline {
         narrow_phase: &mut NarrowPhase,
         bodies: &mut Bodies,
         colliders: &mut Colliders,
+        impulse_joints: &ImpulseJointSet,
+        multibody_joints: &MultibodyJointSet,
         modified_colliders: &[ColliderHandle],
         removed_colliders: &[ColliderHandle],
         hooks: &dyn PhysicsHooks<Bodies, Colliders>,
@@ -145,6 +157,8 @@ impl PhysicsPipeline {
             integration_parameters.prediction_distance,
             bodies,
             colliders,
+            Some(impulse_joints),
+            Some(multibody_joints),
             modified_colliders,
             hooks,
             events,
@@ -165,6 +179,7 @@ impl PhysicsPipeline {
         colliders: &mut Colliders,
         impulse_joints: &mut ImpulseJointSet,
         multibody_joints: &mut MultibodyJointSet,
+        events: &dyn EventHandler,
     ) where
         Bodies: ComponentSetMut<RigidBodyPosition>
             + ComponentSetMut<RigidBodyVelocity>
@@ -175,7 +190,10 @@ impl PhysicsPipeline {
             + ComponentSet<RigidBodyDamping>
             + ComponentSet<RigidBodyColliders>
             + ComponentSet<RigidBodyType>,
-        Colliders: ComponentSetOption<ColliderParent>,
+        Colliders: ComponentSetOption<ColliderParent>
+            + ComponentSet<ColliderShape>
+            + ComponentSet<ColliderPosition>
+            + ComponentSet<ColliderMassProps>,
     {
         self.counters.stages.island_construction_time.resume();
         islands.update_active_set_with_contacts(
@@ -186,9 +204,16 @@ impl PhysicsPipeline {
             impulse_joints,
             multibody_joints,
             integration_parameters.min_island_size,
+            events,
         );
         self.counters.stages.island_construction_time.pause();
 
+        if self.counters.enabled {
+            for island in islands.active_islands() {
+                self.counters.islands.add_island(island.len());
+            }
+        }
+
         if self.manifold_indices.len() < islands.num_islands() {
             self.manifold_indices
                 .resize(islands.num_islands(), Vec::new());
@@ -213,6 +238,7 @@ impl PhysicsPipeline {
         );
 
         self.counters.stages.update_time.resume();
+        let mut generator_colliders = Vec::new();
         for handle in islands.active_dynamic_bodies() {
             let poss: &RigidBodyPosition = bodies.index(handle.0);
             let position = poss.position;
@@ -223,8 +249,54 @@ impl PhysicsPipeline {
                     mprops.effective_mass()
                 })
                 .unwrap();
+
+            let generated_forces = if self.force_generators.is_empty() {
+                None
+            } else {
+                let (vels, mprops, forces, rb_colliders): (
+                    &RigidBodyVelocity,
+                    &RigidBodyMassProps,
+                    &RigidBodyForces,
+                    &RigidBodyColliders,
+                ) = bodies.index_bundle(handle.0);
+
+                generator_colliders.clear();
+                for co_handle in &rb_colliders.0 {
+                    generator_colliders.push(ForceGeneratorCollider {
+                        handle: *co_handle,
+                        shape: colliders.index(co_handle.0),
+                        position: colliders.index(co_handle.0),
+                        mass_props: colliders.index(co_handle.0),
+                    });
+                }
+
+                let mut context = ForceGeneratorContext {
+                    dt: integration_parameters.dt,
+                    gravity,
+                    body: *handle,
+                    position: &position,
+                    velocity: vels,
+                    mass_props: mprops,
+                    gravity_scale: forces.gravity_scale,
+                    colliders: &generator_colliders,
+                    force: na::zero(),
+                    torque: na::zero(),
+                };
+
+                for generator in &self.force_generators {
+                    generator.generate_forces(&mut context);
+                }
+
+                Some((context.force, context.torque))
+            };
+
             bodies.map_mut_internal(handle.0, |forces: &mut RigidBodyForces| {
-                forces.compute_effective_force_and_torque(&gravity, &effective_inv_mass)
+                forces.compute_effective_force_and_torque(gravity, &effective_inv_mass);
+
+                if let Some((force, torque)) = generated_forces {
+                    forces.force += force;
+                    forces.torque += torque;
+                }
             });
         }
 
@@ -313,6 +385,106 @@ impl PhysicsPipeline {
         self.counters.stages.solver_time.pause();
     }
 
+    fn generate_contact_force_events<Bodies, Colliders>(
+        &self,
+        integration_parameters: &IntegrationParameters,
+        narrow_phase: &NarrowPhase,
+        bodies: &Bodies,
+        colliders: &Colliders,
+        events: &dyn EventHandler,
+    ) where
+        Bodies: ComponentSet<RigidBodyType> + ComponentSet<RigidBodyActivation>,
+        Colliders: ComponentSet<ColliderFlags>
+            + ComponentSet<ColliderMaterial>
+            + ComponentSetOption<ColliderParent>,
+    {
+        // Returns whether the collider is attached to a dynamic body and whether this body
+        // is sleeping. Colliders without parents behave like fixed bodies.
+        let parent_state = |handle: ColliderHandle| {
+            let co_parent: Option<&ColliderParent> = colliders.get(handle.0);
+            co_parent.map_or((false, true), |co_parent| {
+                let (rb_type, rb_activation): (&RigidBodyType, &RigidBodyActivation) =
+                    bodies.index_bundle(co_parent.handle.0);
+                (rb_type.is_dynamic(), rb_activation.sleeping)
+            })
+        };
+
+        let threshold = |handle: ColliderHandle| {
+            let co_flags: &ColliderFlags = colliders.index(handle.0);
+
+            if co_flags
+                .active_events
+                .contains(ActiveEvents::CONTACT_FORCE_EVENTS)
+            {
+                let co_material: &ColliderMaterial = colliders.index(handle.0);
+                Some(co_material.contact_force_event_threshold)
+            } else {
+                None
+            }
+        };
+
+        for pair in narrow_phase.contact_pairs() {
+            if !pair.has_any_active_contact {
+                continue;
+            }
+
+            // Only the pairs involving an active island were solved during this step, see
+            // `NarrowPhase::select_active_contacts`.
+            let (dynamic1, sleeping1) = parent_state(pair.collider1);
+            let (dynamic2, sleeping2) = parent_state(pair.collider2);
+
+            if !(dynamic1 || dynamic2) || (dynamic1 && sleeping1) || (dynamic2 && sleeping2) {
+                continue;
+            }
+
+            let threshold = match (threshold(pair.collider1), threshold(pair.collider2)) {
+                (Some(t1), Some(t2)) => t1.min(t2),
+                (Some(t), None) | (None, Some(t)) => t,
+                (None, None) => continue,
+            };
+
+            let event = ContactForceEvent::from_contact_pair(integration_parameters.dt, pair);
+
+            if event.total_force_magnitude > threshold {
+                events.handle_contact_force_event(event, pair);
+            }
+        }
+    }
+
+    fn remove_broken_joints<Bodies>(
+        &mut self,
+        integration_parameters: &IntegrationParameters,
+        islands: &mut IslandManager,
+        bodies: &mut Bodies,
+        impulse_joints: &mut ImpulseJointSet,
+        events: &dyn EventHandler,
+    ) where
+        Bodies: ComponentSetMut<RigidBodyActivation>
+            + ComponentSet<RigidBodyType>
+            + ComponentSetMut<RigidBodyIds>,
+    {
+        self.broken_joints.clear();
+
+        for (handle, joint) in impulse_joints.iter() {
+            if let Some((force, torque)) = joint.check_break(integration_parameters.dt) {
+                self.broken_joints.push((handle, force, torque));
+            }
+        }
+
+        for (handle, force, torque) in self.broken_joints.drain(..) {
+            if let Some(joint) = impulse_joints.remove(handle, islands, bodies, true) {
+                let event = JointBrokenEvent {
+                    joint: handle,
+                    body1: joint.body1,
+                    body2: joint.body2,
+                    force,
+                    torque,
+                };
+                events.handle_joint_broken_event(event, &joint);
+            }
+        }
+    }
+
     fn run_ccd_motion_clamping<Bodies, Colliders>(
         &mut self,
         integration_parameters: &IntegrationParameters,
@@ -501,6 +673,7 @@ impl PhysicsPipeline {
             + ComponentSetMut<ColliderChanges>
             + ComponentSetMut<ColliderPosition>
             + ComponentSet<ColliderShape>
+            + ComponentSet<ColliderMassProps>
             + ComponentSetOption<ColliderParent>
             + ComponentSet<ColliderType>
             + ComponentSet<ColliderMaterial>
@@ -538,6 +711,8 @@ impl PhysicsPipeline {
             narrow_phase,
             bodies,
             colliders,
+            impulse_joints,
+            multibody_joints,
             &modified_colliders[..],
             removed_colliders,
             hooks,
@@ -628,6 +803,22 @@ impl PhysicsPipeline {
                 colliders,
                 impulse_joints,
                 multibody_joints,
+                events,
+            );
+
+            self.generate_contact_force_events(
+                &integration_parameters,
+                narrow_phase,
+                bodies,
+                colliders,
+                events,
+            );
+            self.remove_broken_joints(
+                &integration_parameters,
+                islands,
+                bodies,
+                impulse_joints,
+                events,
             );
 
             // If CCD is enabled, execute the CCD motion clamping.
@@ -662,6 +853,8 @@ impl PhysicsPipeline {
                 narrow_phase,
                 bodies,
                 colliders,
+                impulse_joints,
+                multibody_joints,
                 modified_colliders,
                 removed_colliders,
                 hooks,
@@ -672,6 +865,11 @@ impl PhysicsPipeline {
             self.clear_modified_colliders(colliders, modified_colliders);
         }
 
+        // The user-defined generalized forces apply to every substep of a single timestep.
+        for multibody in &mut multibody_joints.multibodies {
+            multibody.1.forces.fill(0.0);
+        }
+
         self.counters.step_completed();
     }
 }
@@ -0,0 +1,179 @@
+use crate::dynamics::{
+    CCDSolver, ImpulseJointSet, IntegrationParameters, IslandManager, MultibodyJointSet,
+    RigidBodySet,
+};
+use crate::geometry::{BroadPhase, ColliderSet, NarrowPhase};
+use crate::math::{Real, Vector};
+
+/// A snapshot of all the persistent data used by `PhysicsPipeline::step`.
+///
+/// Serializing this snapshot, deserializing it, and restoring it with
+/// [`PhysicsWorldSnapshot::restore`] brings the simulation back to the exact same state:
+/// when the `enhanced-determinism` feature is enabled, stepping after a restore gives
+/// bit-identical results to stepping the original world.
+///
+/// The `PhysicsPipeline` itself isn’t part of the snapshot: besides temporary workspace
+/// data, it only contains its force generators which must be set up again by the user.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct PhysicsWorldSnapshot {
+    /// The gravity applied to the simulated world.
+    pub gravity: Vector<Real>,
+    /// The integration parameters used for stepping the simulation.
+    pub integration_parameters: IntegrationParameters,
+    /// The island manager, tracking the set of active rigid-bodies.
+    pub islands: IslandManager,
+    /// The broad-phase.
+    pub broad_phase: BroadPhase,
+    /// The narrow-phase, including all the contact pairs and their impulses.
+    pub narrow_phase: NarrowPhase,
+    /// The set of rigid-bodies.
+    pub bodies: RigidBodySet,
+    /// The set of colliders.
+    pub colliders: ColliderSet,
+    /// The set of impulse joints.
+    pub impulse_joints: ImpulseJointSet,
+    /// The set of multibody joints.
+    pub multibody_joints: MultibodyJointSet,
+    /// The CCD solver.
+    pub ccd_solver: CCDSolver,
+}
+
+impl PhysicsWorldSnapshot {
+    /// Takes a snapshot of all the given physics world components.
+    pub fn new(
+        gravity: &Vector<Real>,
+        integration_parameters: &IntegrationParameters,
+        islands: &IslandManager,
+        broad_phase: &BroadPhase,
+        narrow_phase: &NarrowPhase,
+        bodies: &RigidBodySet,
+        colliders: &ColliderSet,
+        impulse_joints: &ImpulseJointSet,
+        multibody_joints: &MultibodyJointSet,
+        ccd_solver: &CCDSolver,
+    ) -> Self {
+        Self {
+            gravity: *gravity,
+            integration_parameters: *integration_parameters,
+            islands: islands.clone(),
+            broad_phase: broad_phase.clone(),
+            narrow_phase: narrow_phase.clone(),
+            bodies: bodies.clone(),
+            colliders: colliders.clone(),
+            impulse_joints: impulse_joints.clone(),
+            multibody_joints: multibody_joints.clone(),
+            ccd_solver: ccd_solver.clone(),
+        }
+    }
+
+    /// Overwrites all the given physics world components with the content of this snapshot.
+    ///
+    /// The existing components are reused so their allocated memory may be recycled.
+    pub fn restore(
+        &self,
+        gravity: &mut Vector<Real>,
+        integration_parameters: &mut IntegrationParameters,
+        islands: &mut IslandManager,
+        broad_phase: &mut BroadPhase,
+        narrow_phase: &mut NarrowPhase,
+        bodies: &mut RigidBodySet,
+        colliders: &mut ColliderSet,
+        impulse_joints: &mut ImpulseJointSet,
+        multibody_joints: &mut MultibodyJointSet,
+        ccd_solver: &mut CCDSolver,
+    ) {
+        *gravity = self.gravity;
+        *integration_parameters = self.integration_parameters;
+        islands.clone_from(&self.islands);
+        broad_phase.clone_from(&self.broad_phase);
+        narrow_phase.clone_from(&self.narrow_phase);
+        bodies.clone_from(&self.bodies);
+        colliders.clone_from(&self.colliders);
+        impulse_joints.clone_from(&self.impulse_joints);
+        multibody_joints.clone_from(&self.multibody_joints);
+        ccd_solver.clone_from(&self.ccd_solver);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::PhysicsWorldSnapshot;
+    use crate::math::Vector;
+    use crate::test_utils::TestWorld;
+
+    fn take_snapshot(world: &TestWorld) -> PhysicsWorldSnapshot {
+        PhysicsWorldSnapshot::new(
+            &world.gravity,
+            &world.integration_parameters,
+            &world.islands,
+            &world.broad_phase,
+            &world.narrow_phase,
+            &world.bodies,
+            &world.colliders,
+            &world.impulse_joints,
+            &world.multibody_joints,
+            &world.ccd_solver,
+        )
+    }
+
+    #[test]
+    fn world_snapshot_restore_determinism() {
+        let mut world = TestWorld::new();
+        world.add_ground();
+
+        for i in 0..5 {
+            world.add_ball(Vector::y() * (0.6 + i as crate::math::Real * 1.1), 0.5);
+        }
+
+        // A ball that falls asleep, and is woken up after the snapshot by another ball
+        // falling on it.
+        let sleeper = world.add_ball(Vector::x() * 5.0 + Vector::y() * 0.5, 0.5);
+
+        // A double pendulum simulated as a multibody.
+        world.add_multibody_arm(Vector::x() * -5.0 + Vector::y() * 5.0, 2);
+
+        world.step_n(200);
+        assert!(world.bodies[sleeper].is_sleeping());
+        world.add_ball(Vector::x() * 5.0 + Vector::y() * 3.0, 0.5);
+        world.step_n(5);
+
+        let serialized = bincode::serialize(&take_snapshot(&world)).unwrap();
+
+        world.step_n(60);
+        assert!(!world.bodies[sleeper].is_sleeping());
+
+        let state = |world: &TestWorld| -> Vec<_> {
+            world
+                .bodies
+                .iter()
+                .map(|(h, rb)| (h, *rb.position(), rb.is_sleeping()))
+                .collect()
+        };
+        let expected = state(&world);
+        #[cfg(feature = "enhanced-determinism")]
+        let expected_world = bincode::serialize(&take_snapshot(&world)).unwrap();
+
+        let snapshot: PhysicsWorldSnapshot = bincode::deserialize(&serialized).unwrap();
+        snapshot.restore(
+            &mut world.gravity,
+            &mut world.integration_parameters,
+            &mut world.islands,
+            &mut world.broad_phase,
+            &mut world.narrow_phase,
+            &mut world.bodies,
+            &mut world.colliders,
+            &mut world.impulse_joints,
+            &mut world.multibody_joints,
+            &mut world.ccd_solver,
+        );
+        assert!(world.bodies[sleeper].is_sleeping());
+
+        world.step_n(60);
+        assert_eq!(expected, state(&world));
+
+        // With `enhanced-determinism`, the whole world, not only the body positions, is
+        // bit-identical to the original one.
+        #[cfg(feature = "enhanced-determinism")]
+        assert!(bincode::serialize(&take_snapshot(&world)).unwrap() == expected_world);
+    }
+}
@@ -0,0 +1,322 @@
+use crate::dynamics::{
+    IslandManager, RigidBodyActivation, RigidBodyChanges, RigidBodyForces, RigidBodyHandle,
+    RigidBodyPosition, RigidBodySet, RigidBodyVelocity,
+};
+use crate::geometry::{ColliderHandle, ContactData, NarrowPhase};
+use std::collections::VecDeque;
+use std::ops::Range;
+
+#[derive(Copy, Clone, Debug)]
+struct RecordedBody {
+    handle: RigidBodyHandle,
+    pos: RigidBodyPosition,
+    vels: RigidBodyVelocity,
+    activation: RigidBodyActivation,
+    forces: RigidBodyForces,
+}
+
+#[derive(Copy, Clone, Debug)]
+struct RecordedContact {
+    fid1: u32,
+    fid2: u32,
+    data: ContactData,
+}
+
+#[derive(Clone, Debug)]
+struct RecordedContactPair {
+    collider1: ColliderHandle,
+    collider2: ColliderHandle,
+    manifolds: Range<usize>,
+}
+
+#[derive(Clone, Debug, Default)]
+struct RollbackFrame {
+    frame_id: u64,
+    bodies: Vec<RecordedBody>,
+    contact_pairs: Vec<RecordedContactPair>,
+    manifolds: Vec<Range<usize>>,
+    contacts: Vec<RecordedContact>,
+}
+
+impl RollbackFrame {
+    fn clear(&mut self) {
+        self.bodies.clear();
+        self.contact_pairs.clear();
+        self.manifolds.clear();
+        self.contacts.clear();
+    }
+}
+
+/// A ring buffer keeping track of the dynamic state of the last few simulation frames.
+///
+/// Each recorded frame only contains the position, velocity, activation, and forces of
+/// every rigid-body, as well as the impulses of every contact (used for warmstarting the
+/// constraints solver). This is much cheaper than a full `PhysicsWorldSnapshot` but it
+/// assumes the set of rigid-bodies, colliders, and joints didn’t change since the
+/// restored frame was recorded: rigid-bodies removed since then are ignored, and
+/// rigid-bodies added since then are left untouched. The contact graph and the contact
+/// geometry aren’t recorded either, so re-simulating a restored frame may not reproduce the
+/// original simulation bit-for-bit if contacts started or stopped since then.
+///
+/// The memory allocated for each frame is recycled once the buffer is full so recording
+/// doesn’t allocate anything once the number of bodies and contacts stabilizes.
+#[derive(Clone, Debug)]
+pub struct RollbackBuffer {
+    capacity: usize,
+    frames: VecDeque<RollbackFrame>,
+    // Frames discarded by a rollback, kept around to recycle their memory.
+    free_frames: Vec<RollbackFrame>,
+}
+
+impl RollbackBuffer {
+    /// Creates a rollback buffer able to store up to `capacity` frames.
+    pub fn new(capacity: usize) -> Self {
+        assert!(
+            capacity > 0,
+            "The rollback buffer capacity must be non-zero."
+        );
+        Self {
+            capacity,
+            frames: VecDeque::with_capacity(capacity),
+            free_frames: vec![],
+        }
+    }
+
+    /// The maximum number of frames this buffer can store.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// The number of frames currently stored by this buffer.
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// `true` if no frames are stored by this buffer.
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// The identifiers of all the frames currently stored, from the oldest to the most recent.
+    pub fn frame_ids(&self) -> impl ExactSizeIterator<Item = u64> + '_ {
+        self.frames.iter().map(|f| f.frame_id)
+    }
+
+    /// Is the frame with the given identifier stored by this buffer?
+    pub fn contains(&self, frame_id: u64) -> bool {
+        self.frames.iter().any(|f| f.frame_id == frame_id)
+    }
+
+    /// Removes all the frames from this buffer.
+    pub fn clear(&mut self) {
+        self.free_frames.extend(self.frames.drain(..));
+    }
+
+    /// Records the state of the given rigid-bodies and contacts under the identifier `frame_id`.
+    ///
+    /// This is typically called right after each `PhysicsPipeline::step`. Any stored frame with
+    /// an identifier greater or equal to `frame_id` is discarded first, so re-simulating frames
+    /// after a rollback overwrites the outdated history. If the buffer is full, the oldest
+    /// frame is discarded.
+    pub fn record(&mut self, frame_id: u64, bodies: &RigidBodySet, narrow_phase: &NarrowPhase) {
+        while self
+            .frames
+            .back()
+            .map(|f| f.frame_id >= frame_id)
+            .unwrap_or(false)
+        {
+            let frame = self.frames.pop_back().unwrap();
+            self.free_frames.push(frame);
+        }
+
+        let mut frame = if self.frames.len() == self.capacity {
+            self.frames.pop_front().unwrap()
+        } else {
+            self.free_frames.pop().unwrap_or_default()
+        };
+
+        frame.clear();
+        frame.frame_id = frame_id;
+
+        for (handle, rb) in bodies.iter() {
+            frame.bodies.push(RecordedBody {
+                handle,
+                pos: rb.rb_pos,
+                vels: rb.rb_vels,
+                activation: rb.rb_activation,
+                forces: rb.rb_forces,
+            });
+        }
+
+        for pair in narrow_phase.contact_pairs() {
+            let first_manifold = frame.manifolds.len();
+
+            for manifold in &pair.manifolds {
+                let first_contact = frame.contacts.len();
+                frame
+                    .contacts
+                    .extend(manifold.points.iter().map(|pt| RecordedContact {
+                        fid1: pt.fid1,
+                        fid2: pt.fid2,
+                        data: pt.data,
+                    }));
+                frame.manifolds.push(first_contact..frame.contacts.len());
+            }
+
+            frame.contact_pairs.push(RecordedContactPair {
+                collider1: pair.collider1,
+                collider2: pair.collider2,
+                manifolds: first_manifold..frame.manifolds.len(),
+            });
+        }
+
+        self.frames.push_back(frame);
+    }
+
+    /// Restores in-place the state recorded under the identifier `frame_id`.
+    ///
+    /// Returns `false` (and does nothing) if this frame isn’t stored by this buffer. The restored
+    /// frame, as well as the older ones, are kept. Newer frames are kept as well until the next
+    /// call to [`RollbackBuffer::record`] overwrites them.
+    ///
+    /// Contact impulses are restored for the contact points that still exist in the narrow-phase
+    /// (matched by their feature ids). The impulses of the other contact points are reset to zero.
+    pub fn restore(
+        &self,
+        frame_id: u64,
+        islands: &mut IslandManager,
+        bodies: &mut RigidBodySet,
+        narrow_phase: &mut NarrowPhase,
+    ) -> bool {
+        let frame = match self.frames.iter().find(|f| f.frame_id == frame_id) {
+            Some(frame) => frame,
+            None => return false,
+        };
+
+        for recorded in &frame.bodies {
+            if let Some(rb) = bodies.get_mut_internal_with_modification_tracking(recorded.handle) {
+                rb.rb_pos = recorded.pos;
+                rb.rb_vels = recorded.vels;
+                rb.rb_activation = recorded.activation;
+                rb.rb_forces = recorded.forces;
+                rb.rb_mprops
+                    .update_world_mass_properties(&recorded.pos.position);
+                rb.changes.insert(RigidBodyChanges::POSITION);
+            }
+        }
+
+        for pair in narrow_phase.contact_pairs_mut() {
+            for manifold in &mut pair.manifolds {
+                for pt in &mut manifold.points {
+                    pt.data = ContactData::default();
+                }
+            }
+        }
+
+        for recorded_pair in &frame.contact_pairs {
+            let pair = match narrow_phase
+                .contact_pair_mut(recorded_pair.collider1, recorded_pair.collider2)
+            {
+                Some(pair) => pair,
+                None => continue,
+            };
+
+            let recorded_manifolds = &frame.manifolds[recorded_pair.manifolds.clone()];
+
+            for (manifold, contacts) in pair.manifolds.iter_mut().zip(recorded_manifolds) {
+                let recorded_contacts = &frame.contacts[contacts.clone()];
+
+                for pt in &mut manifold.points {
+                    if let Some(recorded) = recorded_contacts
+                        .iter()
+                        .find(|c| c.fid1 == pt.fid1 && c.fid2 == pt.fid2)
+                    {
+                        pt.data = recorded.data;
+                    }
+                }
+            }
+        }
+
+        // Update the active set so it matches the activation state of the restored frame.
+        // Non-dynamic and removed bodies are ignored by `put_to_sleep` and `wake_up`.
+        for recorded in &frame.bodies {
+            if recorded.activation.sleeping {
+                islands.put_to_sleep(bodies, recorded.handle);
+            } else {
+                islands.wake_up(bodies, recorded.handle, false);
+            }
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::RollbackBuffer;
+    use crate::dynamics::RigidBodyBuilder;
+    use crate::geometry::ColliderBuilder;
+    use crate::math::{Real, Vector};
+    use crate::test_utils::TestWorld;
+
+    #[test]
+    fn rollback_restore_determinism() {
+        let mut world = TestWorld::new();
+        world.add_ground();
+
+        let stack: Vec<_> = (0..5)
+            .map(|i| world.add_ball(Vector::y() * (0.6 + i as Real * 1.1), 0.5))
+            .collect();
+
+        // A ball put to sleep in mid-air, woken up after the recorded frame by another ball
+        // falling on it.
+        let sleeper = world.bodies.insert(
+            RigidBodyBuilder::dynamic()
+                .translation(Vector::x() * 5.0 + Vector::y() * 2.0)
+                .build(),
+        );
+        world.colliders.insert_with_parent(
+            ColliderBuilder::ball(0.5).build(),
+            sleeper,
+            &mut world.bodies,
+        );
+        world.add_ball(Vector::x() * 5.0 + Vector::y() * 4.0, 0.5);
+        world.step();
+        // Newly inserted rigid-bodies are woken up by the first step.
+        world.bodies[sleeper].sleep();
+        world.step_n(4);
+        assert!(world.bodies[sleeper].is_sleeping());
+
+        let mut buffer = RollbackBuffer::new(4);
+        buffer.record(0, &world.bodies, &world.narrow_phase);
+
+        world.step_n(60);
+        assert!(!world.bodies[sleeper].is_sleeping());
+
+        // The sleeper is woken up by a contact pair that isn’t part of the recorded frame,
+        // so only the stack is expected to be re-simulated bit-for-bit.
+        let state = |world: &TestWorld| -> Vec<_> {
+            stack
+                .iter()
+                .map(|h| {
+                    let rb = &world.bodies[*h];
+                    (*rb.position(), *rb.linvel(), rb.is_sleeping())
+                })
+                .collect()
+        };
+        let expected = state(&world);
+
+        assert!(buffer.restore(
+            0,
+            &mut world.islands,
+            &mut world.bodies,
+            &mut world.narrow_phase
+        ));
+        assert!(world.bodies[sleeper].is_sleeping());
+        assert!(!world.islands.active_dynamic_bodies().contains(&sleeper));
+
+        world.step_n(60);
+        assert_eq!(expected, state(&world));
+        assert!(!world.bodies[sleeper].is_sleeping());
+    }
+}
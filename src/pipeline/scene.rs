@@ -0,0 +1,1054 @@
+use crate::dynamics::{
+    CoefficientCombineRule, GenericJoint, ImpulseJointSet, IntegrationParameters, LockedAxes,
+    MassProperties, MultibodyJoint, MultibodyJointHandle, MultibodyJointSet, RigidBody,
+    RigidBodyBuilder, RigidBodyHandle, RigidBodySet, RigidBodyType,
+};
+use crate::geometry::{
+    ActiveCollisionTypes, Collider, ColliderBuilder, ColliderHandle, ColliderMassProps,
+    ColliderSet, InteractionGroups, SharedShape,
+};
+use crate::math::{AngVector, Isometry, Point, Real, Vector};
+use crate::pipeline::{ActiveEvents, ActiveHooks};
+use crate::utils;
+use parry::shape::{Cuboid, RoundShape, Shape, ShapeType};
+use std::collections::HashMap;
+use std::fmt;
+
+/// An error that occurred while building a scene or exporting a world to a scene.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SceneError {
+    /// A joint or collider references a rigid-body index that doesn’t exist in the scene.
+    InvalidBodyIndex(usize),
+    /// A shape description couldn’t be converted to a shape, e.g., because the convex hull
+    /// of its points is degenerate.
+    InvalidShape,
+    /// A multibody joint couldn’t be inserted because it would introduce a loop, or attach
+    /// a rigid-body to two parents.
+    InvalidMultibodyJoint {
+        /// The index of the parent rigid-body of the joint.
+        body1: usize,
+        /// The index of the child rigid-body of the joint.
+        body2: usize,
+    },
+    /// A collider of the world has a shape that can’t be described by a [`ShapeDesc`].
+    UnsupportedShape(ShapeType),
+}
+
+impl fmt::Display for SceneError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SceneError::InvalidBodyIndex(i) => write!(f, "invalid rigid-body index: {}", i),
+            SceneError::InvalidShape => write!(f, "invalid shape description"),
+            SceneError::InvalidMultibodyJoint { body1, body2 } => write!(
+                f,
+                "invalid multibody joint between the rigid-bodies {} and {}",
+                body1, body2
+            ),
+            SceneError::UnsupportedShape(shape_type) => {
+                write!(f, "unsupported shape type: {:?}", shape_type)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SceneError {}
+
+/// The description of a shape, in terms of the parameters of its constructor.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ShapeDesc {
+    /// A ball, see [`SharedShape::ball`].
+    Ball {
+        /// The radius of the ball.
+        radius: Real,
+    },
+    /// A cuboid, see [`SharedShape::cuboid`].
+    Cuboid {
+        /// The half-extents of the cuboid.
+        half_extents: Vector<Real>,
+    },
+    /// A cuboid with rounded corners, see [`SharedShape::round_cuboid`].
+    RoundCuboid {
+        /// The half-extents of the cuboid, not including its border.
+        half_extents: Vector<Real>,
+        /// The radius of the rounded border.
+        border_radius: Real,
+    },
+    /// A capsule, see [`SharedShape::capsule`].
+    Capsule {
+        /// The first endpoint of the capsule’s principal segment.
+        a: Point<Real>,
+        /// The second endpoint of the capsule’s principal segment.
+        b: Point<Real>,
+        /// The radius of the capsule.
+        radius: Real,
+    },
+    /// A segment, see [`SharedShape::segment`].
+    Segment {
+        /// The first endpoint of the segment.
+        a: Point<Real>,
+        /// The second endpoint of the segment.
+        b: Point<Real>,
+    },
+    /// A triangle, see [`SharedShape::triangle`].
+    Triangle {
+        /// The first vertex of the triangle.
+        a: Point<Real>,
+        /// The second vertex of the triangle.
+        b: Point<Real>,
+        /// The third vertex of the triangle.
+        c: Point<Real>,
+    },
+    /// A triangle with rounded corners, see [`SharedShape::round_triangle`].
+    RoundTriangle {
+        /// The first vertex of the triangle.
+        a: Point<Real>,
+        /// The second vertex of the triangle.
+        b: Point<Real>,
+        /// The third vertex of the triangle.
+        c: Point<Real>,
+        /// The radius of the rounded border.
+        border_radius: Real,
+    },
+    /// A triangle mesh, see [`SharedShape::trimesh`].
+    TriMesh {
+        /// The vertices of the mesh.
+        vertices: Vec<Point<Real>>,
+        /// The vertex indices of each triangle of the mesh.
+        indices: Vec<[u32; 3]>,
+    },
+    /// A polyline, see [`SharedShape::polyline`].
+    Polyline {
+        /// The vertices of the polyline.
+        vertices: Vec<Point<Real>>,
+        /// The vertex indices of each segment of the polyline. If `None`, the vertices are
+        /// linked in sequence.
+        indices: Option<Vec<[u32; 2]>>,
+    },
+    /// A half-space, see [`SharedShape::halfspace`].
+    HalfSpace {
+        /// The outward normal of the half-space. It is normalized when the shape is built.
+        normal: Vector<Real>,
+    },
+    /// A heightfield, see [`SharedShape::heightfield`].
+    #[cfg(feature = "dim2")]
+    HeightField {
+        /// The heights of the heightfield.
+        heights: na::DVector<Real>,
+        /// The scale factor applied to the heightfield.
+        scale: Vector<Real>,
+    },
+    /// A heightfield, see [`SharedShape::heightfield`].
+    #[cfg(feature = "dim3")]
+    HeightField {
+        /// The heights of the heightfield.
+        heights: na::DMatrix<Real>,
+        /// The scale factor applied to the heightfield.
+        scale: Vector<Real>,
+    },
+    /// The convex hull of a set of points, see [`SharedShape::convex_hull`].
+    ConvexHull {
+        /// The points the convex hull is computed from.
+        points: Vec<Point<Real>>,
+    },
+    /// The convex hull of a set of points with rounded corners,
+    /// see [`SharedShape::round_convex_hull`].
+    RoundConvexHull {
+        /// The points the convex hull is computed from.
+        points: Vec<Point<Real>>,
+        /// The radius of the rounded border.
+        border_radius: Real,
+    },
+    /// A cylinder, see [`SharedShape::cylinder`].
+    #[cfg(feature = "dim3")]
+    Cylinder {
+        /// The half-height of the cylinder, along the `y` axis.
+        half_height: Real,
+        /// The radius of the cylinder.
+        radius: Real,
+    },
+    /// A cylinder with rounded corners, see [`SharedShape::round_cylinder`].
+    #[cfg(feature = "dim3")]
+    RoundCylinder {
+        /// The half-height of the cylinder, along the `y` axis.
+        half_height: Real,
+        /// The radius of the cylinder.
+        radius: Real,
+        /// The radius of the rounded border.
+        border_radius: Real,
+    },
+    /// A cone, see [`SharedShape::cone`].
+    #[cfg(feature = "dim3")]
+    Cone {
+        /// The half-height of the cone, along the `y` axis.
+        half_height: Real,
+        /// The radius of the base of the cone.
+        radius: Real,
+    },
+    /// A cone with rounded corners, see [`SharedShape::round_cone`].
+    #[cfg(feature = "dim3")]
+    RoundCone {
+        /// The half-height of the cone, along the `y` axis.
+        half_height: Real,
+        /// The radius of the base of the cone.
+        radius: Real,
+        /// The radius of the rounded border.
+        border_radius: Real,
+    },
+    /// A compound shape, see [`SharedShape::compound`].
+    Compound {
+        /// The sub-shapes of the compound shape, with their position relative to the compound
+        /// shape.
+        shapes: Vec<(Isometry<Real>, ShapeDesc)>,
+    },
+}
+
+impl ShapeDesc {
+    /// Builds the shape described by `self`.
+    pub fn build(&self) -> Result<SharedShape, SceneError> {
+        let shape = match self {
+            ShapeDesc::Ball { radius } => SharedShape::ball(*radius),
+            ShapeDesc::Cuboid { half_extents } => SharedShape::new(Cuboid::new(*half_extents)),
+            ShapeDesc::RoundCuboid {
+                half_extents,
+                border_radius,
+            } => SharedShape::new(RoundShape {
+                base_shape: Cuboid::new(*half_extents),
+                border_radius: *border_radius,
+            }),
+            ShapeDesc::Capsule { a, b, radius } => SharedShape::capsule(*a, *b, *radius),
+            ShapeDesc::Segment { a, b } => SharedShape::segment(*a, *b),
+            ShapeDesc::Triangle { a, b, c } => SharedShape::triangle(*a, *b, *c),
+            ShapeDesc::RoundTriangle {
+                a,
+                b,
+                c,
+                border_radius,
+            } => SharedShape::round_triangle(*a, *b, *c, *border_radius),
+            ShapeDesc::TriMesh { vertices, indices } => {
+                SharedShape::trimesh(vertices.clone(), indices.clone())
+            }
+            ShapeDesc::Polyline { vertices, indices } => {
+                SharedShape::polyline(vertices.clone(), indices.clone())
+            }
+            ShapeDesc::HalfSpace { normal } => SharedShape::halfspace(
+                na::Unit::try_new(*normal, 0.0).ok_or(SceneError::InvalidShape)?,
+            ),
+            ShapeDesc::HeightField { heights, scale } => {
+                SharedShape::heightfield(heights.clone(), *scale)
+            }
+            ShapeDesc::ConvexHull { points } => {
+                SharedShape::convex_hull(points).ok_or(SceneError::InvalidShape)?
+            }
+            ShapeDesc::RoundConvexHull {
+                points,
+                border_radius,
+            } => SharedShape::round_convex_hull(points, *border_radius)
+                .ok_or(SceneError::InvalidShape)?,
+            #[cfg(feature = "dim3")]
+            ShapeDesc::Cylinder {
+                half_height,
+                radius,
+            } => SharedShape::cylinder(*half_height, *radius),
+            #[cfg(feature = "dim3")]
+            ShapeDesc::RoundCylinder {
+                half_height,
+                radius,
+                border_radius,
+            } => SharedShape::round_cylinder(*half_height, *radius, *border_radius),
+            #[cfg(feature = "dim3")]
+            ShapeDesc::Cone {
+                half_height,
+                radius,
+            } => SharedShape::cone(*half_height, *radius),
+            #[cfg(feature = "dim3")]
+            ShapeDesc::RoundCone {
+                half_height,
+                radius,
+                border_radius,
+            } => SharedShape::round_cone(*half_height, *radius, *border_radius),
+            ShapeDesc::Compound { shapes } => {
+                let shapes = shapes
+                    .iter()
+                    .map(|(pos, shape)| Ok((*pos, shape.build()?)))
+                    .collect::<Result<_, SceneError>>()?;
+                SharedShape::compound(shapes)
+            }
+        };
+
+        Ok(shape)
+    }
+
+    /// Describes the given shape.
+    ///
+    /// Convex polygons and polyhedra are described by their vertices, and rebuilt by
+    /// computing their convex hull. Custom shapes aren’t supported.
+    pub fn from_shape(shape: &dyn Shape) -> Result<Self, SceneError> {
+        let desc = match shape.shape_type() {
+            ShapeType::Ball => ShapeDesc::Ball {
+                radius: shape.as_ball().unwrap().radius,
+            },
+            ShapeType::Cuboid => ShapeDesc::Cuboid {
+                half_extents: shape.as_cuboid().unwrap().half_extents,
+            },
+            ShapeType::RoundCuboid => {
+                let s = shape.as_round_cuboid().unwrap();
+                ShapeDesc::RoundCuboid {
+                    half_extents: s.base_shape.half_extents,
+                    border_radius: s.border_radius,
+                }
+            }
+            ShapeType::Capsule => {
+                let s = shape.as_capsule().unwrap();
+                ShapeDesc::Capsule {
+                    a: s.segment.a,
+                    b: s.segment.b,
+                    radius: s.radius,
+                }
+            }
+            ShapeType::Segment => {
+                let s = shape.as_segment().unwrap();
+                ShapeDesc::Segment { a: s.a, b: s.b }
+            }
+            ShapeType::Triangle => {
+                let s = shape.as_triangle().unwrap();
+                ShapeDesc::Triangle {
+                    a: s.a,
+                    b: s.b,
+                    c: s.c,
+                }
+            }
+            ShapeType::RoundTriangle => {
+                let s = shape.as_round_triangle().unwrap();
+                ShapeDesc::RoundTriangle {
+                    a: s.base_shape.a,
+                    b: s.base_shape.b,
+                    c: s.base_shape.c,
+                    border_radius: s.border_radius,
+                }
+            }
+            ShapeType::TriMesh => {
+                let s = shape.as_trimesh().unwrap();
+                ShapeDesc::TriMesh {
+                    vertices: s.vertices().to_vec(),
+                    indices: s.indices().to_vec(),
+                }
+            }
+            ShapeType::Polyline => {
+                let s = shape.as_polyline().unwrap();
+                ShapeDesc::Polyline {
+                    vertices: s.vertices().to_vec(),
+                    indices: Some(s.indices().to_vec()),
+                }
+            }
+            ShapeType::HalfSpace => ShapeDesc::HalfSpace {
+                normal: *shape.as_halfspace().unwrap().normal,
+            },
+            ShapeType::HeightField => {
+                let s = shape.as_heightfield().unwrap();
+                ShapeDesc::HeightField {
+                    heights: s.heights().clone(),
+                    scale: *s.scale(),
+                }
+            }
+            #[cfg(feature = "dim2")]
+            ShapeType::ConvexPolygon => ShapeDesc::ConvexHull {
+                points: shape.as_convex_polygon().unwrap().points().to_vec(),
+            },
+            #[cfg(feature = "dim2")]
+            ShapeType::RoundConvexPolygon => {
+                let s = shape.as_round_convex_polygon().unwrap();
+                ShapeDesc::RoundConvexHull {
+                    points: s.base_shape.points().to_vec(),
+                    border_radius: s.border_radius,
+                }
+            }
+            #[cfg(feature = "dim3")]
+            ShapeType::ConvexPolyhedron => ShapeDesc::ConvexHull {
+                points: shape.as_convex_polyhedron().unwrap().points().to_vec(),
+            },
+            #[cfg(feature = "dim3")]
+            ShapeType::RoundConvexPolyhedron => {
+                let s = shape.as_round_convex_polyhedron().unwrap();
+                ShapeDesc::RoundConvexHull {
+                    points: s.base_shape.points().to_vec(),
+                    border_radius: s.border_radius,
+                }
+            }
+            #[cfg(feature = "dim3")]
+            ShapeType::Cylinder => {
+                let s = shape.as_cylinder().unwrap();
+                ShapeDesc::Cylinder {
+                    half_height: s.half_height,
+                    radius: s.radius,
+                }
+            }
+            #[cfg(feature = "dim3")]
+            ShapeType::RoundCylinder => {
+                let s = shape.as_round_cylinder().unwrap();
+                ShapeDesc::RoundCylinder {
+                    half_height: s.base_shape.half_height,
+                    radius: s.base_shape.radius,
+                    border_radius: s.border_radius,
+                }
+            }
+            #[cfg(feature = "dim3")]
+            ShapeType::Cone => {
+                let s = shape.as_cone().unwrap();
+                ShapeDesc::Cone {
+                    half_height: s.half_height,
+                    radius: s.radius,
+                }
+            }
+            #[cfg(feature = "dim3")]
+            ShapeType::RoundCone => {
+                let s = shape.as_round_cone().unwrap();
+                ShapeDesc::RoundCone {
+                    half_height: s.base_shape.half_height,
+                    radius: s.base_shape.radius,
+                    border_radius: s.border_radius,
+                }
+            }
+            ShapeType::Compound => {
+                let shapes = shape
+                    .as_compound()
+                    .unwrap()
+                    .shapes()
+                    .iter()
+                    .map(|(pos, shape)| Ok((*pos, Self::from_shape(&**shape)?)))
+                    .collect::<Result<_, SceneError>>()?;
+                ShapeDesc::Compound { shapes }
+            }
+            shape_type => return Err(SceneError::UnsupportedShape(shape_type)),
+        };
+
+        Ok(desc)
+    }
+}
+
+/// The description of a rigid-body.
+///
+/// Missing fields are set to the default values of [`RigidBodyBuilder`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RigidBodyDesc {
+    /// The type of the rigid-body.
+    pub body_type: RigidBodyType,
+    /// The world-space position of the rigid-body.
+    pub position: Isometry<Real>,
+    /// The linear velocity of the rigid-body.
+    pub linvel: Vector<Real>,
+    /// The angular velocity of the rigid-body.
+    pub angvel: AngVector<Real>,
+    /// The scale factor applied to the gravity affecting the rigid-body.
+    pub gravity_scale: Real,
+    /// The linear damping of the rigid-body.
+    pub linear_damping: Real,
+    /// The angular damping of the rigid-body.
+    pub angular_damping: Real,
+    /// The degrees of freedom of the rigid-body that are locked.
+    pub locked_axes: LockedAxes,
+    /// The mass properties added to the ones computed from the colliders of the rigid-body.
+    pub additional_mass_properties: MassProperties,
+    /// Can the rigid-body fall asleep?
+    pub can_sleep: bool,
+    /// Is the rigid-body sleeping?
+    pub sleeping: bool,
+    /// Is continuous collision-detection enabled for the rigid-body?
+    pub ccd_enabled: bool,
+    /// The dominance group of the rigid-body.
+    pub dominance_group: i8,
+    /// The user-data of the rigid-body.
+    pub user_data: u128,
+}
+
+impl Default for RigidBodyDesc {
+    fn default() -> Self {
+        Self::from_rigid_body(&RigidBodyBuilder::dynamic().build(), &ColliderSet::new())
+    }
+}
+
+impl RigidBodyDesc {
+    /// Describes the given rigid-body, attached to the given colliders.
+    pub fn from_rigid_body(body: &RigidBody, colliders: &ColliderSet) -> Self {
+        // The mass properties of the rigid-body include the contributions of its colliders.
+        // Remove them to retrieve the additional mass properties set by the user.
+        let mut additional_mass_properties = *body.mass_properties();
+        let mut colliders_mass = 0.0;
+
+        for handle in body.colliders() {
+            if let Some(co) = colliders.get(*handle) {
+                let co_mprops = co
+                    .mass_properties()
+                    .transform_by(co.position_wrt_parent().unwrap());
+                colliders_mass += utils::inv(co_mprops.inv_mass);
+                additional_mass_properties -= co_mprops;
+            }
+        }
+
+        // Don’t turn round-off errors into a tiny extra mass.
+        if utils::inv(additional_mass_properties.inv_mass) <= colliders_mass * 1.0e-5 {
+            additional_mass_properties = MassProperties::new(Point::origin(), 0.0, na::zero());
+        }
+
+        Self {
+            body_type: body.body_type(),
+            position: *body.position(),
+            linvel: *body.linvel(),
+            angvel: body.rb_vels.angvel,
+            gravity_scale: body.gravity_scale(),
+            linear_damping: body.linear_damping(),
+            angular_damping: body.angular_damping(),
+            locked_axes: body.rb_mprops.flags,
+            additional_mass_properties,
+            can_sleep: body.activation().linear_threshold >= 0.0,
+            sleeping: body.is_sleeping(),
+            ccd_enabled: body.is_ccd_enabled(),
+            dominance_group: body.dominance_group(),
+            user_data: body.user_data,
+        }
+    }
+
+    /// Builds the rigid-body described by `self`.
+    pub fn build(&self) -> RigidBody {
+        RigidBodyBuilder::new(self.body_type)
+            .position(self.position)
+            .linvel(self.linvel)
+            .angvel(self.angvel)
+            .gravity_scale(self.gravity_scale)
+            .linear_damping(self.linear_damping)
+            .angular_damping(self.angular_damping)
+            .locked_axes(self.locked_axes)
+            .additional_mass_properties(self.additional_mass_properties)
+            .can_sleep(self.can_sleep)
+            .sleeping(self.sleeping)
+            .ccd_enabled(self.ccd_enabled)
+            .dominance_group(self.dominance_group)
+            .user_data(self.user_data)
+            .build()
+    }
+}
+
+/// The description of a collider.
+///
+/// Missing fields, except the shape, are set to the default values of [`ColliderBuilder`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ColliderDesc {
+    /// The shape of the collider.
+    pub shape: ShapeDesc,
+    /// The index, in [`SceneDesc::bodies`], of the rigid-body this collider is attached to.
+    #[serde(default)]
+    pub parent: Option<usize>,
+    /// The position of the collider relative to its parent, or its world-space position if it
+    /// doesn’t have a parent.
+    #[serde(default = "Isometry::identity")]
+    pub position: Isometry<Real>,
+    /// The density of the collider. Ignored if `mass_properties` is set.
+    #[serde(default)]
+    pub density: Option<Real>,
+    /// The mass properties of the collider, overriding the ones computed from its shape.
+    #[serde(default)]
+    pub mass_properties: Option<MassProperties>,
+    /// The friction coefficient of the collider.
+    #[serde(default = "ColliderBuilder::default_friction")]
+    pub friction: Real,
+    /// The rule used to combine two friction coefficients.
+    #[serde(default)]
+    pub friction_combine_rule: CoefficientCombineRule,
+    /// The restitution coefficient of the collider.
+    #[serde(default)]
+    pub restitution: Real,
+    /// The rule used to combine two restitution coefficients.
+    #[serde(default)]
+    pub restitution_combine_rule: CoefficientCombineRule,
+    /// Is the collider a sensor?
+    #[serde(default)]
+    pub is_sensor: bool,
+    /// The collision groups of the collider.
+    #[serde(default = "InteractionGroups::all")]
+    pub collision_groups: InteractionGroups,
+    /// The solver groups of the collider.
+    #[serde(default = "InteractionGroups::all")]
+    pub solver_groups: InteractionGroups,
+    /// The contact pairs enabled for the collider.
+    #[serde(default)]
+    pub active_collision_types: ActiveCollisionTypes,
+    /// The physics hooks enabled for the collider.
+    #[serde(default = "ActiveHooks::empty")]
+    pub active_hooks: ActiveHooks,
+    /// The events enabled for the collider.
+    #[serde(default = "ActiveEvents::empty")]
+    pub active_events: ActiveEvents,
+    /// The total force magnitude beyond which a contact force event can be emitted.
+    #[serde(default = "default_contact_force_event_threshold")]
+    pub contact_force_event_threshold: Real,
+    /// The user-data of the collider.
+    #[serde(default)]
+    pub user_data: u128,
+}
+
+fn default_contact_force_event_threshold() -> Real {
+    Real::MAX
+}
+
+impl ColliderDesc {
+    /// Describes the given collider.
+    ///
+    /// The `parent` is the index of the collider’s parent in [`SceneDesc::bodies`].
+    pub fn from_collider(collider: &Collider, parent: Option<usize>) -> Result<Self, SceneError> {
+        let (density, mass_properties) = match &collider.co_mprops {
+            ColliderMassProps::Density(density) => (Some(*density), None),
+            ColliderMassProps::MassProperties(mprops) => (None, Some(**mprops)),
+        };
+        let position = if parent.is_some() {
+            collider.position_wrt_parent().copied()
+        } else {
+            None
+        };
+
+        Ok(Self {
+            shape: ShapeDesc::from_shape(collider.shape())?,
+            parent,
+            position: position.unwrap_or(*collider.position()),
+            density,
+            mass_properties,
+            friction: collider.friction(),
+            friction_combine_rule: collider.friction_combine_rule(),
+            restitution: collider.restitution(),
+            restitution_combine_rule: collider.restitution_combine_rule(),
+            is_sensor: collider.is_sensor(),
+            collision_groups: collider.collision_groups(),
+            solver_groups: collider.solver_groups(),
+            active_collision_types: collider.active_collision_types(),
+            active_hooks: collider.active_hooks(),
+            active_events: collider.active_events(),
+            contact_force_event_threshold: collider.contact_force_event_threshold(),
+            user_data: collider.user_data,
+        })
+    }
+
+    /// Builds the collider described by `self`.
+    ///
+    /// The parent of the collider is ignored: it must be passed to
+    /// [`ColliderSet::insert_with_parent`] when the collider is inserted.
+    pub fn build(&self) -> Result<Collider, SceneError> {
+        let mut builder = ColliderBuilder::new(self.shape.build()?)
+            .position(self.position)
+            .friction(self.friction)
+            .friction_combine_rule(self.friction_combine_rule)
+            .restitution(self.restitution)
+            .restitution_combine_rule(self.restitution_combine_rule)
+            .sensor(self.is_sensor)
+            .collision_groups(self.collision_groups)
+            .solver_groups(self.solver_groups)
+            .active_collision_types(self.active_collision_types)
+            .active_hooks(self.active_hooks)
+            .active_events(self.active_events)
+            .contact_force_event_threshold(self.contact_force_event_threshold)
+            .user_data(self.user_data);
+
+        if let Some(density) = self.density {
+            builder = builder.density(density);
+        }
+
+        if let Some(mprops) = self.mass_properties {
+            builder = builder.mass_properties(mprops);
+        }
+
+        Ok(builder.build())
+    }
+}
+
+/// The description of an impulse joint.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ImpulseJointDesc {
+    /// The index, in [`SceneDesc::bodies`], of the first rigid-body attached to the joint.
+    pub body1: usize,
+    /// The index, in [`SceneDesc::bodies`], of the second rigid-body attached to the joint.
+    pub body2: usize,
+    /// The joint.
+    pub data: GenericJoint,
+}
+
+/// The description of a joint of a multibody.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+pub struct MultibodyJointDesc {
+    /// The index, in [`SceneDesc::bodies`], of the parent rigid-body of the joint.
+    pub body1: usize,
+    /// The index, in [`SceneDesc::bodies`], of the child rigid-body of the joint.
+    pub body2: usize,
+    /// The joint, including its current coordinates.
+    ///
+    /// If the coordinates are omitted, the joint starts at its rest configuration.
+    pub joint: MultibodyJoint,
+}
+
+/// The description of a multibody.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MultibodyDesc {
+    /// The joints of the multibody.
+    ///
+    /// The parent rigid-body of a joint must be the root of the multibody, or the child of
+    /// one of the joints before it.
+    pub joints: Vec<MultibodyJointDesc>,
+    /// The generalized velocities of the multibody.
+    ///
+    /// If empty, or if its length doesn’t match the number of degrees of freedom of the
+    /// multibody, the multibody starts at rest.
+    #[serde(default)]
+    pub velocities: Vec<Real>,
+}
+
+/// A scene description, i.e., a whole physics world in a format suitable for authoring and
+/// sharing scenes.
+///
+/// The scene can be serialized to any format supported by `serde`, e.g., RON or JSON. Shapes
+/// are described by their constructor parameters, and objects refer to each other by their
+/// index in the scene, so the files remain human-readable and editable. Unlike
+/// [`PhysicsWorldSnapshot`](crate::pipeline::PhysicsWorldSnapshot), transient solver and
+/// collision-detection data aren’t saved.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SceneDesc {
+    /// The gravity applied to the simulated world.
+    #[serde(default = "Vector::zeros")]
+    pub gravity: Vector<Real>,
+    /// The integration parameters used for stepping the simulation.
+    #[serde(default)]
+    pub integration_parameters: IntegrationParameters,
+    /// The rigid-bodies of the scene.
+    #[serde(default)]
+    pub bodies: Vec<RigidBodyDesc>,
+    /// The colliders of the scene.
+    #[serde(default)]
+    pub colliders: Vec<ColliderDesc>,
+    /// The impulse joints of the scene.
+    #[serde(default)]
+    pub impulse_joints: Vec<ImpulseJointDesc>,
+    /// The multibodies of the scene.
+    #[serde(default)]
+    pub multibodies: Vec<MultibodyDesc>,
+}
+
+/// A physics world built from a [`SceneDesc`].
+pub struct SceneWorld {
+    /// The gravity applied to the simulated world.
+    pub gravity: Vector<Real>,
+    /// The integration parameters used for stepping the simulation.
+    pub integration_parameters: IntegrationParameters,
+    /// The set of rigid-bodies.
+    pub bodies: RigidBodySet,
+    /// The set of colliders.
+    pub colliders: ColliderSet,
+    /// The set of impulse joints.
+    pub impulse_joints: ImpulseJointSet,
+    /// The set of multibody joints.
+    pub multibody_joints: MultibodyJointSet,
+    /// The handle of each rigid-body, in the same order as [`SceneDesc::bodies`].
+    pub body_handles: Vec<RigidBodyHandle>,
+    /// The handle of each collider, in the same order as [`SceneDesc::colliders`].
+    pub collider_handles: Vec<ColliderHandle>,
+}
+
+impl SceneDesc {
+    /// Describes a whole physics world.
+    ///
+    /// Fails if a collider has a shape that can’t be described by a [`ShapeDesc`].
+    pub fn from_world(
+        gravity: &Vector<Real>,
+        integration_parameters: &IntegrationParameters,
+        bodies: &RigidBodySet,
+        colliders: &ColliderSet,
+        impulse_joints: &ImpulseJointSet,
+        multibody_joints: &MultibodyJointSet,
+    ) -> Result<Self, SceneError> {
+        let mut body_ids = HashMap::new();
+        let mut body_descs = vec![];
+
+        for (handle, body) in bodies.iter() {
+            let _ = body_ids.insert(handle, body_descs.len());
+            body_descs.push(RigidBodyDesc::from_rigid_body(body, colliders));
+        }
+
+        let collider_descs = colliders
+            .iter()
+            .map(|(_, co)| {
+                let parent = co.parent().and_then(|h| body_ids.get(&h).copied());
+                ColliderDesc::from_collider(co, parent)
+            })
+            .collect::<Result<_, _>>()?;
+
+        let impulse_joint_descs = impulse_joints
+            .iter()
+            .map(|(_, joint)| ImpulseJointDesc {
+                body1: body_ids[&joint.body1],
+                body2: body_ids[&joint.body2],
+                data: joint.data,
+            })
+            .collect();
+
+        let multibody_descs = multibody_joints
+            .multibodies()
+            .filter(|mb| mb.num_links() > 1)
+            .map(|mb| {
+                let joints = mb
+                    .links()
+                    .skip(1)
+                    .map(|link| {
+                        let parent = mb.link(link.parent_id().unwrap()).unwrap();
+                        MultibodyJointDesc {
+                            body1: body_ids[&parent.rigid_body_handle()],
+                            body2: body_ids[&link.rigid_body_handle()],
+                            joint: link.joint,
+                        }
+                    })
+                    .collect();
+
+                MultibodyDesc {
+                    joints,
+                    velocities: mb.generalized_velocity().iter().copied().collect(),
+                }
+            })
+            .collect();
+
+        Ok(Self {
+            gravity: *gravity,
+            integration_parameters: *integration_parameters,
+            bodies: body_descs,
+            colliders: collider_descs,
+            impulse_joints: impulse_joint_descs,
+            multibodies: multibody_descs,
+        })
+    }
+
+    /// Builds the physics world described by this scene.
+    pub fn build(&self) -> Result<SceneWorld, SceneError> {
+        let mut bodies = RigidBodySet::new();
+        let mut colliders = ColliderSet::new();
+        let mut impulse_joints = ImpulseJointSet::new();
+        let mut multibody_joints = MultibodyJointSet::new();
+
+        let body_handles: Vec<_> = self
+            .bodies
+            .iter()
+            .map(|desc| bodies.insert(desc.build()))
+            .collect();
+        let body_handle = |i: usize| {
+            body_handles
+                .get(i)
+                .copied()
+                .ok_or(SceneError::InvalidBodyIndex(i))
+        };
+
+        let mut collider_handles = Vec::with_capacity(self.colliders.len());
+
+        for desc in &self.colliders {
+            let collider = desc.build()?;
+            let handle = match desc.parent {
+                Some(parent) => {
+                    colliders.insert_with_parent(collider, body_handle(parent)?, &mut bodies)
+                }
+                None => colliders.insert(collider),
+            };
+            collider_handles.push(handle);
+        }
+
+        for desc in &self.impulse_joints {
+            let _ = impulse_joints.insert(
+                body_handle(desc.body1)?,
+                body_handle(desc.body2)?,
+                desc.data,
+            );
+        }
+
+        for desc in &self.multibodies {
+            for joint_desc in &desc.joints {
+                let handle = multibody_joints
+                    .insert(
+                        body_handle(joint_desc.body1)?,
+                        body_handle(joint_desc.body2)?,
+                        joint_desc.joint.data,
+                    )
+                    .ok_or(SceneError::InvalidMultibodyJoint {
+                        body1: joint_desc.body1,
+                        body2: joint_desc.body2,
+                    })?;
+
+                // Restore the joint coordinates.
+                let (mb, link_id) = multibody_joints.get_mut_internal(handle).unwrap();
+                mb.link_mut(link_id).unwrap().joint = joint_desc.joint;
+            }
+
+            if let Some(joint_desc) = desc.joints.first() {
+                let handle = MultibodyJointHandle(body_handle(joint_desc.body2)?.0);
+                let (mb, _) = multibody_joints.get_mut_internal(handle).unwrap();
+                // Don’t wait for the first timestep to remove the degrees of freedom of a
+                // fixed root, so the velocities match the exported ones.
+                mb.update_root_type(&mut bodies);
+
+                if mb.ndofs() == desc.velocities.len() {
+                    mb.generalized_velocity_mut()
+                        .copy_from_slice(&desc.velocities);
+                }
+            }
+        }
+
+        Ok(SceneWorld {
+            gravity: self.gravity,
+            integration_parameters: self.integration_parameters,
+            bodies,
+            colliders,
+            impulse_joints,
+            multibody_joints,
+            body_handles,
+            collider_handles,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SceneDesc;
+    use crate::dynamics::{JointAxesMask, RevoluteJointBuilder, RigidBodyBuilder, RigidBodyType};
+    use crate::geometry::ColliderBuilder;
+    use crate::math::{Real, Vector};
+    use crate::test_utils::{cuboid, TestWorld};
+
+    fn export_to_json(world: &TestWorld) -> String {
+        let scene = SceneDesc::from_world(
+            &world.gravity,
+            &world.integration_parameters,
+            &world.bodies,
+            &world.colliders,
+            &world.impulse_joints,
+            &world.multibody_joints,
+        )
+        .unwrap();
+        serde_json::to_string_pretty(&scene).unwrap()
+    }
+
+    #[test]
+    fn scene_export_and_build() {
+        let mut world = TestWorld::new();
+        let ground = world.bodies.insert(RigidBodyBuilder::fixed().build());
+        world.colliders.insert_with_parent(
+            cuboid(10.0, 0.1, 10.0).build(),
+            ground,
+            &mut world.bodies,
+        );
+        let body = world.bodies.insert(
+            RigidBodyBuilder::dynamic()
+                .translation(Vector::y() * 2.0)
+                .additional_mass(3.0)
+                .build(),
+        );
+        world.colliders.insert_with_parent(
+            ColliderBuilder::ball(0.5).friction(0.2).build(),
+            body,
+            &mut world.bodies,
+        );
+        #[cfg(feature = "dim2")]
+        let joint = RevoluteJointBuilder::new();
+        #[cfg(feature = "dim3")]
+        let joint = RevoluteJointBuilder::new(Vector::z_axis());
+        world.impulse_joints.insert(ground, body, joint);
+
+        let scene: SceneDesc = serde_json::from_str(&export_to_json(&world)).unwrap();
+        let rebuilt = scene.build().unwrap();
+
+        assert_eq!(rebuilt.gravity, world.gravity);
+        assert_eq!(rebuilt.bodies.len(), 2);
+        assert_eq!(rebuilt.colliders.len(), 2);
+        assert_eq!(rebuilt.impulse_joints.len(), 1);
+
+        let rebuilt_body = &rebuilt.bodies[rebuilt.body_handles[1]];
+        assert_eq!(rebuilt_body.position(), world.bodies[body].position());
+        assert!((rebuilt_body.mass() - world.bodies[body].mass()).abs() < 1.0e-4);
+
+        let collider = &rebuilt.colliders[rebuilt.collider_handles[1]];
+        assert_eq!(collider.parent(), Some(rebuilt.body_handles[1]));
+        assert_eq!(collider.friction(), 0.2);
+    }
+
+    #[test]
+    fn multibodies_survive_scene_export_and_build() {
+        let mut world = TestWorld::new();
+        world.add_multibody_arm(Vector::zeros(), 2);
+        // Let the arm swing so its coordinates and velocities aren’t zero.
+        world.step_n(20);
+
+        let scene: SceneDesc = serde_json::from_str(&export_to_json(&world)).unwrap();
+        let rebuilt = scene.build().unwrap();
+
+        let original = world.multibody_joints.multibodies().next().unwrap();
+        let mut multibodies = rebuilt.multibody_joints.multibodies();
+        let multibody = multibodies.next().unwrap();
+        assert!(multibodies.next().is_none());
+        assert_eq!(multibody.num_links(), 3);
+        assert_eq!(multibody.ndofs(), original.ndofs());
+        assert!(original.generalized_velocity().norm() > 0.1);
+        assert_eq!(
+            multibody.generalized_velocity(),
+            original.generalized_velocity()
+        );
+
+        for (link, original_link) in multibody.links().zip(original.links()) {
+            assert_eq!(link.joint.coords, original_link.joint.coords);
+            assert_eq!(
+                rebuilt.bodies[link.rigid_body_handle()].position(),
+                world.bodies[original_link.rigid_body_handle()].position()
+            );
+        }
+    }
+
+    #[test]
+    fn authored_scene_with_omitted_fields() {
+        // A ball attached to a fixed body by a revolute multibody joint. Only the fields
+        // differing from their default value are given.
+        const SCENE: &str = r#"{
+            "bodies": [
+                { "body_type": "Fixed" },
+                { "gravity_scale": 0.5, "user_data": 7 }
+            ],
+            "colliders": [
+                { "shape": { "Ball": { "radius": 0.5 } }, "parent": 1, "friction": 0.2 }
+            ],
+            "multibodies": [
+                {
+                    "joints": [
+                        {
+                            "body1": 0,
+                            "body2": 1,
+                            "joint": { "data": { "locked_axes": { "bits": LOCKED_AXES } } }
+                        }
+                    ]
+                }
+            ]
+        }"#;
+        let locked_axes = JointAxesMask::LOCKED_REVOLUTE_AXES;
+        let json = SCENE.replace("LOCKED_AXES", &locked_axes.bits().to_string());
+
+        let scene: SceneDesc = serde_json::from_str(&json).unwrap();
+        let world = scene.build().unwrap();
+
+        assert_eq!(world.gravity, Vector::zeros());
+        let root = &world.bodies[world.body_handles[0]];
+        let ball = &world.bodies[world.body_handles[1]];
+        assert_eq!(root.body_type(), RigidBodyType::Fixed);
+        assert_eq!(ball.body_type(), RigidBodyType::Dynamic);
+        assert_eq!(ball.gravity_scale(), 0.5);
+        assert_eq!(ball.user_data, 7);
+        assert!(ball.activation().linear_threshold >= 0.0);
+
+        let collider = &world.colliders[world.collider_handles[0]];
+        assert_eq!(collider.parent(), Some(world.body_handles[1]));
+        assert_eq!(collider.friction(), 0.2);
+        assert_eq!(collider.restitution(), 0.0);
+        assert_eq!(collider.contact_force_event_threshold(), Real::MAX);
+
+        let multibody = world.multibody_joints.multibodies().next().unwrap();
+        assert_eq!(multibody.num_links(), 2);
+        assert_eq!(multibody.ndofs(), 1);
+        let link = multibody.link(1).unwrap();
+        assert_eq!(link.joint.data.locked_axes, locked_axes);
+        assert!(link.joint.data.is_enabled());
+    }
+}
@@ -0,0 +1,179 @@
+//! Helpers shared by the unit tests.
+//!
+//! Most behavioral tests only need a world to step, and differ by the few bodies and joints they
+//! add to it. [`TestWorld`] owns all the sets required by `PhysicsPipeline::step` so that each
+//! test only spells out its own setup, and lives next to the code it exercises rather than in
+//! `physics_pipeline.rs`.
+
+// Not every test configuration uses every helper.
+#![allow(dead_code)]
+
+use crate::dynamics::{
+    CCDSolver, ImpulseJointSet, IntegrationParameters, IslandManager, MultibodyJointHandle,
+    MultibodyJointSet, RevoluteJointBuilder, RigidBodyBuilder, RigidBodyHandle, RigidBodySet,
+};
+use crate::geometry::{BroadPhase, ColliderBuilder, ColliderSet, NarrowPhase};
+use crate::math::{Point, Real, Vector};
+use crate::pipeline::{EventHandler, PhysicsPipeline, QueryPipeline};
+
+/// A cuboid collider builder with the given half-extents. `hz` is ignored in 2D.
+pub(crate) fn cuboid(hx: Real, hy: Real, hz: Real) -> ColliderBuilder {
+    #[cfg(feature = "dim2")]
+    {
+        let _ = hz;
+        ColliderBuilder::cuboid(hx, hy)
+    }
+    #[cfg(feature = "dim3")]
+    {
+        ColliderBuilder::cuboid(hx, hy, hz)
+    }
+}
+
+/// A physics world with all the sets required to step it.
+pub(crate) struct TestWorld {
+    pub pipeline: PhysicsPipeline,
+    pub gravity: Vector<Real>,
+    pub integration_parameters: IntegrationParameters,
+    pub islands: IslandManager,
+    pub broad_phase: BroadPhase,
+    pub narrow_phase: NarrowPhase,
+    pub bodies: RigidBodySet,
+    pub colliders: ColliderSet,
+    pub impulse_joints: ImpulseJointSet,
+    pub multibody_joints: MultibodyJointSet,
+    pub ccd_solver: CCDSolver,
+    pub query_pipeline: QueryPipeline,
+}
+
+impl TestWorld {
+    /// An empty world with the default gravity of `-9.81` along the `Y` axis.
+    pub fn new() -> Self {
+        Self {
+            pipeline: PhysicsPipeline::new(),
+            gravity: Vector::y() * -9.81,
+            integration_parameters: IntegrationParameters::default(),
+            islands: IslandManager::new(),
+            broad_phase: BroadPhase::new(),
+            narrow_phase: NarrowPhase::new(),
+            bodies: RigidBodySet::new(),
+            colliders: ColliderSet::new(),
+            impulse_joints: ImpulseJointSet::new(),
+            multibody_joints: MultibodyJointSet::new(),
+            ccd_solver: CCDSolver::new(),
+            query_pipeline: QueryPipeline::new(),
+        }
+    }
+
+    /// An empty world without gravity.
+    pub fn without_gravity() -> Self {
+        Self {
+            gravity: Vector::zeros(),
+            ..Self::new()
+        }
+    }
+
+    /// Adds a fixed rigid-body with a large flat collider whose top face is at `y = 0`.
+    pub fn add_ground(&mut self) -> RigidBodyHandle {
+        let ground = self.bodies.insert(
+            RigidBodyBuilder::fixed()
+                .translation(Vector::y() * -0.1)
+                .build(),
+        );
+        self.colliders.insert_with_parent(
+            cuboid(10.0, 0.1, 10.0).build(),
+            ground,
+            &mut self.bodies,
+        );
+        ground
+    }
+
+    /// Adds a dynamic rigid-body with a ball collider of the given radius.
+    pub fn add_ball(&mut self, translation: Vector<Real>, radius: Real) -> RigidBodyHandle {
+        let body = self
+            .bodies
+            .insert(RigidBodyBuilder::dynamic().translation(translation).build());
+        self.colliders.insert_with_parent(
+            ColliderBuilder::ball(radius).build(),
+            body,
+            &mut self.bodies,
+        );
+        body
+    }
+
+    /// Adds a fixed root at `root` and a chain of `num_links` links of length `1` along the
+    /// `X` axis, attached with revolute multibody joints rotating around the `Z` axis.
+    ///
+    /// Each link is a dynamic rigid-body with a ball collider of radius `0.2` at its tip.
+    /// Returns the handles of the joints, from the root to the tip of the chain.
+    pub fn add_multibody_arm(
+        &mut self,
+        root: Vector<Real>,
+        num_links: usize,
+    ) -> Vec<MultibodyJointHandle> {
+        let mut parent = self
+            .bodies
+            .insert(RigidBodyBuilder::fixed().translation(root).build());
+
+        (1..=num_links)
+            .map(|i| {
+                let link = self.bodies.insert(
+                    RigidBodyBuilder::dynamic()
+                        .translation(root + Vector::x() * i as Real)
+                        .build(),
+                );
+                self.colliders.insert_with_parent(
+                    ColliderBuilder::ball(0.2).build(),
+                    link,
+                    &mut self.bodies,
+                );
+
+                #[cfg(feature = "dim2")]
+                let joint = RevoluteJointBuilder::new();
+                #[cfg(feature = "dim3")]
+                let joint = RevoluteJointBuilder::new(Vector::z_axis());
+                let handle = self
+                    .multibody_joints
+                    .insert(parent, link, joint.local_anchor2(Point::from(-Vector::x())))
+                    .unwrap();
+                parent = link;
+                handle
+            })
+            .collect()
+    }
+
+    /// Runs one timestep.
+    pub fn step(&mut self) {
+        self.step_with_events(&());
+    }
+
+    /// Runs `n` timesteps.
+    pub fn step_n(&mut self, n: usize) {
+        for _ in 0..n {
+            self.step();
+        }
+    }
+
+    /// Runs one timestep, sending the events to `events`.
+    pub fn step_with_events(&mut self, events: &dyn EventHandler) {
+        self.pipeline.step(
+            &self.gravity,
+            &self.integration_parameters,
+            &mut self.islands,
+            &mut self.broad_phase,
+            &mut self.narrow_phase,
+            &mut self.bodies,
+            &mut self.colliders,
+            &mut self.impulse_joints,
+            &mut self.multibody_joints,
+            &mut self.ccd_solver,
+            &(),
+            events,
+        );
+    }
+
+    /// Updates the query pipeline with the current positions of the colliders.
+    pub fn update_query_pipeline(&mut self) {
+        self.query_pipeline
+            .update(&self.islands, &self.bodies, &self.colliders);
+    }
+}
@@ -0,0 +1,11 @@
+//! Loading of robots described with the Unified Robot Description Format (URDF).
+//!
+//! This module is only available when the `urdf` feature is enabled.
+
+pub use self::urdf_robot::{
+    UrdfJoint, UrdfLink, UrdfLinkHandle, UrdfLoaderError, UrdfLoaderOptions, UrdfMeshShape,
+    UrdfRobot, UrdfRobotHandles,
+};
+pub use urdf_rs;
+
+mod urdf_robot;
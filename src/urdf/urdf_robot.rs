@@ -0,0 +1,679 @@
+use crate::dynamics::{
+    GenericJoint, GenericJointBuilder, ImpulseJointHandle, ImpulseJointSet, JointAxesMask,
+    JointAxis, MassProperties, MultibodyJointHandle, MultibodyJointSet, RigidBody,
+    RigidBodyBuilder, RigidBodyHandle, RigidBodySet, RigidBodyType,
+};
+use crate::geometry::{Collider, ColliderBuilder, ColliderHandle, ColliderSet};
+use crate::math::{Isometry, Matrix, Point, Real, Rotation, Vector};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use urdf_rs::{Geometry, JointType, Pose};
+
+/// An error that occurred while loading a URDF robot.
+#[derive(Debug)]
+pub enum UrdfLoaderError {
+    /// The URDF description could not be read or parsed.
+    Urdf(urdf_rs::UrdfError),
+    /// A mesh file referenced by the URDF description could not be read.
+    Mesh {
+        /// The path of the mesh file.
+        path: PathBuf,
+        /// A description of the error.
+        message: String,
+    },
+    /// A mesh file referenced by the URDF description has an unsupported format.
+    ///
+    /// Only STL and OBJ files are supported.
+    UnsupportedMeshFormat(PathBuf),
+    /// A joint references a link that doesn’t exist.
+    UnknownLink(String),
+}
+
+impl fmt::Display for UrdfLoaderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            UrdfLoaderError::Urdf(e) => write!(f, "invalid URDF description: {}", e),
+            UrdfLoaderError::Mesh { path, message } => {
+                write!(f, "failed to load the mesh {}: {}", path.display(), message)
+            }
+            UrdfLoaderError::UnsupportedMeshFormat(path) => {
+                write!(f, "unsupported mesh format: {}", path.display())
+            }
+            UrdfLoaderError::UnknownLink(name) => write!(f, "unknown link: {}", name),
+        }
+    }
+}
+
+impl std::error::Error for UrdfLoaderError {}
+
+impl From<urdf_rs::UrdfError> for UrdfLoaderError {
+    fn from(e: urdf_rs::UrdfError) -> Self {
+        UrdfLoaderError::Urdf(e)
+    }
+}
+
+/// The shape of the colliders generated from the meshes of a URDF description.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum UrdfMeshShape {
+    /// The mesh is converted to a triangle mesh.
+    ///
+    /// Triangle meshes don’t contribute to the mass of the rigid-bodies, and don’t collide
+    /// with each other.
+    TriMesh,
+    /// The mesh is replaced by its convex hull.
+    ConvexHull,
+    /// The mesh is decomposed into convex parts.
+    ConvexDecomposition,
+}
+
+/// Options for loading a URDF robot.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct UrdfLoaderOptions {
+    /// Should colliders be created from the `<collision>` elements of the links?
+    pub create_colliders: bool,
+    /// Should the mass properties of the rigid-bodies be set from the `<inertial>` elements of the
+    /// links?
+    ///
+    /// If `true`, the colliders are given a zero density so they don’t contribute to the mass
+    /// of the rigid-bodies.
+    pub apply_imported_mass_props: bool,
+    /// Should the rigid-bodies of the root links be fixed?
+    ///
+    /// This is typically what you want for robotic arms bolted to the ground.
+    pub make_roots_fixed: bool,
+    /// The shape of the colliders generated from meshes.
+    pub mesh_shape: UrdfMeshShape,
+    /// The world-space pose of the root links of the robot.
+    pub shift: Isometry<Real>,
+}
+
+impl Default for UrdfLoaderOptions {
+    fn default() -> Self {
+        Self {
+            create_colliders: true,
+            apply_imported_mass_props: true,
+            make_roots_fixed: false,
+            mesh_shape: UrdfMeshShape::ConvexHull,
+            shift: Isometry::identity(),
+        }
+    }
+}
+
+/// A link of a URDF robot, converted to a rigid-body and its colliders.
+#[derive(Clone)]
+pub struct UrdfLink {
+    /// The name of the link.
+    pub name: String,
+    /// The rigid-body of the link.
+    pub body: RigidBody,
+    /// The colliders attached to the rigid-body of the link.
+    pub colliders: Vec<Collider>,
+}
+
+/// A joint of a URDF robot.
+#[derive(Copy, Clone, Debug)]
+pub struct UrdfJoint {
+    /// The joint, with the URDF limits.
+    pub joint: GenericJoint,
+    /// The index of the parent link in `UrdfRobot::links`.
+    pub link1: usize,
+    /// The index of the child link in `UrdfRobot::links`.
+    pub link2: usize,
+}
+
+/// A robot loaded from a URDF description, not yet added to the physics world.
+///
+/// The rigid-bodies, colliders, and joints of the robot can be modified before they are
+/// inserted into the physics sets with [`UrdfRobot::insert_using_impulse_joints`] or
+/// [`UrdfRobot::insert_using_multibody_joints`].
+#[derive(Clone)]
+pub struct UrdfRobot {
+    /// The links of the robot.
+    pub links: Vec<UrdfLink>,
+    /// The joints of the robot, identified by their name.
+    ///
+    /// Floating joints are ignored since they don’t constrain the motion of their links.
+    pub joints: HashMap<String, UrdfJoint>,
+}
+
+/// The handles of the rigid-body and colliders created for a link of a URDF robot.
+#[derive(Clone, Debug)]
+pub struct UrdfLinkHandle {
+    /// The handle of the rigid-body of the link.
+    pub body: RigidBodyHandle,
+    /// The handles of the colliders attached to the rigid-body of the link.
+    pub colliders: Vec<ColliderHandle>,
+}
+
+/// The handles of all the physics objects created for a URDF robot.
+#[derive(Clone, Debug)]
+pub struct UrdfRobotHandles<JointHandle> {
+    /// The handles of the links, identified by their name.
+    pub links: HashMap<String, UrdfLinkHandle>,
+    /// The handles of the joints, identified by their name.
+    pub joints: HashMap<String, JointHandle>,
+}
+
+impl UrdfRobot {
+    /// Loads a robot from a URDF file.
+    ///
+    /// The relative paths of the mesh files are resolved from the directory containing the
+    /// URDF file.
+    pub fn from_file(
+        path: impl AsRef<Path>,
+        options: &UrdfLoaderOptions,
+    ) -> Result<Self, UrdfLoaderError> {
+        let path = path.as_ref();
+        let robot = urdf_rs::read_file(path)?;
+        let mesh_dir = path.parent().unwrap_or_else(|| Path::new("."));
+        Self::from_robot(&robot, options, mesh_dir)
+    }
+
+    /// Converts an already parsed URDF description to a robot.
+    ///
+    /// The relative paths of the mesh files are resolved from `mesh_dir`.
+    pub fn from_robot(
+        robot: &urdf_rs::Robot,
+        options: &UrdfLoaderOptions,
+        mesh_dir: &Path,
+    ) -> Result<Self, UrdfLoaderError> {
+        let link_ids: HashMap<&str, usize> = robot
+            .links
+            .iter()
+            .enumerate()
+            .map(|(i, link)| (link.name.as_str(), i))
+            .collect();
+        let link_id = |name: &str| {
+            link_ids
+                .get(name)
+                .copied()
+                .ok_or_else(|| UrdfLoaderError::UnknownLink(name.to_string()))
+        };
+
+        // The joint attaching each link to its parent, if any.
+        let mut parent_joints = vec![None; robot.links.len()];
+        for joint in &robot.joints {
+            parent_joints[link_id(&joint.child.link)?] = Some(joint);
+        }
+
+        let mut links = Vec::with_capacity(robot.links.len());
+
+        for (i, link) in robot.links.iter().enumerate() {
+            // Accumulate the joint origins up to the root.
+            let mut pose = Isometry::identity();
+            let mut curr = i;
+            let mut depth = 0;
+
+            while let Some(joint) = parent_joints[curr] {
+                pose = pose_to_isometry(&joint.origin) * pose;
+                curr = link_id(&joint.parent.link)?;
+                depth += 1;
+
+                assert!(
+                    depth <= robot.links.len(),
+                    "The links of a URDF robot must form a tree."
+                );
+            }
+
+            let is_root = parent_joints[i].is_none();
+            let body_type = if is_root && options.make_roots_fixed {
+                RigidBodyType::Fixed
+            } else {
+                RigidBodyType::Dynamic
+            };
+            let mut body = RigidBodyBuilder::new(body_type).position(options.shift * pose);
+
+            if options.apply_imported_mass_props {
+                body = body.additional_mass_properties(urdf_to_mass_properties(&link.inertial));
+            }
+
+            let mut colliders = vec![];
+
+            if options.create_colliders {
+                for collision in &link.collision {
+                    let builder =
+                        urdf_to_collider(&collision.geometry, options.mesh_shape, mesh_dir)?;
+
+                    if let Some(builder) = builder {
+                        let shape_pos = builder.position;
+                        let mut builder =
+                            builder.position(pose_to_isometry(&collision.origin) * shape_pos);
+
+                        if options.apply_imported_mass_props {
+                            builder = builder.density(0.0);
+                        }
+
+                        colliders.push(builder.build());
+                    }
+                }
+            }
+
+            links.push(UrdfLink {
+                name: link.name.clone(),
+                body: body.build(),
+                colliders,
+            });
+        }
+
+        let mut joints = HashMap::new();
+
+        for joint in &robot.joints {
+            if let Some(generic_joint) = urdf_to_joint(joint) {
+                let _ = joints.insert(
+                    joint.name.clone(),
+                    UrdfJoint {
+                        joint: generic_joint,
+                        link1: link_id(&joint.parent.link)?,
+                        link2: link_id(&joint.child.link)?,
+                    },
+                );
+            }
+        }
+
+        Ok(Self { links, joints })
+    }
+
+    /// Inserts the robot into the physics sets, using impulse joints for its joints.
+    pub fn insert_using_impulse_joints(
+        self,
+        bodies: &mut RigidBodySet,
+        colliders: &mut ColliderSet,
+        impulse_joints: &mut ImpulseJointSet,
+    ) -> UrdfRobotHandles<ImpulseJointHandle> {
+        let (link_handles, links) = Self::insert_links(self.links, bodies, colliders);
+        let joints = self
+            .joints
+            .into_iter()
+            .map(|(name, joint)| {
+                let handle = impulse_joints.insert(
+                    link_handles[joint.link1],
+                    link_handles[joint.link2],
+                    joint.joint,
+                );
+                (name, handle)
+            })
+            .collect();
+
+        UrdfRobotHandles { links, joints }
+    }
+
+    /// Inserts the robot into the physics sets, using multibody joints for its joints.
+    ///
+    /// A joint handle is `None` if the joint couldn’t be added to a multibody, e.g., because it
+    /// would introduce a kinematic loop.
+    pub fn insert_using_multibody_joints(
+        self,
+        bodies: &mut RigidBodySet,
+        colliders: &mut ColliderSet,
+        multibody_joints: &mut MultibodyJointSet,
+    ) -> UrdfRobotHandles<Option<MultibodyJointHandle>> {
+        let (link_handles, links) = Self::insert_links(self.links, bodies, colliders);
+        let joints = self
+            .joints
+            .into_iter()
+            .map(|(name, joint)| {
+                let handle = multibody_joints.insert(
+                    link_handles[joint.link1],
+                    link_handles[joint.link2],
+                    joint.joint,
+                );
+                (name, handle)
+            })
+            .collect();
+
+        UrdfRobotHandles { links, joints }
+    }
+
+    fn insert_links(
+        links: Vec<UrdfLink>,
+        bodies: &mut RigidBodySet,
+        colliders: &mut ColliderSet,
+    ) -> (Vec<RigidBodyHandle>, HashMap<String, UrdfLinkHandle>) {
+        let mut body_handles = Vec::with_capacity(links.len());
+        let mut link_handles = HashMap::new();
+
+        for link in links {
+            let body = bodies.insert(link.body);
+            let collider_handles = link
+                .colliders
+                .into_iter()
+                .map(|co| colliders.insert_with_parent(co, body, bodies))
+                .collect();
+
+            body_handles.push(body);
+            let _ = link_handles.insert(
+                link.name,
+                UrdfLinkHandle {
+                    body,
+                    colliders: collider_handles,
+                },
+            );
+        }
+
+        (body_handles, link_handles)
+    }
+}
+
+fn pose_to_isometry(pose: &Pose) -> Isometry<Real> {
+    let translation = Vector::new(
+        pose.xyz[0] as Real,
+        pose.xyz[1] as Real,
+        pose.xyz[2] as Real,
+    );
+    let rotation = Rotation::from_euler_angles(
+        pose.rpy[0] as Real,
+        pose.rpy[1] as Real,
+        pose.rpy[2] as Real,
+    );
+    Isometry::from_parts(translation.into(), rotation)
+}
+
+fn urdf_to_mass_properties(inertial: &urdf_rs::Inertial) -> MassProperties {
+    let frame = pose_to_isometry(&inertial.origin);
+    let inertia = &inertial.inertia;
+    let local_inertia = Matrix::new(
+        inertia.ixx as Real,
+        inertia.ixy as Real,
+        inertia.ixz as Real,
+        inertia.ixy as Real,
+        inertia.iyy as Real,
+        inertia.iyz as Real,
+        inertia.ixz as Real,
+        inertia.iyz as Real,
+        inertia.izz as Real,
+    );
+    let rot = frame.rotation.to_rotation_matrix();
+    let inertia = rot * local_inertia * rot.inverse();
+
+    MassProperties::with_inertia_matrix(
+        Point::from(frame.translation.vector),
+        inertial.mass.value as Real,
+        inertia,
+    )
+}
+
+fn urdf_to_collider(
+    geometry: &Geometry,
+    mesh_shape: UrdfMeshShape,
+    mesh_dir: &Path,
+) -> Result<Option<ColliderBuilder>, UrdfLoaderError> {
+    let builder = match geometry {
+        Geometry::Box { size } => ColliderBuilder::cuboid(
+            size[0] as Real / 2.0,
+            size[1] as Real / 2.0,
+            size[2] as Real / 2.0,
+        ),
+        Geometry::Cylinder { radius, length } => {
+            // URDF cylinders are aligned with the Z axis, but ours are aligned with the Y axis.
+            ColliderBuilder::cylinder(*length as Real / 2.0, *radius as Real).position(
+                Isometry::rotation(Vector::x() * std::f64::consts::FRAC_PI_2 as Real),
+            )
+        }
+        Geometry::Capsule { radius, length } => {
+            ColliderBuilder::capsule_z(*length as Real / 2.0, *radius as Real)
+        }
+        Geometry::Sphere { radius } => ColliderBuilder::ball(*radius as Real),
+        Geometry::Mesh { filename, scale } => {
+            let scale = scale
+                .as_ref()
+                .map(|s| Vector::new(s[0] as Real, s[1] as Real, s[2] as Real))
+                .unwrap_or_else(|| Vector::repeat(1.0));
+            let path = mesh_dir.join(filename.trim_start_matches("package://"));
+            let (vertices, indices) = load_mesh(&path, &scale)?;
+
+            match mesh_shape {
+                UrdfMeshShape::TriMesh => ColliderBuilder::trimesh(vertices, indices),
+                UrdfMeshShape::ConvexHull => match ColliderBuilder::convex_hull(&vertices) {
+                    Some(builder) => builder,
+                    None => return Ok(None),
+                },
+                UrdfMeshShape::ConvexDecomposition => {
+                    ColliderBuilder::convex_decomposition(&vertices, &indices)
+                }
+            }
+        }
+    };
+
+    Ok(Some(builder))
+}
+
+fn load_mesh(
+    path: &Path,
+    scale: &Vector<Real>,
+) -> Result<(Vec<Point<Real>>, Vec<[u32; 3]>), UrdfLoaderError> {
+    let mesh_error = |message: String| UrdfLoaderError::Mesh {
+        path: path.to_path_buf(),
+        message,
+    };
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_lowercase());
+
+    match extension.as_deref() {
+        Some("stl") => {
+            let mut file = File::open(path).map_err(|e| mesh_error(e.to_string()))?;
+            let mesh = stl_io::read_stl(&mut file).map_err(|e| mesh_error(e.to_string()))?;
+            let vertices = mesh
+                .vertices
+                .iter()
+                .map(|v| {
+                    Point::new(v[0] as Real, v[1] as Real, v[2] as Real)
+                        .coords
+                        .component_mul(scale)
+                        .into()
+                })
+                .collect();
+            let indices = mesh
+                .faces
+                .iter()
+                .map(|f| {
+                    [
+                        f.vertices[0] as u32,
+                        f.vertices[1] as u32,
+                        f.vertices[2] as u32,
+                    ]
+                })
+                .collect();
+            Ok((vertices, indices))
+        }
+        Some("obj") => {
+            let options = tobj::LoadOptions {
+                triangulate: true,
+                single_index: true,
+                ..Default::default()
+            };
+            let (models, _) =
+                tobj::load_obj(path, &options).map_err(|e| mesh_error(e.to_string()))?;
+            let mut vertices = vec![];
+            let mut indices = vec![];
+
+            for model in models {
+                let base = vertices.len() as u32;
+                vertices.extend(
+                    model
+                        .mesh
+                        .positions
+                        .chunks_exact(3)
+                        .map(|p| -> Point<Real> {
+                            Point::new(p[0] as Real, p[1] as Real, p[2] as Real)
+                                .coords
+                                .component_mul(scale)
+                                .into()
+                        }),
+                );
+                indices.extend(
+                    model
+                        .mesh
+                        .indices
+                        .chunks_exact(3)
+                        .map(|idx| [base + idx[0], base + idx[1], base + idx[2]]),
+                );
+            }
+
+            Ok((vertices, indices))
+        }
+        _ => Err(UrdfLoaderError::UnsupportedMeshFormat(path.to_path_buf())),
+    }
+}
+
+fn urdf_to_joint(joint: &urdf_rs::Joint) -> Option<GenericJoint> {
+    let locked_axes = match joint.joint_type {
+        JointType::Fixed => JointAxesMask::LOCKED_FIXED_AXES,
+        JointType::Revolute | JointType::Continuous => JointAxesMask::LOCKED_REVOLUTE_AXES,
+        JointType::Prismatic => JointAxesMask::LOCKED_PRISMATIC_AXES,
+        JointType::Spherical => JointAxesMask::LOCKED_SPHERICAL_AXES,
+        // Planar joints allow translations orthogonal to their axis.
+        JointType::Planar => JointAxesMask::X | JointAxesMask::ANG_AXES,
+        JointType::Floating => return None,
+    };
+
+    // Our joints are defined along their local X axis. Build local frames
+    // mapping the X axis to the URDF joint axis.
+    let axis = Vector::new(
+        joint.axis.xyz[0] as Real,
+        joint.axis.xyz[1] as Real,
+        joint.axis.xyz[2] as Real,
+    )
+    .try_normalize(1.0e-6)
+    .unwrap_or_else(Vector::x);
+    let axis_frame = Rotation::rotation_between(&Vector::x(), &axis).unwrap_or_else(|| {
+        Rotation::from_axis_angle(&Vector::y_axis(), std::f64::consts::PI as Real)
+    });
+
+    let mut builder = GenericJointBuilder::new(locked_axes)
+        .local_frame1(pose_to_isometry(&joint.origin) * axis_frame)
+        .local_frame2(Isometry::from_parts(na::one(), axis_frame));
+
+    let limits = [joint.limit.lower as Real, joint.limit.upper as Real];
+    if limits[0] < limits[1] {
+        match joint.joint_type {
+            JointType::Revolute => builder = builder.limits(JointAxis::AngX, limits),
+            JointType::Prismatic => builder = builder.limits(JointAxis::X, limits),
+            _ => {}
+        }
+    }
+
+    Some(builder.build())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{UrdfLoaderOptions, UrdfRobot};
+    use crate::dynamics::JointAxis;
+    use crate::math::Vector;
+    use crate::test_utils::TestWorld;
+    use std::path::Path;
+
+    const ARM_URDF: &str = r#"
+        <robot name="arm">
+            <link name="base">
+                <inertial>
+                    <mass value="10.0"/>
+                    <inertia ixx="1.0" ixy="0.0" ixz="0.0" iyy="1.0" iyz="0.0" izz="1.0"/>
+                </inertial>
+                <collision>
+                    <geometry><box size="0.4 0.4 0.2"/></geometry>
+                </collision>
+            </link>
+            <link name="upper_arm">
+                <inertial>
+                    <origin xyz="0.5 0 0"/>
+                    <mass value="1.0"/>
+                    <inertia ixx="0.01" ixy="0.0" ixz="0.0" iyy="0.1" iyz="0.0" izz="0.1"/>
+                </inertial>
+                <collision>
+                    <origin xyz="0.5 0 0"/>
+                    <geometry><sphere radius="0.1"/></geometry>
+                </collision>
+            </link>
+            <link name="gripper">
+                <inertial>
+                    <mass value="0.5"/>
+                    <inertia ixx="0.01" ixy="0.0" ixz="0.0" iyy="0.01" iyz="0.0" izz="0.01"/>
+                </inertial>
+            </link>
+            <joint name="shoulder" type="revolute">
+                <parent link="base"/>
+                <child link="upper_arm"/>
+                <origin xyz="0 0 0.5"/>
+                <axis xyz="0 0 1"/>
+                <limit lower="-1.0" upper="1.0" effort="10.0" velocity="1.0"/>
+            </joint>
+            <joint name="wrist" type="fixed">
+                <parent link="upper_arm"/>
+                <child link="gripper"/>
+                <origin xyz="1 0 0"/>
+            </joint>
+        </robot>
+    "#;
+
+    fn load_arm() -> UrdfRobot {
+        let robot = urdf_rs::read_from_string(ARM_URDF).unwrap();
+        let options = UrdfLoaderOptions {
+            make_roots_fixed: true,
+            ..UrdfLoaderOptions::default()
+        };
+        let arm = UrdfRobot::from_robot(&robot, &options, Path::new(".")).unwrap();
+        assert_eq!(arm.links.len(), 3);
+        assert_eq!(arm.joints.len(), 2);
+        let shoulder_limits = arm.joints["shoulder"]
+            .joint
+            .limits(JointAxis::AngX)
+            .unwrap();
+        assert_eq!([shoulder_limits.min, shoulder_limits.max], [-1.0, 1.0]);
+        arm
+    }
+
+    #[test]
+    fn urdf_inline_robot_into_multibodies() {
+        let mut world = TestWorld::without_gravity();
+        // URDF robots are usually described with the Z axis pointing up.
+        world.gravity = Vector::z() * -9.81;
+        let handles = load_arm().insert_using_multibody_joints(
+            &mut world.bodies,
+            &mut world.colliders,
+            &mut world.multibody_joints,
+        );
+
+        let shoulder = handles.joints["shoulder"].unwrap();
+        assert!(handles.joints["wrist"].is_some());
+
+        let gripper = handles.links["gripper"].body;
+        let expected = Vector::x() + Vector::z() * 0.5;
+        assert!((world.bodies[gripper].translation() - expected).norm() < 1.0e-5);
+
+        // The arm swings around the vertical shoulder axis only, so gravity doesn’t move it.
+        world.step_n(20);
+        assert!((world.bodies[gripper].translation() - expected).norm() < 1.0e-3);
+
+        let (multibody, _) = world.multibody_joints.get(shoulder).unwrap();
+        assert_eq!(multibody.num_links(), 3);
+        // Only the shoulder can move once the pipeline took the fixed base into account.
+        assert_eq!(multibody.ndofs(), 1);
+    }
+
+    #[test]
+    fn urdf_inline_robot_into_impulse_joints() {
+        let mut world = TestWorld::without_gravity();
+        // URDF robots are usually described with the Z axis pointing up.
+        world.gravity = Vector::z() * -9.81;
+        let handles = load_arm().insert_using_impulse_joints(
+            &mut world.bodies,
+            &mut world.colliders,
+            &mut world.impulse_joints,
+        );
+
+        assert_eq!(world.impulse_joints.len(), 2);
+        assert_eq!(handles.links["base"].colliders.len(), 1);
+        assert_eq!(handles.links["upper_arm"].colliders.len(), 1);
+        assert!(handles.links["gripper"].colliders.is_empty());
+
+        let gripper = handles.links["gripper"].body;
+        let expected = Vector::x() + Vector::z() * 0.5;
+        world.step_n(20);
+        assert!((world.bodies[gripper].translation() - expected).norm() < 1.0e-2);
+    }
+}
@@ -86,10 +86,19 @@ type Callbacks =
 #[allow(dead_code)]
 impl Harness {
     pub fn new_empty() -> Self {
-        let event_channel = crossbeam::channel::unbounded();
-        let event_handler = ChannelEventCollector::new(event_channel.0);
+        let collision_event_channel = crossbeam::channel::unbounded();
+        let contact_force_event_channel = crossbeam::channel::unbounded();
+        let joint_broken_event_channel = crossbeam::channel::unbounded();
+        let body_activation_event_channel = crossbeam::channel::unbounded();
+        let event_handler = ChannelEventCollector::new(collision_event_channel.0)
+            .with_contact_force_event_sender(contact_force_event_channel.0)
+            .with_joint_broken_event_sender(joint_broken_event_channel.0)
+            .with_body_activation_event_sender(body_activation_event_channel.0);
         let events = PhysicsEvents {
-            events: event_channel.1,
+            events: collision_event_channel.1,
+            contact_force_events: contact_force_event_channel.1,
+            joint_broken_events: joint_broken_event_channel.1,
+            body_activation_events: body_activation_event_channel.1,
         };
         let physics = PhysicsState::new();
         let state = RunState::new();
@@ -5,68 +5,43 @@ use rapier::dynamics::{
 };
 use rapier::geometry::{BroadPhase, ColliderSet, CollisionEvent, NarrowPhase};
 use rapier::math::{Real, Vector};
-use rapier::pipeline::{PhysicsHooks, PhysicsPipeline, QueryPipeline};
+use rapier::pipeline::{
+    BodyActivationEvent, ContactForceEvent, JointBrokenEvent, PhysicsHooks, PhysicsPipeline,
+    PhysicsWorldSnapshot, QueryPipeline,
+};
 
 pub struct PhysicsSnapshot {
     timestep_id: usize,
-    broad_phase: Vec<u8>,
-    narrow_phase: Vec<u8>,
-    bodies: Vec<u8>,
-    colliders: Vec<u8>,
-    impulse_joints: Vec<u8>,
+    world: Vec<u8>,
 }
 
 impl PhysicsSnapshot {
-    pub fn new(
-        timestep_id: usize,
-        broad_phase: &BroadPhase,
-        narrow_phase: &NarrowPhase,
-        bodies: &RigidBodySet,
-        colliders: &ColliderSet,
-        impulse_joints: &ImpulseJointSet,
-    ) -> bincode::Result<Self> {
+    pub fn new(timestep_id: usize, physics: &PhysicsState) -> bincode::Result<Self> {
+        let world = PhysicsWorldSnapshot::new(
+            &physics.gravity,
+            &physics.integration_parameters,
+            &physics.islands,
+            &physics.broad_phase,
+            &physics.narrow_phase,
+            &physics.bodies,
+            &physics.colliders,
+            &physics.impulse_joints,
+            &physics.multibody_joints,
+            &physics.ccd_solver,
+        );
+
         Ok(Self {
             timestep_id,
-            broad_phase: bincode::serialize(broad_phase)?,
-            narrow_phase: bincode::serialize(narrow_phase)?,
-            bodies: bincode::serialize(bodies)?,
-            colliders: bincode::serialize(colliders)?,
-            impulse_joints: bincode::serialize(impulse_joints)?,
+            world: bincode::serialize(&world)?,
         })
     }
 
-    pub fn restore(
-        &self,
-    ) -> bincode::Result<(
-        usize,
-        BroadPhase,
-        NarrowPhase,
-        RigidBodySet,
-        ColliderSet,
-        ImpulseJointSet,
-    )> {
-        Ok((
-            self.timestep_id,
-            bincode::deserialize(&self.broad_phase)?,
-            bincode::deserialize(&self.narrow_phase)?,
-            bincode::deserialize(&self.bodies)?,
-            bincode::deserialize(&self.colliders)?,
-            bincode::deserialize(&self.impulse_joints)?,
-        ))
+    pub fn restore(&self) -> bincode::Result<(usize, PhysicsWorldSnapshot)> {
+        Ok((self.timestep_id, bincode::deserialize(&self.world)?))
     }
 
     pub fn print_snapshot_len(&self) {
-        let total = self.broad_phase.len()
-            + self.narrow_phase.len()
-            + self.bodies.len()
-            + self.colliders.len()
-            + self.impulse_joints.len();
-        println!("Snapshot length: {}B", total);
-        println!("|_ broad_phase: {}B", self.broad_phase.len());
-        println!("|_ narrow_phase: {}B", self.narrow_phase.len());
-        println!("|_ bodies: {}B", self.bodies.len());
-        println!("|_ colliders: {}B", self.colliders.len());
-        println!("|_ impulse_joints: {}B", self.impulse_joints.len());
+        println!("Snapshot length: {}B", self.world.len());
     }
 }
 
@@ -108,10 +83,16 @@ impl PhysicsState {
 
 pub struct PhysicsEvents {
     pub events: Receiver<CollisionEvent>,
+    pub contact_force_events: Receiver<ContactForceEvent>,
+    pub joint_broken_events: Receiver<JointBrokenEvent>,
+    pub body_activation_events: Receiver<BodyActivationEvent>,
 }
 
 impl PhysicsEvents {
     pub fn poll_all(&self) {
         while let Ok(_) = self.events.try_recv() {}
+        while let Ok(_) = self.contact_force_events.try_recv() {}
+        while let Ok(_) = self.joint_broken_events.try_recv() {}
+        while let Ok(_) = self.body_activation_events.try_recv() {}
     }
 }
@@ -1049,15 +1049,7 @@ fn update_testbed(
             state
                 .action_flags
                 .set(TestbedActionFlags::TAKE_SNAPSHOT, false);
-            state.snapshot = PhysicsSnapshot::new(
-                harness.state.timestep_id,
-                &harness.physics.broad_phase,
-                &harness.physics.narrow_phase,
-                &harness.physics.bodies,
-                &harness.physics.colliders,
-                &harness.physics.impulse_joints,
-            )
-            .ok();
+            state.snapshot = PhysicsSnapshot::new(harness.state.timestep_id, &harness.physics).ok();
 
             if let Some(snap) = &state.snapshot {
                 snap.print_snapshot_len();
@@ -1072,17 +1064,30 @@ fn update_testbed(
                 .action_flags
                 .set(TestbedActionFlags::RESTORE_SNAPSHOT, false);
             if let Some(snapshot) = &state.snapshot {
-                if let Ok(w) = snapshot.restore() {
+                if let Ok((timestep_id, world)) = snapshot.restore() {
                     clear(&mut commands, &mut state, &mut graphics, &mut plugins);
 
                     for plugin in &mut plugins.0 {
                         plugin.clear_graphics(&mut graphics, &mut commands);
                     }
 
-                    // set_world(w.3, w.4, w.5);
-                    harness.physics.broad_phase = w.1;
-                    harness.physics.narrow_phase = w.2;
-                    harness.state.timestep_id = w.0;
+                    let physics = &mut harness.physics;
+                    world.restore(
+                        &mut physics.gravity,
+                        &mut physics.integration_parameters,
+                        &mut physics.islands,
+                        &mut physics.broad_phase,
+                        &mut physics.narrow_phase,
+                        &mut physics.bodies,
+                        &mut physics.colliders,
+                        &mut physics.impulse_joints,
+                        &mut physics.multibody_joints,
+                        &mut physics.ccd_solver,
+                    );
+                    harness.state.timestep_id = timestep_id;
+                    state
+                        .action_flags
+                        .set(TestbedActionFlags::RESET_WORLD_GRAPHICS, true);
                 }
             }
         }